@@ -0,0 +1,53 @@
+//! Balancing of a general matrix
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::One;
+
+pub trait MatrixBalance_: Scalar + Sized {
+    /// Wraps `*gebal` with `job = 'S'`, i.e. scales a square matrix without permutations
+    ///
+    /// `a` is overwritten by `D^-1 A D` where `D` is the diagonal matrix of the returned factors,
+    /// which are powers of the radix and reduce the difference of the row and column norms.
+    fn balance(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_balance {
+    ($scalar:ty, $gebal:path) => {
+        impl MatrixBalance_ for $scalar {
+            fn balance(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self::Real>> {
+                let n = l.len();
+                let mut ilo = 0;
+                let mut ihi = 0;
+                let mut scale = unsafe { vec_uninit(n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $gebal(
+                        b'S',
+                        n,
+                        a,
+                        l.lda(),
+                        &mut ilo,
+                        &mut ihi,
+                        &mut scale,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result(stringify!($gebal))?;
+                // LAPACK balances the transpose `A^T` of a C layout matrix into
+                // `D^-1 A^T D = (D A D^-1)^T`, i.e. `A` is scaled by the inverse factors
+                if let MatrixLayout::C { .. } = l {
+                    for s in scale.iter_mut() {
+                        *s = Self::Real::one() / *s;
+                    }
+                }
+                Ok(scale)
+            }
+        }
+    };
+}
+
+impl_balance!(f64, lapack::dgebal);
+impl_balance!(f32, lapack::sgebal);
+impl_balance!(c64, lapack::zgebal);
+impl_balance!(c32, lapack::cgebal);
@@ -0,0 +1,479 @@
+//! BLAS routines
+
+use crate::{layout::*, Diag, Transpose, UPLO};
+use cauchy::*;
+use cblas_sys::*;
+
+/// Which side the matrix is multiplied from
+#[derive(Debug, Clone, Copy)]
+#[repr(u8)]
+pub enum Side {
+    Left = b'L',
+    Right = b'R',
+}
+
+/// Wraps BLAS routines through CBLAS
+///
+/// Unlike the LAPACK wrappers, CBLAS accepts both row-major and column-major matrices,
+/// and thus no transpose of the data is required.
+pub trait Blas_: Scalar {
+    /// Wrapper of `*gemm`, i.e. `C = alpha * A * B + beta * C`
+    ///
+    /// The shapes of `A`, `B` and `C` are assumed to be consistent.
+    #[allow(clippy::too_many_arguments)]
+    fn gemm(
+        alpha: Self,
+        a_layout: MatrixLayout,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &[Self],
+        beta: Self,
+        c_layout: MatrixLayout,
+        c: &mut [Self],
+    );
+
+    /// Wrapper of `*gemv`, i.e. `y = alpha * A * x + beta * y`
+    ///
+    /// `x` and `y` are accessed with the strides `incx` and `incy`, which must be positive.
+    /// The shapes of `A`, `x` and `y` are assumed to be consistent.
+    #[allow(clippy::too_many_arguments)]
+    fn gemv(
+        alpha: Self,
+        a_layout: MatrixLayout,
+        a: &[Self],
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+
+    /// Wrapper of `*syrk` for real and `*herk` for complex, i.e. `C = alpha * A * A^H + beta * C`
+    ///
+    /// Only the triangular part of `C` specified by `uplo` is referenced and updated.
+    /// The shapes of `A` and `C` are assumed to be consistent.
+    fn syrk(
+        uplo: UPLO,
+        alpha: Self::Real,
+        a_layout: MatrixLayout,
+        a: &[Self],
+        beta: Self::Real,
+        c_layout: MatrixLayout,
+        c: &mut [Self],
+    );
+
+    /// Wrapper of `*trmm`, i.e. `B = op(A) * B` for `Side::Left` or `B = B * op(A)` for `Side::Right`
+    /// where `A` is a triangular matrix and `op` is specified by `trans`
+    ///
+    /// Only the triangular part of `A` specified by `uplo` is referenced.
+    /// The shapes of `A` and `B` are assumed to be consistent.
+    #[allow(clippy::too_many_arguments)]
+    fn trmm(
+        side: Side,
+        uplo: UPLO,
+        trans: Transpose,
+        diag: Diag,
+        a_layout: MatrixLayout,
+        a: &[Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    );
+
+    /// Wrapper of `*ger` for real, and `*geru` or `*gerc` for complex,
+    /// i.e. `A = alpha * x * y^T + A` or `A = alpha * x * y^H + A` if `conj`
+    ///
+    /// `x` and `y` are read with the strides `incx` and `incy`, which must be positive.
+    /// The shape of `A` is assumed to be consistent with `x` and `y`.
+    #[allow(clippy::too_many_arguments)]
+    fn ger(
+        conj: bool,
+        alpha: Self,
+        x: &[Self],
+        incx: i32,
+        y: &[Self],
+        incy: i32,
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+    );
+
+    /// Wrapper of `*dot` for real, and `*dotu` or `*dotc` for complex,
+    /// i.e. `x^T y` or `x^H y` if `conj` for `n` elements
+    ///
+    /// `x` and `y` are read with the strides `incx` and `incy`, which must be positive.
+    fn dot(conj: bool, n: i32, x: &[Self], incx: i32, y: &[Self], incy: i32) -> Self;
+
+    /// Wrapper of `*axpy`, i.e. `y = alpha * x + y` for `n` elements
+    ///
+    /// `x` and `y` are accessed with the strides `incx` and `incy`, which must be positive.
+    fn axpy(n: i32, alpha: Self, x: &[Self], incx: i32, y: &mut [Self], incy: i32);
+
+    /// Wrapper of `*scal`, i.e. `x = alpha * x` for `n` elements accessed with the positive stride `incx`
+    fn scal(n: i32, alpha: Self, x: &mut [Self], incx: i32);
+
+    /// Wrapper of `i*amax`, i.e. the 0-based index of the first element of the largest `|re| + |im|`
+    ///
+    /// `n` elements of `x` are read with the stride `incx`, which must be positive.
+    fn iamax(n: i32, x: &[Self], incx: i32) -> usize;
+}
+
+/// `CBLAS_LAYOUT` which reads the memory in the same order as `l`
+fn cblas_layout(l: MatrixLayout) -> CBLAS_LAYOUT {
+    match l {
+        MatrixLayout::C { .. } => CblasRowMajor,
+        MatrixLayout::F { .. } => CblasColMajor,
+    }
+}
+
+/// Transpose flag to read a matrix of layout `l` with the order of `order`
+fn cblas_transpose(order: MatrixLayout, l: MatrixLayout) -> CBLAS_TRANSPOSE {
+    if order.same_order(&l) {
+        CblasNoTrans
+    } else {
+        CblasTrans
+    }
+}
+
+fn cblas_op(trans: Transpose) -> CBLAS_TRANSPOSE {
+    match trans {
+        Transpose::No => CblasNoTrans,
+        Transpose::Transpose => CblasTrans,
+        Transpose::Hermite => CblasConjTrans,
+    }
+}
+
+fn cblas_uplo(uplo: UPLO) -> CBLAS_UPLO {
+    match uplo {
+        UPLO::Upper => CblasUpper,
+        UPLO::Lower => CblasLower,
+    }
+}
+
+fn cblas_side(side: Side) -> CBLAS_SIDE {
+    match side {
+        Side::Left => CblasLeft,
+        Side::Right => CblasRight,
+    }
+}
+
+fn cblas_diag(diag: Diag) -> CBLAS_DIAG {
+    match diag {
+        Diag::Unit => CblasUnit,
+        Diag::NonUnit => CblasNonUnit,
+    }
+}
+
+// Real and complex versions of CBLAS differ in how scalars are passed
+macro_rules! cblas_scalar {
+    (real, $x:expr) => {
+        $x
+    };
+    (complex, $x:expr) => {
+        &$x as *const Self as *const _
+    };
+}
+
+// Conjugate the matrix in place. This is no-op for real matrices.
+macro_rules! cblas_conj {
+    (real, $a:expr) => {};
+    (complex, $a:expr) => {
+        $a.iter_mut().for_each(|x| *x = x.conj());
+    };
+}
+
+// Real versions of CBLAS return the dot product, while complex ones write it to a pointer
+macro_rules! cblas_dot {
+    (real, $dotu:path, $dotc:path, $conj:expr, $n:expr, $x:expr, $incx:expr, $y:expr, $incy:expr) => {{
+        // `x^H y` is `x^T y` for real vectors
+        let _ = $conj;
+        unsafe { $dotu($n, $x.as_ptr(), $incx, $y.as_ptr(), $incy) }
+    }};
+    (complex, $dotu:path, $dotc:path, $conj:expr, $n:expr, $x:expr, $incx:expr, $y:expr, $incy:expr) => {{
+        let dot = if $conj { $dotc } else { $dotu };
+        let mut out = Self::from_real(Self::real(0.0));
+        unsafe {
+            dot(
+                $n,
+                $x.as_ptr() as *const _,
+                $incx,
+                $y.as_ptr() as *const _,
+                $incy,
+                &mut out as *mut Self as *mut _,
+            )
+        };
+        out
+    }};
+}
+
+macro_rules! impl_blas {
+    ($scalar:ty, $kind:ident, $gemm:path, $gemv:path, $syrk:path, $trmm:path, $geru:path, $gerc:path, $dotu:path, $dotc:path, $axpy:path, $scal:path, $iamax:path) => {
+        impl Blas_ for $scalar {
+            fn gemm(
+                alpha: Self,
+                a_layout: MatrixLayout,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &[Self],
+                beta: Self,
+                c_layout: MatrixLayout,
+                c: &mut [Self],
+            ) {
+                let (m, n) = c_layout.size();
+                let (_, k) = a_layout.size();
+                unsafe {
+                    $gemm(
+                        cblas_layout(c_layout),
+                        cblas_transpose(c_layout, a_layout),
+                        cblas_transpose(c_layout, b_layout),
+                        m,
+                        n,
+                        k,
+                        cblas_scalar!($kind, alpha),
+                        a.as_ptr() as *const _,
+                        a_layout.lda(),
+                        b.as_ptr() as *const _,
+                        b_layout.lda(),
+                        cblas_scalar!($kind, beta),
+                        c.as_mut_ptr() as *mut _,
+                        c_layout.lda(),
+                    );
+                }
+            }
+
+            fn gemv(
+                alpha: Self,
+                a_layout: MatrixLayout,
+                a: &[Self],
+                x: &[Self],
+                incx: i32,
+                beta: Self,
+                y: &mut [Self],
+                incy: i32,
+            ) {
+                let (m, n) = a_layout.size();
+                unsafe {
+                    $gemv(
+                        cblas_layout(a_layout),
+                        CblasNoTrans,
+                        m,
+                        n,
+                        cblas_scalar!($kind, alpha),
+                        a.as_ptr() as *const _,
+                        a_layout.lda(),
+                        x.as_ptr() as *const _,
+                        incx,
+                        cblas_scalar!($kind, beta),
+                        y.as_mut_ptr() as *mut _,
+                        incy,
+                    );
+                }
+            }
+
+            fn syrk(
+                uplo: UPLO,
+                alpha: Self::Real,
+                a_layout: MatrixLayout,
+                a: &[Self],
+                beta: Self::Real,
+                c_layout: MatrixLayout,
+                c: &mut [Self],
+            ) {
+                let (n, k) = a_layout.size();
+                // `A` read in the order of `C` is `A^T`, and `*herk` computes only
+                // `(A^T)^H A^T = conj(A A^H)` from it. Thus `C` is conjugated before and after.
+                let transposed = !c_layout.same_order(&a_layout);
+                let trans = if transposed {
+                    cblas_conj!($kind, c);
+                    CblasConjTrans
+                } else {
+                    CblasNoTrans
+                };
+                unsafe {
+                    $syrk(
+                        cblas_layout(c_layout),
+                        cblas_uplo(uplo),
+                        trans,
+                        n,
+                        k,
+                        alpha,
+                        a.as_ptr() as *const _,
+                        a_layout.lda(),
+                        beta,
+                        c.as_mut_ptr() as *mut _,
+                        c_layout.lda(),
+                    );
+                }
+                if transposed {
+                    cblas_conj!($kind, c);
+                }
+            }
+
+            fn trmm(
+                side: Side,
+                uplo: UPLO,
+                trans: Transpose,
+                diag: Diag,
+                a_layout: MatrixLayout,
+                a: &[Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) {
+                let (m, n) = b_layout.size();
+                // `A` read in the order of `B` is `A^T`, whose triangle is flipped.
+                // `conj(A)` is computed as `conj(conj(B) A^T)` since CBLAS has no such flag.
+                let (uplo, trans, conj) = if a_layout.same_order(&b_layout) {
+                    (uplo, cblas_op(trans), false)
+                } else {
+                    match trans {
+                        Transpose::No => (uplo.t(), CblasTrans, false),
+                        Transpose::Transpose => (uplo.t(), CblasNoTrans, false),
+                        Transpose::Hermite => (uplo.t(), CblasNoTrans, true),
+                    }
+                };
+                if conj {
+                    cblas_conj!($kind, b);
+                }
+                let alpha = Self::from_real(Self::real(1.0));
+                unsafe {
+                    $trmm(
+                        cblas_layout(b_layout),
+                        cblas_side(side),
+                        cblas_uplo(uplo),
+                        trans,
+                        cblas_diag(diag),
+                        m,
+                        n,
+                        cblas_scalar!($kind, alpha),
+                        a.as_ptr() as *const _,
+                        a_layout.lda(),
+                        b.as_mut_ptr() as *mut _,
+                        b_layout.lda(),
+                    );
+                }
+                if conj {
+                    cblas_conj!($kind, b);
+                }
+            }
+
+            fn ger(
+                conj: bool,
+                alpha: Self,
+                x: &[Self],
+                incx: i32,
+                y: &[Self],
+                incy: i32,
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+            ) {
+                let (m, n) = a_layout.size();
+                let ger = if conj { $gerc } else { $geru };
+                unsafe {
+                    ger(
+                        cblas_layout(a_layout),
+                        m,
+                        n,
+                        cblas_scalar!($kind, alpha),
+                        x.as_ptr() as *const _,
+                        incx,
+                        y.as_ptr() as *const _,
+                        incy,
+                        a.as_mut_ptr() as *mut _,
+                        a_layout.lda(),
+                    );
+                }
+            }
+
+            fn dot(conj: bool, n: i32, x: &[Self], incx: i32, y: &[Self], incy: i32) -> Self {
+                cblas_dot!($kind, $dotu, $dotc, conj, n, x, incx, y, incy)
+            }
+
+            fn axpy(n: i32, alpha: Self, x: &[Self], incx: i32, y: &mut [Self], incy: i32) {
+                unsafe {
+                    $axpy(
+                        n,
+                        cblas_scalar!($kind, alpha),
+                        x.as_ptr() as *const _,
+                        incx,
+                        y.as_mut_ptr() as *mut _,
+                        incy,
+                    );
+                }
+            }
+
+            fn scal(n: i32, alpha: Self, x: &mut [Self], incx: i32) {
+                unsafe {
+                    $scal(
+                        n,
+                        cblas_scalar!($kind, alpha),
+                        x.as_mut_ptr() as *mut _,
+                        incx,
+                    )
+                }
+            }
+
+            fn iamax(n: i32, x: &[Self], incx: i32) -> usize {
+                unsafe { $iamax(n, x.as_ptr() as *const _, incx) as usize }
+            }
+        }
+    };
+} // impl_blas!
+
+impl_blas!(
+    f64,
+    real,
+    cblas_dgemm,
+    cblas_dgemv,
+    cblas_dsyrk,
+    cblas_dtrmm,
+    cblas_dger,
+    cblas_dger,
+    cblas_ddot,
+    cblas_ddot,
+    cblas_daxpy,
+    cblas_dscal,
+    cblas_idamax
+);
+impl_blas!(
+    f32,
+    real,
+    cblas_sgemm,
+    cblas_sgemv,
+    cblas_ssyrk,
+    cblas_strmm,
+    cblas_sger,
+    cblas_sger,
+    cblas_sdot,
+    cblas_sdot,
+    cblas_saxpy,
+    cblas_sscal,
+    cblas_isamax
+);
+impl_blas!(
+    c64,
+    complex,
+    cblas_zgemm,
+    cblas_zgemv,
+    cblas_zherk,
+    cblas_ztrmm,
+    cblas_zgeru,
+    cblas_zgerc,
+    cblas_zdotu_sub,
+    cblas_zdotc_sub,
+    cblas_zaxpy,
+    cblas_zscal,
+    cblas_izamax
+);
+impl_blas!(
+    c32,
+    complex,
+    cblas_cgemm,
+    cblas_cgemv,
+    cblas_cherk,
+    cblas_ctrmm,
+    cblas_cgeru,
+    cblas_cgerc,
+    cblas_cdotu_sub,
+    cblas_cdotc_sub,
+    cblas_caxpy,
+    cblas_cscal,
+    cblas_icamax
+);
@@ -31,7 +31,7 @@ macro_rules! impl_cholesky {
                 unsafe {
                     $trf(uplo as u8, n, a, n, &mut info);
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($trf))?;
                 if matches!(l, MatrixLayout::C { .. }) {
                     square_transpose(l, a);
                 }
@@ -47,7 +47,7 @@ macro_rules! impl_cholesky {
                 unsafe {
                     $tri(uplo as u8, n, a, l.lda(), &mut info);
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($tri))?;
                 if matches!(l, MatrixLayout::C { .. }) {
                     square_transpose(l, a);
                 }
@@ -72,7 +72,7 @@ macro_rules! impl_cholesky {
                 unsafe {
                     $trs(uplo as u8, n, nrhs, a, l.lda(), b, n, &mut info);
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($trs))?;
                 if matches!(l, MatrixLayout::C { .. }) {
                     for val in b.iter_mut() {
                         *val = val.conj();
@@ -4,24 +4,102 @@ use crate::{error::*, layout::MatrixLayout, *};
 use cauchy::*;
 use num_traits::{ToPrimitive, Zero};
 
-/// Wraps `*geev` for general matrices
+/// Balancing of a general matrix before computing the eigenvalues, see `*gebal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Balance {
+    /// Do not balance
+    None = b'N',
+    /// Permute to isolate the eigenvalues if possible
+    Permute = b'P',
+    /// Scale rows and columns to make their norms close
+    Scale = b'S',
+    /// Both permute and scale
+    Both = b'B',
+}
+
+/// Wraps `*geevx` for general matrices
 pub trait Eig_: Scalar {
     /// Calculate Right eigenvalue
+    ///
+    /// The matrix is balanced with `Balance::Both` as `*geev` does.
     fn eig(
         calc_v: bool,
         l: MatrixLayout,
         a: &mut [Self],
-    ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)>;
+    ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)> {
+        Self::eig_balanced(calc_v, l, Balance::Both, a)
+    }
+
+    /// Calculate Right eigenvalue after balancing specified by `balance`
+    ///
+    /// The eigenvectors are transformed back to those of the original matrix.
+    #[allow(clippy::type_complexity)]
+    fn eig_balanced(
+        calc_v: bool,
+        l: MatrixLayout,
+        balance: Balance,
+        a: &mut [Self],
+    ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)> {
+        let out = Self::eig_expert(calc_v, false, l, balance, a)?;
+        Ok((out.eigs, out.vr))
+    }
+
+    /// Calculate Right eigenvalue, and the reciprocal condition numbers if `calc_rcond`
+    ///
+    /// The eigenvectors are always calculated if `calc_rcond`,
+    /// since both the left and right eigenvectors are required for the condition numbers.
+    fn eig_expert(
+        calc_v: bool,
+        calc_rcond: bool,
+        l: MatrixLayout,
+        balance: Balance,
+        a: &mut [Self],
+    ) -> Result<EigExpertOutput<Self>> {
+        Self::eig_generic(false, calc_v, calc_rcond, l, balance, a)
+    }
+
+    /// Calculate eigenvalues, the left eigenvectors if `calc_vl`,
+    /// the right eigenvectors if `calc_vr`, and the reciprocal condition numbers if `calc_rcond`
+    ///
+    /// Both the left and right eigenvectors are always calculated if `calc_rcond`.
+    /// The left eigenvectors `u_j` satisfy `u_j^H A = λ_j u_j^H`,
+    /// and both eigenvectors are normalized to have the unit 2-norm as `*geevx` does.
+    fn eig_generic(
+        calc_vl: bool,
+        calc_vr: bool,
+        calc_rcond: bool,
+        l: MatrixLayout,
+        balance: Balance,
+        a: &mut [Self],
+    ) -> Result<EigExpertOutput<Self>>;
+}
+
+/// Result of [Eig_::eig_expert]
+pub struct EigExpertOutput<A: Scalar> {
+    /// Eigenvalues
+    pub eigs: Vec<A::Complex>,
+    /// Right eigenvectors as the columns of a column-major matrix, or empty if not calculated
+    pub vr: Vec<A::Complex>,
+    /// Left eigenvectors as the columns of a column-major matrix, or empty if not calculated
+    pub vl: Vec<A::Complex>,
+    /// Reciprocal condition numbers of the eigenvalues
+    pub rconde: Option<Vec<A::Real>>,
+    /// Reciprocal condition numbers of the right eigenvectors
+    pub rcondv: Option<Vec<A::Real>>,
 }
 
 macro_rules! impl_eig_complex {
     ($scalar:ty, $ev:path) => {
         impl Eig_ for $scalar {
-            fn eig(
-                calc_v: bool,
+            fn eig_generic(
+                calc_vl: bool,
+                calc_vr: bool,
+                calc_rcond: bool,
                 l: MatrixLayout,
+                balance: Balance,
                 mut a: &mut [Self],
-            ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)> {
+            ) -> Result<EigExpertOutput<Self>> {
                 let (n, _) = l.size();
                 // LAPACK assumes a column-major input. A row-major input can
                 // be interpreted as the transpose of a column-major input. So,
@@ -33,16 +111,27 @@ macro_rules! impl_eig_complex {
                 // So, in this case, the right eigenvectors are the conjugates
                 // of the left eigenvectors computed with `A`, and the
                 // eigenvalues are the eigenvalues computed with `A`.
-                let (jobvl, jobvr) = if calc_v {
-                    match l {
-                        MatrixLayout::C { .. } => (b'V', b'N'),
-                        MatrixLayout::F { .. } => (b'N', b'V'),
-                    }
+                //
+                // The condition numbers are invariant under the transpose.
+                // Similarly, the left eigenvectors are the conjugates of the
+                // right eigenvectors computed with `A`.
+                let left = matches!(l, MatrixLayout::C { .. });
+                let calc_vl = calc_vl || calc_rcond;
+                let calc_vr = calc_vr || calc_rcond;
+                let job = |calc| if calc { b'V' } else { b'N' };
+                let (jobvl, jobvr) = if left {
+                    (job(calc_vr), job(calc_vl))
                 } else {
-                    (b'N', b'N')
+                    (job(calc_vl), job(calc_vr))
                 };
+                let sense = if calc_rcond { b'B' } else { b'N' };
                 let mut eigs = unsafe { vec_uninit(n as usize) };
                 let mut rwork = unsafe { vec_uninit(2 * n as usize) };
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = unsafe { vec_uninit(n as usize) };
+                let mut abnrm = Self::Real::zero();
+                let mut rconde = unsafe { vec_uninit(n as usize) };
+                let mut rcondv = unsafe { vec_uninit(n as usize) };
 
                 let mut vl = if jobvl == b'V' {
                     Some(unsafe { vec_uninit((n * n) as usize) })
@@ -60,8 +149,10 @@ macro_rules! impl_eig_complex {
                 let mut work_size = [Self::zero()];
                 unsafe {
                     $ev(
+                        balance as u8,
                         jobvl,
                         jobvr,
+                        sense,
                         n,
                         &mut a,
                         n,
@@ -70,21 +161,29 @@ macro_rules! impl_eig_complex {
                         n,
                         &mut vr.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut []),
                         n,
+                        &mut ilo,
+                        &mut ihi,
+                        &mut scale,
+                        &mut abnrm,
+                        &mut rconde,
+                        &mut rcondv,
                         &mut work_size,
                         -1,
                         &mut rwork,
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($ev))?;
 
                 // actal ev
                 let lwork = work_size[0].to_usize().unwrap();
                 let mut work = unsafe { vec_uninit(lwork) };
                 unsafe {
                     $ev(
+                        balance as u8,
                         jobvl,
                         jobvr,
+                        sense,
                         n,
                         &mut a,
                         n,
@@ -93,38 +192,62 @@ macro_rules! impl_eig_complex {
                         n,
                         &mut vr.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut []),
                         n,
+                        &mut ilo,
+                        &mut ihi,
+                        &mut scale,
+                        &mut abnrm,
+                        &mut rconde,
+                        &mut rcondv,
                         &mut work,
                         lwork as i32,
                         &mut rwork,
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($ev))?;
 
                 // Hermite conjugate
-                if jobvl == b'V' {
-                    for c in vl.as_mut().unwrap().iter_mut() {
-                        c.im = -c.im
+                let conj = |v: Option<Vec<Self>>| {
+                    let mut v = v.unwrap_or_default();
+                    if left {
+                        for c in v.iter_mut() {
+                            c.im = -c.im
+                        }
                     }
-                }
+                    v
+                };
+                let (vl, vr) = if left {
+                    (conj(vr), conj(vl))
+                } else {
+                    (conj(vl), conj(vr))
+                };
 
-                Ok((eigs, vr.or(vl).unwrap_or(Vec::new())))
+                Ok(EigExpertOutput {
+                    eigs,
+                    vr,
+                    vl,
+                    rconde: if calc_rcond { Some(rconde) } else { None },
+                    rcondv: if calc_rcond { Some(rcondv) } else { None },
+                })
             }
         }
     };
 }
 
-impl_eig_complex!(c64, lapack::zgeev);
-impl_eig_complex!(c32, lapack::cgeev);
+impl_eig_complex!(c64, lapack::zgeevx);
+impl_eig_complex!(c32, lapack::cgeevx);
 
 macro_rules! impl_eig_real {
     ($scalar:ty, $ev:path) => {
         impl Eig_ for $scalar {
-            fn eig(
-                calc_v: bool,
+            fn eig_generic(
+                calc_vl: bool,
+                calc_vr: bool,
+                calc_rcond: bool,
                 l: MatrixLayout,
+                balance: Balance,
                 mut a: &mut [Self],
-            ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)> {
+            ) -> Result<EigExpertOutput<Self>> {
                 let (n, _) = l.size();
                 // LAPACK assumes a column-major input. A row-major input can
                 // be interpreted as the transpose of a column-major input. So,
@@ -142,16 +265,28 @@ macro_rules! impl_eig_real {
                 // into new matrices anyway, and by not modifying the
                 // eigenvalues, we preserve the nice ordering specified by
                 // `sgeev`/`dgeev`.
-                let (jobvl, jobvr) = if calc_v {
-                    match l {
-                        MatrixLayout::C { .. } => (b'V', b'N'),
-                        MatrixLayout::F { .. } => (b'N', b'V'),
-                    }
+                //
+                // The condition numbers are invariant under the transpose.
+                // Similarly, the left eigenvectors are the conjugates of the
+                // right eigenvectors computed with `A`.
+                let left = matches!(l, MatrixLayout::C { .. });
+                let calc_vl = calc_vl || calc_rcond;
+                let calc_vr = calc_vr || calc_rcond;
+                let job = |calc| if calc { b'V' } else { b'N' };
+                let (jobvl, jobvr) = if left {
+                    (job(calc_vr), job(calc_vl))
                 } else {
-                    (b'N', b'N')
+                    (job(calc_vl), job(calc_vr))
                 };
+                let sense = if calc_rcond { b'B' } else { b'N' };
                 let mut eig_re = unsafe { vec_uninit(n as usize) };
                 let mut eig_im = unsafe { vec_uninit(n as usize) };
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = unsafe { vec_uninit(n as usize) };
+                let mut abnrm = 0.0;
+                let mut rconde = unsafe { vec_uninit(n as usize) };
+                let mut rcondv = unsafe { vec_uninit(n as usize) };
+                let mut iwork = unsafe { vec_uninit(std::cmp::max(1, 2 * n - 2) as usize) };
 
                 let mut vl = if jobvl == b'V' {
                     Some(unsafe { vec_uninit((n * n) as usize) })
@@ -169,8 +304,10 @@ macro_rules! impl_eig_real {
                 let mut work_size = [0.0];
                 unsafe {
                     $ev(
+                        balance as u8,
                         jobvl,
                         jobvr,
+                        sense,
                         n,
                         &mut a,
                         n,
@@ -180,20 +317,29 @@ macro_rules! impl_eig_real {
                         n,
                         vr.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut []),
                         n,
+                        &mut ilo,
+                        &mut ihi,
+                        &mut scale,
+                        &mut abnrm,
+                        &mut rconde,
+                        &mut rcondv,
                         &mut work_size,
                         -1,
+                        &mut iwork,
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($ev))?;
 
                 // actual ev
                 let lwork = work_size[0].to_usize().unwrap();
                 let mut work = unsafe { vec_uninit(lwork) };
                 unsafe {
                     $ev(
+                        balance as u8,
                         jobvl,
                         jobvr,
+                        sense,
                         n,
                         &mut a,
                         n,
@@ -203,12 +349,19 @@ macro_rules! impl_eig_real {
                         n,
                         vr.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut []),
                         n,
+                        &mut ilo,
+                        &mut ihi,
+                        &mut scale,
+                        &mut abnrm,
+                        &mut rconde,
+                        &mut rcondv,
                         &mut work,
                         lwork as i32,
+                        &mut iwork,
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($ev))?;
 
                 // reconstruct eigenvalues
                 let eigs: Vec<Self::Complex> = eig_re
@@ -217,10 +370,6 @@ macro_rules! impl_eig_real {
                     .map(|(&re, &im)| Self::complex(re, im))
                     .collect();
 
-                if !calc_v {
-                    return Ok((eigs, Vec::new()));
-                }
-
                 // Reconstruct eigenvectors into complex-array
                 // --------------------------------------------
                 //
@@ -233,42 +382,60 @@ macro_rules! impl_eig_real {
                 //   - v(j)   = VR(:,j) + i*VR(:,j+1)
                 //   - v(j+1) = VR(:,j) - i*VR(:,j+1).
                 //
-                // In the C-layout case, we need the conjugates of the left
+                // The left eigenvectors in VL are stored in the same way.
+                // In the C-layout case, we need the conjugates of the
                 // eigenvectors, so the signs should be reversed.
 
                 let n = n as usize;
-                let v = vr.or(vl).unwrap();
-                let mut eigvecs = unsafe { vec_uninit(n * n) };
-                let mut col = 0;
-                while col < n {
-                    if eig_im[col] == 0. {
-                        // The corresponding eigenvalue is real.
-                        for row in 0..n {
-                            let re = v[row + col * n];
-                            eigvecs[row + col * n] = Self::complex(re, 0.);
-                        }
-                        col += 1;
-                    } else {
-                        // This is a complex conjugate pair.
-                        assert!(col + 1 < n);
-                        for row in 0..n {
-                            let re = v[row + col * n];
-                            let mut im = v[row + (col + 1) * n];
-                            if jobvl == b'V' {
-                                im = -im;
+                let reconstruct = |v: Option<Vec<Self>>| {
+                    let v = match v {
+                        Some(v) => v,
+                        None => return Vec::new(),
+                    };
+                    let mut eigvecs = unsafe { vec_uninit(n * n) };
+                    let mut col = 0;
+                    while col < n {
+                        if eig_im[col] == 0. {
+                            // The corresponding eigenvalue is real.
+                            for row in 0..n {
+                                let re = v[row + col * n];
+                                eigvecs[row + col * n] = Self::complex(re, 0.);
+                            }
+                            col += 1;
+                        } else {
+                            // This is a complex conjugate pair.
+                            assert!(col + 1 < n);
+                            for row in 0..n {
+                                let re = v[row + col * n];
+                                let mut im = v[row + (col + 1) * n];
+                                if left {
+                                    im = -im;
+                                }
+                                eigvecs[row + col * n] = Self::complex(re, im);
+                                eigvecs[row + (col + 1) * n] = Self::complex(re, -im);
                             }
-                            eigvecs[row + col * n] = Self::complex(re, im);
-                            eigvecs[row + (col + 1) * n] = Self::complex(re, -im);
+                            col += 2;
                         }
-                        col += 2;
                     }
-                }
+                    eigvecs
+                };
+                let (vl, vr) = if left {
+                    (reconstruct(vr), reconstruct(vl))
+                } else {
+                    (reconstruct(vl), reconstruct(vr))
+                };
 
-                Ok((eigs, eigvecs))
+                Ok(EigExpertOutput {
+                    eigs,
+                    vr,
+                    vl,
+                    rconde: if calc_rcond { Some(rconde) } else { None },
+                    rcondv: if calc_rcond { Some(rcondv) } else { None },
+                })
             }
         }
     };
 }
 
-impl_eig_real!(f64, lapack::dgeev);
-impl_eig_real!(f32, lapack::sgeev);
+impl_eig_real!(f64, lapack::dgeevx);
+impl_eig_real!(f32, lapack::sgeevx);
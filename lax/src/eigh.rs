@@ -65,7 +65,7 @@ macro_rules! impl_eigh {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($ev))?;
 
                 // actual ev
                 let lwork = work_size[0].to_usize().unwrap();
@@ -84,7 +84,7 @@ macro_rules! impl_eigh {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($ev))?;
                 Ok(eigs)
             }
 
@@ -124,7 +124,7 @@ macro_rules! impl_eigh {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($evg))?;
 
                 // actual evg
                 let lwork = work_size[0].to_usize().unwrap();
@@ -146,7 +146,7 @@ macro_rules! impl_eigh {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($evg))?;
                 Ok(eigs)
             }
         }
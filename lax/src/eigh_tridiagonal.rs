@@ -0,0 +1,97 @@
+//! Eigenvalue decomposition for real symmetric tridiagonal matrices
+
+use crate::{error::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+pub trait EighTridiagonal_: Scalar {
+    /// Wraps `*stevd`, i.e. the divide-and-conquer eigensolver of a real symmetric tridiagonal matrix
+    ///
+    /// `d` is the diagonal of length `n` and is overwritten by the eigenvalues in ascending order.
+    /// `e` is the off-diagonal of length `n - 1` and is destroyed.
+    /// Returns the eigenvectors as the columns of a column-major `n x n` matrix if `calc_v`,
+    /// or an empty vector otherwise.
+    /// Since the matrix is real, the real routine of the same precision is used also for complex `Self`.
+    fn eigh_tridiagonal(
+        calc_v: bool,
+        d: &mut [Self::Real],
+        e: &mut [Self::Real],
+    ) -> Result<Vec<Self::Real>>;
+}
+
+macro_rules! impl_eigh_tridiagonal {
+    ($scalar:ty, $stevd:path) => {
+        impl EighTridiagonal_ for $scalar {
+            fn eigh_tridiagonal(
+                calc_v: bool,
+                d: &mut [Self::Real],
+                e: &mut [Self::Real],
+            ) -> Result<Vec<Self::Real>> {
+                let n = d.len() as i32;
+                assert_eq!(e.len() as i32, std::cmp::max(n - 1, 0));
+                if n == 0 {
+                    return Ok(Vec::new());
+                }
+                let jobz = if calc_v { b'V' } else { b'N' };
+                let ldz = if calc_v { n } else { 1 };
+                let mut z = if calc_v {
+                    unsafe { vec_uninit((n * n) as usize) }
+                } else {
+                    vec![Self::Real::zero()]
+                };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $stevd(
+                        jobz,
+                        n,
+                        d,
+                        e,
+                        &mut z,
+                        ldz,
+                        &mut work_size,
+                        -1,
+                        &mut iwork_size,
+                        -1,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result(stringify!($stevd))?;
+
+                // actual ev
+                let lwork = work_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                let mut iwork = unsafe { vec_uninit(liwork) };
+                unsafe {
+                    $stevd(
+                        jobz,
+                        n,
+                        d,
+                        e,
+                        &mut z,
+                        ldz,
+                        &mut work,
+                        lwork as i32,
+                        &mut iwork,
+                        liwork as i32,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result(stringify!($stevd))?;
+                if !calc_v {
+                    z.clear();
+                }
+                Ok(z)
+            }
+        }
+    };
+} // impl_eigh_tridiagonal!
+
+impl_eigh_tridiagonal!(f64, lapack::dstevd);
+impl_eigh_tridiagonal!(f32, lapack::sstevd);
+impl_eigh_tridiagonal!(c64, lapack::dstevd);
+impl_eigh_tridiagonal!(c32, lapack::sstevd);
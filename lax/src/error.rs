@@ -4,17 +4,28 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 
 #[derive(Error, Debug)]
 pub enum Error {
+    /// Negative `info` returned by LAPACK, i.e. an invalid argument is passed.
+    /// This is a bug of this crate.
     #[error(
-        "Invalid value for LAPACK subroutine {}-th argument",
+        "Invalid value for LAPACK subroutine {}: {}-th argument",
+        routine,
         -return_code
     )]
-    LapackInvalidValue { return_code: i32 },
+    LapackInvalidValue {
+        routine: &'static str,
+        return_code: i32,
+    },
 
+    /// Positive `info` returned by LAPACK, i.e. the computation fails for the input
     #[error(
-        "Comutational failure in LAPACK subroutine: return_code = {}",
+        "Comutational failure in LAPACK subroutine {}: return_code = {}",
+        routine,
         return_code
     )]
-    LapackComputationalFailure { return_code: i32 },
+    LapackComputationalFailure {
+        routine: &'static str,
+        return_code: i32,
+    },
 
     /// Strides of the array is not supported
     #[error("Invalid shape")]
@@ -22,16 +33,25 @@ pub enum Error {
 }
 
 pub trait AsLapackResult {
-    fn as_lapack_result(self) -> Result<()>;
+    /// Convert `info` returned by the LAPACK subroutine `routine` into `Result`
+    fn as_lapack_result(self, routine: &'static str) -> Result<()>;
 }
 
 impl AsLapackResult for i32 {
-    fn as_lapack_result(self) -> Result<()> {
+    fn as_lapack_result(self, routine: &'static str) -> Result<()> {
+        // Drop the module path of `stringify!(lapack::dgetrf)`
+        let routine = routine.rsplit("::").next().unwrap_or(routine).trim();
         if self > 0 {
-            return Err(Error::LapackComputationalFailure { return_code: self });
+            return Err(Error::LapackComputationalFailure {
+                routine,
+                return_code: self,
+            });
         }
         if self < 0 {
-            return Err(Error::LapackInvalidValue { return_code: self });
+            return Err(Error::LapackInvalidValue {
+                routine,
+                return_code: self,
+            });
         }
         Ok(())
     }
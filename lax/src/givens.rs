@@ -0,0 +1,43 @@
+//! Generate Givens rotations
+
+use cauchy::*;
+
+extern "C" {
+    // `lapack` and `lapack-sys` do not provide the auxiliary routine `*lartg`.
+    fn slartg_(f: *const f32, g: *const f32, c: *mut f32, s: *mut f32, r: *mut f32);
+    fn dlartg_(f: *const f64, g: *const f64, c: *mut f64, s: *mut f64, r: *mut f64);
+    fn clartg_(f: *const c32, g: *const c32, c: *mut f32, s: *mut c32, r: *mut c32);
+    fn zlartg_(f: *const c64, g: *const c64, c: *mut f64, s: *mut c64, r: *mut c64);
+}
+
+pub trait Givens_: Scalar {
+    /// Wrapper of `*lartg`, i.e. generate a plane rotation `(c, s, r)` such that
+    ///
+    /// ```text
+    /// [  c        s ] [ f ]   [ r ]
+    /// [ -conj(s)  c ] [ g ] = [ 0 ]
+    /// ```
+    ///
+    /// where `c` is real and `c^2 + |s|^2 = 1`.
+    /// This avoids the overflow and underflow of the naive formula as LAPACK does.
+    fn lartg(f: Self, g: Self) -> (Self::Real, Self, Self);
+}
+
+macro_rules! impl_givens {
+    ($scalar:ty, $lartg:path) => {
+        impl Givens_ for $scalar {
+            fn lartg(f: Self, g: Self) -> (Self::Real, Self, Self) {
+                let mut c = Self::real(0.0);
+                let mut s = Self::from_real(Self::real(0.0));
+                let mut r = s;
+                unsafe { $lartg(&f, &g, &mut c, &mut s, &mut r) };
+                (c, s, r)
+            }
+        }
+    };
+} // impl_givens!
+
+impl_givens!(f64, dlartg_);
+impl_givens!(f32, slartg_);
+impl_givens!(c64, zlartg_);
+impl_givens!(c32, clartg_);
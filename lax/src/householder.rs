@@ -0,0 +1,67 @@
+//! Generate and apply Householder reflectors
+
+use crate::{layout::*, *};
+use cauchy::*;
+use num_traits::{One, Zero};
+
+pub trait Householder_: Scalar {
+    /// Wrapper of `*larfg`, i.e. generate an elementary reflector `H = I - tau v v^H`
+    /// such that `H^H x = beta e_1` with real `beta`
+    ///
+    /// `x` is overwritten by `v` whose first element is one.
+    /// Returns `(tau, beta)`, and `tau = 0` if `x` is already a multiple of `e_1`.
+    fn reflector(x: &mut [Self]) -> (Self, Self::Real);
+
+    /// Apply an elementary reflector `H = I - tau v v^H` to `C` in place,
+    /// i.e. `C = H C` for `Side::Left` or `C = C H` for `Side::Right`, wrapper of `*larfx`
+    ///
+    /// The length of `v` is assumed to be consistent with the shape of `C`.
+    fn apply_reflector(side: Side, v: &[Self], tau: Self, l: MatrixLayout, c: &mut [Self]);
+}
+
+macro_rules! impl_householder {
+    ($scalar:ty, $larfg:path, $larfx:path) => {
+        impl Householder_ for $scalar {
+            fn reflector(x: &mut [Self]) -> (Self, Self::Real) {
+                let n = x.len() as i32;
+                let (alpha, x) = x.split_first_mut().expect("Empty vector");
+                let mut tau = [Self::zero()];
+                unsafe { $larfg(n, alpha, x, 1, &mut tau) };
+                let beta = alpha.re();
+                *alpha = Self::one();
+                (tau[0], beta)
+            }
+
+            fn apply_reflector(side: Side, v: &[Self], tau: Self, l: MatrixLayout, c: &mut [Self]) {
+                // A row-major matrix is read as its transpose, i.e. `(H C)^T = C^T H^T`,
+                // and `H^T` is the reflector of `conj(v)` with the same `tau`.
+                let (side, v) = match l {
+                    MatrixLayout::F { .. } => (side, v.to_vec()),
+                    MatrixLayout::C { .. } => {
+                        let side = match side {
+                            Side::Left => Side::Right,
+                            Side::Right => Side::Left,
+                        };
+                        (side, v.iter().map(|x| x.conj()).collect())
+                    }
+                };
+                let (m, n) = match l {
+                    MatrixLayout::F { col, lda } => (lda, col),
+                    MatrixLayout::C { row, lda } => (lda, row),
+                };
+                let mut work = unsafe {
+                    vec_uninit(match side {
+                        Side::Left => n as usize,
+                        Side::Right => m as usize,
+                    })
+                };
+                unsafe { $larfx(side as u8, m, n, &v, &[tau], c, l.lda(), &mut work) };
+            }
+        }
+    };
+} // impl_householder!
+
+impl_householder!(f64, lapack::dlarfg, lapack::dlarfx);
+impl_householder!(f32, lapack::slarfg, lapack::slarfx);
+impl_householder!(c64, lapack::zlarfg, lapack::zlarfx);
+impl_householder!(c32, lapack::clarfg, lapack::clarfx);
@@ -114,7 +114,7 @@ macro_rules! impl_least_squares {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gelsd))?;
 
                 // calc
                 let lwork = work_size[0].to_usize().unwrap();
@@ -144,7 +144,7 @@ macro_rules! impl_least_squares {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gelsd))?;
 
                 // Skip a_t -> a transpose because A has been destroyed
                 // Re-transpose b
@@ -43,10 +43,12 @@
 //! |:--------------------------------|:----------------|:------------------------------------|
 //! | General (GE)                    |[eig]            | -                                   |
 //! | Symmetric (SY) / Hermitian (HE) |[eigh]           |[eigh_generalized]                   |
+//! | Symmetric tridiagonal (ST)      |[eigh_tridiagonal]| -                                  |
 //!
 //! [eig]:              eig/trait.Eig_.html#tymethod.eig
 //! [eigh]:             eigh/trait.Eigh_.html#tymethod.eigh
 //! [eigh_generalized]: eigh/trait.Eigh_.html#tymethod.eigh_generalized
+//! [eigh_tridiagonal]: eigh_tridiagonal/trait.EighTridiagonal_.html#tymethod.eigh_tridiagonal
 //!
 //! Singular Value Decomposition (SVD), Least square problem
 //! ----------------------------------------------------------
@@ -58,6 +60,23 @@
 //! [svd]:   svd/trait.SVD_.html#tymethod.svd
 //! [svddc]: svddck/trait.SVDDC_.html#tymethod.svddc
 //! [least_squares]: least_squares/trait.LeastSquaresSvdDivideConquer_.html#tymethod.least_squares
+//!
+//! Matrix multiplication
+//! ----------------------
+//!
+//! BLAS level-3 routines are also wrapped through CBLAS, which is provided by the same backends as LAPACK.
+//! The level-1 [iamax] used for pivoting is also available.
+//!
+//! | matrix type                     | Matrix-matrix product (MM) | Rank-k update (RK) |
+//! |:--------------------------------|:---------------------------|:-------------------|
+//! | General (GE)                    | [gemm]                     | -                  |
+//! | Symmetric (SY) / Hermitian (HE) | -                          | [syrk]             |
+//! | Triangular (TR)                 | [trmm]                     | -                  |
+//!
+//! [gemm]: blas/trait.Blas_.html#tymethod.gemm
+//! [syrk]: blas/trait.Blas_.html#tymethod.syrk
+//! [trmm]: blas/trait.Blas_.html#tymethod.trmm
+//! [iamax]: blas/trait.Blas_.html#tymethod.iamax
 
 #[cfg(any(feature = "intel-mkl-system", feature = "intel-mkl-static"))]
 extern crate intel_mkl_src as _src;
@@ -71,33 +90,51 @@ extern crate netlib_src as _src;
 pub mod error;
 pub mod layout;
 
+mod balance;
+mod blas;
 mod cholesky;
 mod eig;
 mod eigh;
+mod eigh_tridiagonal;
+mod givens;
+mod householder;
 mod least_squares;
 mod opnorm;
 mod qr;
+mod qz;
 mod rcond;
+mod refine;
+mod schur;
 mod solve;
 mod solveh;
 mod svd;
 mod svddc;
 mod triangular;
 mod tridiagonal;
+mod tuning;
 
+pub use self::balance::*;
+pub use self::blas::*;
 pub use self::cholesky::*;
 pub use self::eig::*;
 pub use self::eigh::*;
+pub use self::eigh_tridiagonal::*;
+pub use self::givens::*;
+pub use self::householder::*;
 pub use self::least_squares::*;
 pub use self::opnorm::*;
 pub use self::qr::*;
+pub use self::qz::*;
 pub use self::rcond::*;
+pub use self::refine::*;
+pub use self::schur::*;
 pub use self::solve::*;
 pub use self::solveh::*;
 pub use self::svd::*;
 pub use self::svddc::*;
 pub use self::triangular::*;
 pub use self::tridiagonal::*;
+pub use self::tuning::*;
 
 use cauchy::*;
 
@@ -114,10 +151,20 @@ pub trait Lapack:
     + Cholesky_
     + Eig_
     + Eigh_
+    + EighTridiagonal_
     + Triangular_
     + Tridiagonal_
     + Rcond_
+    + Refine_
+    + Schur_
+    + Qz_
+    + Sylvester_
     + LeastSquaresSvdDivideConquer_
+    + Blas_
+    + Tuning_
+    + Givens_
+    + Householder_
+    + MatrixBalance_
 {
 }
 
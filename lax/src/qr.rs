@@ -26,6 +26,10 @@ macro_rules! impl_qr {
                 let n = l.len();
                 let k = m.min(n);
                 let mut tau = unsafe { vec_uninit(k as usize) };
+                let routine = match l {
+                    MatrixLayout::F { .. } => stringify!($qrf),
+                    MatrixLayout::C { .. } => stringify!($lqf),
+                };
 
                 // eval work size
                 let mut info = 0;
@@ -40,7 +44,7 @@ macro_rules! impl_qr {
                         }
                     }
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(routine)?;
 
                 // calc
                 let lwork = work_size[0].to_usize().unwrap();
@@ -73,7 +77,7 @@ macro_rules! impl_qr {
                         }
                     }
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(routine)?;
 
                 Ok(tau)
             }
@@ -83,6 +87,10 @@ macro_rules! impl_qr {
                 let n = l.len();
                 let k = m.min(n);
                 assert_eq!(tau.len(), k as usize);
+                let routine = match l {
+                    MatrixLayout::F { .. } => stringify!($gqr),
+                    MatrixLayout::C { .. } => stringify!($glq),
+                };
 
                 // eval work size
                 let mut info = 0;
@@ -111,7 +119,7 @@ macro_rules! impl_qr {
                         }
                     }
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(routine)?;
                 Ok(())
             }
 
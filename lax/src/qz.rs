@@ -0,0 +1,284 @@
+//! Generalized Schur (QZ) decomposition
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Which generalized eigenvalues `λ = α / β` are moved to the leading part of the QZ decomposition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QzSort {
+    /// Do not reorder
+    None,
+    /// `Re λ < 0`, i.e. the stable eigenvalues of a continuous-time system
+    LeftHalfPlane,
+    /// `|λ| < 1`, i.e. the stable eigenvalues of a discrete-time system
+    UnitDisk,
+}
+
+/// Result of [Qz_::qz]
+pub struct QzOutput<A: Scalar> {
+    /// Numerators `α` of the generalized eigenvalues in the order of the diagonal of `S` and `T`
+    pub alpha: Vec<A::Complex>,
+    /// Denominators `β` of the generalized eigenvalues, which are real and non-negative for real matrices
+    pub beta: Vec<A>,
+    /// The left Schur vectors `Q` as a column-major matrix
+    pub q: Vec<A>,
+    /// The right Schur vectors `Z` as a column-major matrix
+    pub z: Vec<A>,
+    /// The number of the eigenvalues selected by [QzSort], or zero for [QzSort::None]
+    pub sdim: usize,
+}
+
+pub trait Qz_: Scalar + Sized {
+    /// Computes the generalized Schur decomposition `A = Q S Z^H`, `B = Q T Z^H` using `*gges`
+    ///
+    /// `a` and `b` are overwritten by `S` and `T`. `T` is upper triangular, and `S` is upper triangular
+    /// for complex matrices and quasi-upper triangular with 2x2 blocks of complex conjugate eigenvalues
+    /// for real matrices.
+    ///
+    /// LAPACK sees a C layout matrix as its transpose, and thus `a` and `b` should be F layout.
+    fn qz(l: MatrixLayout, sort: QzSort, a: &mut [Self], b: &mut [Self]) -> Result<QzOutput<Self>>;
+}
+
+macro_rules! impl_qz_real {
+    ($scalar:ty, $gges:path) => {
+        impl Qz_ for $scalar {
+            fn qz(
+                l: MatrixLayout,
+                sort: QzSort,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<QzOutput<Self>> {
+                extern "C" fn left_half_plane(
+                    ar: *const $scalar,
+                    _ai: *const $scalar,
+                    b: *const $scalar,
+                ) -> i32 {
+                    let (ar, b) = unsafe { (*ar, *b) };
+                    (ar * b < 0.0) as i32
+                }
+                extern "C" fn unit_disk(
+                    ar: *const $scalar,
+                    ai: *const $scalar,
+                    b: *const $scalar,
+                ) -> i32 {
+                    let (ar, ai, b) = unsafe { (*ar, *ai, *b) };
+                    (ar.hypot(ai) < b.abs()) as i32
+                }
+                let (n, _) = l.size();
+                if n == 0 {
+                    return Ok(QzOutput {
+                        alpha: Vec::new(),
+                        beta: Vec::new(),
+                        q: Vec::new(),
+                        z: Vec::new(),
+                        sdim: 0,
+                    });
+                }
+                let (sort_flag, select): (u8, Option<extern "C" fn(_, _, _) -> i32>) = match sort {
+                    QzSort::None => (b'N', None),
+                    QzSort::LeftHalfPlane => (b'S', Some(left_half_plane)),
+                    QzSort::UnitDisk => (b'S', Some(unit_disk)),
+                };
+                let mut sdim = 0;
+                let mut alphar: Vec<Self> = unsafe { vec_uninit(n as usize) };
+                let mut alphai: Vec<Self> = unsafe { vec_uninit(n as usize) };
+                let mut beta: Vec<Self> = unsafe { vec_uninit(n as usize) };
+                let mut q = unsafe { vec_uninit((n * n) as usize) };
+                let mut z = unsafe { vec_uninit((n * n) as usize) };
+                let mut bwork = unsafe { vec_uninit(n as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gges(
+                        b'V',
+                        b'V',
+                        sort_flag,
+                        select,
+                        n,
+                        a,
+                        l.lda(),
+                        b,
+                        l.lda(),
+                        &mut sdim,
+                        &mut alphar[0],
+                        &mut alphai[0],
+                        &mut beta[0],
+                        &mut q,
+                        n,
+                        &mut z,
+                        n,
+                        &mut work_size,
+                        -1,
+                        &mut bwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gges))?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gges(
+                        b'V',
+                        b'V',
+                        sort_flag,
+                        select,
+                        n,
+                        a,
+                        l.lda(),
+                        b,
+                        l.lda(),
+                        &mut sdim,
+                        &mut alphar[0],
+                        &mut alphai[0],
+                        &mut beta[0],
+                        &mut q,
+                        n,
+                        &mut z,
+                        n,
+                        &mut work,
+                        lwork as i32,
+                        &mut bwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gges))?;
+
+                let alpha = alphar
+                    .iter()
+                    .zip(alphai.iter())
+                    .map(|(&re, &im)| Self::complex(re, im))
+                    .collect();
+                Ok(QzOutput {
+                    alpha,
+                    beta,
+                    q,
+                    z,
+                    sdim: sdim as usize,
+                })
+            }
+        }
+    };
+}
+
+impl_qz_real!(f64, lapack::dgges);
+impl_qz_real!(f32, lapack::sgges);
+
+macro_rules! impl_qz_complex {
+    ($scalar:ty, $gges:path) => {
+        impl Qz_ for $scalar {
+            fn qz(
+                l: MatrixLayout,
+                sort: QzSort,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<QzOutput<Self>> {
+                extern "C" fn left_half_plane(alpha: *const $scalar, beta: *const $scalar) -> i32 {
+                    let (alpha, beta) = unsafe { (*alpha, *beta) };
+                    ((alpha * beta.conj()).re < 0.0) as i32
+                }
+                extern "C" fn unit_disk(alpha: *const $scalar, beta: *const $scalar) -> i32 {
+                    let (alpha, beta) = unsafe { (*alpha, *beta) };
+                    (alpha.norm() < beta.norm()) as i32
+                }
+                let (n, _) = l.size();
+                if n == 0 {
+                    return Ok(QzOutput {
+                        alpha: Vec::new(),
+                        beta: Vec::new(),
+                        q: Vec::new(),
+                        z: Vec::new(),
+                        sdim: 0,
+                    });
+                }
+                let (sort_flag, select): (u8, Option<extern "C" fn(_, _) -> i32>) = match sort {
+                    QzSort::None => (b'N', None),
+                    QzSort::LeftHalfPlane => (b'S', Some(left_half_plane)),
+                    QzSort::UnitDisk => (b'S', Some(unit_disk)),
+                };
+                let mut sdim = 0;
+                let mut alpha: Vec<Self> = unsafe { vec_uninit(n as usize) };
+                let mut beta: Vec<Self> = unsafe { vec_uninit(n as usize) };
+                let mut q = unsafe { vec_uninit((n * n) as usize) };
+                let mut z = unsafe { vec_uninit((n * n) as usize) };
+                let mut rwork = unsafe { vec_uninit(8 * n as usize) };
+                let mut bwork = unsafe { vec_uninit(n as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gges(
+                        b'V',
+                        b'V',
+                        sort_flag,
+                        select,
+                        n,
+                        a,
+                        l.lda(),
+                        b,
+                        l.lda(),
+                        &mut sdim,
+                        &mut alpha[0],
+                        &mut beta[0],
+                        &mut q,
+                        n,
+                        &mut z,
+                        n,
+                        &mut work_size,
+                        -1,
+                        &mut rwork,
+                        &mut bwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gges))?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gges(
+                        b'V',
+                        b'V',
+                        sort_flag,
+                        select,
+                        n,
+                        a,
+                        l.lda(),
+                        b,
+                        l.lda(),
+                        &mut sdim,
+                        &mut alpha[0],
+                        &mut beta[0],
+                        &mut q,
+                        n,
+                        &mut z,
+                        n,
+                        &mut work,
+                        lwork as i32,
+                        &mut rwork,
+                        &mut bwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gges))?;
+
+                Ok(QzOutput {
+                    alpha,
+                    beta,
+                    q,
+                    z,
+                    sdim: sdim as usize,
+                })
+            }
+        }
+    };
+}
+
+impl_qz_complex!(c64, lapack::zgges);
+impl_qz_complex!(c32, lapack::cgges);
@@ -7,10 +7,21 @@ pub trait Rcond_: Scalar + Sized {
     ///
     /// `anorm` should be the 1-norm of the matrix `a`.
     fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real>;
+
+    /// Estimates the reciprocal of the condition number in 1-norm of the Hermitian (or real symmetric)
+    /// positive definite matrix from its Cholesky factor `a` computed by `*potrf`, i.e. wrapper of `*pocon`.
+    ///
+    /// `anorm` should be the 1-norm of the original matrix.
+    fn rcond_cholesky(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        anorm: Self::Real,
+    ) -> Result<Self::Real>;
 }
 
 macro_rules! impl_rcond_real {
-    ($scalar:ty, $gecon:path) => {
+    ($scalar:ty, $gecon:path, $pocon:path) => {
         impl Rcond_ for $scalar {
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 let (n, _) = l.size();
@@ -36,7 +47,41 @@ macro_rules! impl_rcond_real {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gecon))?;
+
+                Ok(rcond)
+            }
+
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(3 * n as usize) };
+                let mut iwork = unsafe { vec_uninit(n as usize) };
+                // The transposed factor of the C layout is that of the other triangle
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                unsafe {
+                    $pocon(
+                        uplo as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        anorm,
+                        &mut rcond,
+                        &mut work,
+                        &mut iwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($pocon))?;
 
                 Ok(rcond)
             }
@@ -44,11 +89,11 @@ macro_rules! impl_rcond_real {
     };
 }
 
-impl_rcond_real!(f32, lapack::sgecon);
-impl_rcond_real!(f64, lapack::dgecon);
+impl_rcond_real!(f32, lapack::sgecon, lapack::spocon);
+impl_rcond_real!(f64, lapack::dgecon, lapack::dpocon);
 
 macro_rules! impl_rcond_complex {
-    ($scalar:ty, $gecon:path) => {
+    ($scalar:ty, $gecon:path, $pocon:path) => {
         impl Rcond_ for $scalar {
             fn rcond(l: MatrixLayout, a: &[Self], anorm: Self::Real) -> Result<Self::Real> {
                 let (n, _) = l.size();
@@ -73,7 +118,41 @@ macro_rules! impl_rcond_complex {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gecon))?;
+
+                Ok(rcond)
+            }
+
+            fn rcond_cholesky(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                anorm: Self::Real,
+            ) -> Result<Self::Real> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                let mut work = unsafe { vec_uninit(2 * n as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+                // The transposed factor of the C layout is that of the other triangle
+                let uplo = match l {
+                    MatrixLayout::C { .. } => uplo.t(),
+                    MatrixLayout::F { .. } => uplo,
+                };
+                unsafe {
+                    $pocon(
+                        uplo as u8,
+                        n,
+                        a,
+                        l.lda(),
+                        anorm,
+                        &mut rcond,
+                        &mut work,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($pocon))?;
 
                 Ok(rcond)
             }
@@ -81,5 +160,5 @@ macro_rules! impl_rcond_complex {
     };
 }
 
-impl_rcond_complex!(c32, lapack::cgecon);
-impl_rcond_complex!(c64, lapack::zgecon);
+impl_rcond_complex!(c32, lapack::cgecon, lapack::cpocon);
+impl_rcond_complex!(c64, lapack::zgecon, lapack::zpocon);
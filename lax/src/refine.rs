@@ -0,0 +1,123 @@
+//! Iterative refinement of the solution of a linear problem
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{One, Zero};
+
+/// Error bounds of the refined solution returned by [Refine_::refine]
+#[derive(Debug, Clone, Copy)]
+pub struct RefineErrorBounds<A> {
+    /// Reciprocal condition number of the row-scaled matrix
+    pub rcond: A,
+    /// Componentwise relative backward error
+    pub backward_error: A,
+    /// Bound of the normwise relative forward error `|x - x_true|_inf / |x|_inf`
+    pub normwise: A,
+    /// Bound of the componentwise relative forward error `max_i |x_i - x_true_i| / |x_i|`
+    pub componentwise: A,
+    /// Whether `normwise` is guaranteed, i.e. the matrix is not too ill-conditioned
+    pub normwise_trusted: bool,
+    /// Whether `componentwise` is guaranteed
+    pub componentwise_trusted: bool,
+}
+
+pub trait Refine_: Scalar + Sized {
+    /// Refine the solution `x` of `A x = b` with the LU factorization `af` and the pivots of `A`
+    /// using `*gerfsx`
+    ///
+    /// The residual is computed in extended precision if LAPACK is linked with XBLAS,
+    /// and in the working precision otherwise.
+    fn refine(
+        l: MatrixLayout,
+        a: &[Self],
+        af: &[Self],
+        ipiv: &Pivot,
+        b: &[Self],
+        x: &mut [Self],
+    ) -> Result<RefineErrorBounds<Self::Real>>;
+}
+
+// LAPACK sees a C layout matrix as its transpose, and thus solves the transposed problem
+fn refine_transpose(l: MatrixLayout) -> Transpose {
+    match l {
+        MatrixLayout::C { .. } => Transpose::Transpose,
+        MatrixLayout::F { .. } => Transpose::No,
+    }
+}
+
+// Number of the kinds of the error bounds, i.e. the trust flag, the bound, and the condition number
+const N_ERR_BNDS: usize = 3;
+
+macro_rules! impl_refine {
+    ($scalar:ty, $gerfsx:path, $work_size:expr, $rwork_size:expr) => {
+        impl Refine_ for $scalar {
+            fn refine(
+                l: MatrixLayout,
+                a: &[Self],
+                af: &[Self],
+                ipiv: &Pivot,
+                b: &[Self],
+                x: &mut [Self],
+            ) -> Result<RefineErrorBounds<Self::Real>> {
+                let (n, _) = l.size();
+                let mut rcond = Self::Real::zero();
+                let mut berr = [Self::Real::zero()];
+                let mut err_bnds_norm = [Self::Real::zero(); N_ERR_BNDS];
+                let mut err_bnds_comp = [Self::Real::zero(); N_ERR_BNDS];
+                // Default parameters, i.e. the refinement in extended precision if possible
+                let mut params = [Self::Real::zero()];
+                let mut work = unsafe { vec_uninit($work_size * n as usize) };
+                // `iwork` for real and `rwork` for complex
+                let mut rwork = unsafe { vec_uninit($rwork_size * n as usize) };
+                let mut info = 0;
+                unsafe {
+                    $gerfsx(
+                        refine_transpose(l) as u8,
+                        b'N',
+                        n,
+                        1,
+                        a,
+                        l.lda(),
+                        af,
+                        l.lda(),
+                        ipiv,
+                        &[],
+                        &[],
+                        b,
+                        n,
+                        x,
+                        n,
+                        &mut rcond,
+                        &mut berr,
+                        N_ERR_BNDS as i32,
+                        &mut err_bnds_norm,
+                        &mut err_bnds_comp,
+                        &[0],
+                        &mut params,
+                        &mut work,
+                        &mut rwork,
+                        &mut info,
+                    )
+                };
+                // `info > n` reports that the error bounds are not guaranteed,
+                // which is returned as the trust flags
+                if info <= n {
+                    info.as_lapack_result(stringify!($gerfsx))?;
+                }
+                Ok(RefineErrorBounds {
+                    rcond,
+                    backward_error: berr[0],
+                    normwise: err_bnds_norm[1],
+                    componentwise: err_bnds_comp[1],
+                    normwise_trusted: err_bnds_norm[0] == Self::Real::one(),
+                    componentwise_trusted: err_bnds_comp[0] == Self::Real::one(),
+                })
+            }
+        }
+    };
+} // impl_refine!
+
+impl_refine!(f64, lapack::dgerfsx, 4, 1);
+impl_refine!(f32, lapack::sgerfsx, 4, 1);
+impl_refine!(c64, lapack::zgerfsx, 2, 2);
+impl_refine!(c32, lapack::cgerfsx, 2, 2);
@@ -0,0 +1,228 @@
+//! Schur decomposition and Sylvester equation of (quasi-)triangular matrices
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{One, ToPrimitive, Zero};
+
+pub trait Schur_: Scalar + Sized {
+    /// Computes the Schur decomposition `A = Z T Z^H` using `*gees` without reordering
+    ///
+    /// `a` is overwritten by `T`, which is upper triangular for complex matrices,
+    /// and quasi-upper triangular for real matrices with 2x2 blocks of complex conjugate eigenvalues.
+    /// Returns the eigenvalues in the order of the diagonal of `T`,
+    /// and the unitary `Z` as a column-major matrix.
+    ///
+    /// LAPACK sees a C layout matrix as its transpose, and thus `a` should be F layout.
+    fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)>;
+}
+
+pub trait Sylvester_: Scalar + Sized {
+    /// Solves the Sylvester equation `op(A) X + isgn X op(B) = scale C` using `*trsyl`,
+    /// where `A` and `B` are the `T` of [Schur_::schur]
+    ///
+    /// `A` is `m x m`, `B` is `n x n`, and `C` is `m x n`, all column-major.
+    /// `isgn` is `1` or `-1`, and the transposes are `Transpose::No` or `Transpose::Hermite`.
+    /// `c` is overwritten by `X`, and `scale <= 1` chosen to avoid overflow is returned.
+    #[allow(clippy::too_many_arguments)]
+    fn sylvester(
+        trans_a: Transpose,
+        trans_b: Transpose,
+        isgn: i32,
+        m: i32,
+        n: i32,
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) -> Result<Self::Real>;
+}
+
+macro_rules! impl_schur_real {
+    ($scalar:ty, $gees:path) => {
+        impl Schur_ for $scalar {
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if n == 0 {
+                    return Ok((Vec::new(), Vec::new()));
+                }
+                let mut sdim = 0;
+                let mut wr = unsafe { vec_uninit(n as usize) };
+                let mut wi = unsafe { vec_uninit(n as usize) };
+                let mut vs = unsafe { vec_uninit((n * n) as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a,
+                        l.lda(),
+                        &mut sdim,
+                        &mut wr,
+                        &mut wi,
+                        &mut vs,
+                        n,
+                        &mut work_size,
+                        -1,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gees))?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a,
+                        l.lda(),
+                        &mut sdim,
+                        &mut wr,
+                        &mut wi,
+                        &mut vs,
+                        n,
+                        &mut work,
+                        lwork as i32,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gees))?;
+
+                let eigs = wr
+                    .iter()
+                    .zip(wi.iter())
+                    .map(|(&re, &im)| Self::complex(re, im))
+                    .collect();
+                Ok((eigs, vs))
+            }
+        }
+    };
+}
+
+impl_schur_real!(f64, lapack::dgees);
+impl_schur_real!(f32, lapack::sgees);
+
+macro_rules! impl_schur_complex {
+    ($scalar:ty, $gees:path) => {
+        impl Schur_ for $scalar {
+            fn schur(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self::Complex>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if n == 0 {
+                    return Ok((Vec::new(), Vec::new()));
+                }
+                let mut sdim = 0;
+                let mut w = unsafe { vec_uninit(n as usize) };
+                let mut vs = unsafe { vec_uninit((n * n) as usize) };
+                let mut rwork = unsafe { vec_uninit(n as usize) };
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a,
+                        l.lda(),
+                        &mut sdim,
+                        &mut w,
+                        &mut vs,
+                        n,
+                        &mut work_size,
+                        -1,
+                        &mut rwork,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gees))?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work = unsafe { vec_uninit(lwork) };
+                unsafe {
+                    $gees(
+                        b'V',
+                        b'N',
+                        None,
+                        n,
+                        a,
+                        l.lda(),
+                        &mut sdim,
+                        &mut w,
+                        &mut vs,
+                        n,
+                        &mut work,
+                        lwork as i32,
+                        &mut rwork,
+                        &mut [],
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($gees))?;
+                Ok((w, vs))
+            }
+        }
+    };
+}
+
+impl_schur_complex!(c64, lapack::zgees);
+impl_schur_complex!(c32, lapack::cgees);
+
+macro_rules! impl_sylvester {
+    ($scalar:ty, $trsyl:path) => {
+        impl Sylvester_ for $scalar {
+            fn sylvester(
+                trans_a: Transpose,
+                trans_b: Transpose,
+                isgn: i32,
+                m: i32,
+                n: i32,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+            ) -> Result<Self::Real> {
+                if m == 0 || n == 0 {
+                    return Ok(Self::Real::one());
+                }
+                let mut scale = [Self::Real::zero()];
+                let mut info = 0;
+                unsafe {
+                    $trsyl(
+                        trans_a as u8,
+                        trans_b as u8,
+                        &[isgn],
+                        m,
+                        n,
+                        a,
+                        m,
+                        b,
+                        n,
+                        c,
+                        m,
+                        &mut scale,
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result(stringify!($trsyl))?;
+                Ok(scale[0])
+            }
+        }
+    };
+}
+
+impl_sylvester!(f64, lapack::dtrsyl);
+impl_sylvester!(f32, lapack::strsyl);
+impl_sylvester!(c64, lapack::ztrsyl);
+impl_sylvester!(c32, lapack::ctrsyl);
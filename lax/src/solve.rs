@@ -1,6 +1,6 @@
 //! Solve linear problem using LU decomposition
 
-use crate::{error::*, layout::MatrixLayout, *};
+use crate::{error::*, layout::*, *};
 use cauchy::*;
 use num_traits::{ToPrimitive, Zero};
 
@@ -20,6 +20,22 @@ pub trait Solve_: Scalar + Sized {
     fn inv(l: MatrixLayout, a: &mut [Self], p: &Pivot) -> Result<()>;
 
     fn solve(l: MatrixLayout, t: Transpose, a: &[Self], p: &Pivot, b: &mut [Self]) -> Result<()>;
+
+    /// Solves `op(A) X = B` for the multiple right-hand sides `B`
+    /// using the LU factorization of `A` by [Solve_::lu]
+    ///
+    /// `A` and `B` may have the different layouts.
+    /// A C-layout `A` is handled by switching `t` as [Solve_::solve] does without copy,
+    /// while a C-layout `B` is transposed into a temporary buffer
+    /// since `*getrs` accepts only the Fortran layout of `B`.
+    fn solve_nrhs(
+        l: MatrixLayout,
+        t: Transpose,
+        a: &[Self],
+        p: &Pivot,
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
 }
 
 macro_rules! impl_solve {
@@ -36,7 +52,7 @@ macro_rules! impl_solve {
                 let mut ipiv = unsafe { vec_uninit(k as usize) };
                 let mut info = 0;
                 unsafe { $getrf(l.lda(), l.len(), a, l.lda(), &mut ipiv, &mut info) };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($getrf))?;
                 Ok(ipiv)
             }
 
@@ -51,7 +67,7 @@ macro_rules! impl_solve {
                 let mut info = 0;
                 let mut work_size = [Self::zero()];
                 unsafe { $getri(n, a, l.lda(), ipiv, &mut work_size, -1, &mut info) };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($getri))?;
 
                 // actual
                 let lwork = work_size[0].to_usize().unwrap();
@@ -67,7 +83,7 @@ macro_rules! impl_solve {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($getri))?;
 
                 Ok(())
             }
@@ -122,7 +138,68 @@ macro_rules! impl_solve {
                         *b_elem = b_elem.conj();
                     }
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($getrs))?;
+                Ok(())
+            }
+
+            fn solve_nrhs(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &[Self],
+                ipiv: &Pivot,
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                // Switch `t` for a C-layout `A` in the same way as `solve`
+                let (t, conj) = match l {
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => (Transpose::Transpose, false),
+                        Transpose::Transpose => (Transpose::No, false),
+                        Transpose::Hermite => (Transpose::No, true),
+                    },
+                    MatrixLayout::F { .. } => (t, false),
+                };
+                let (n, _) = l.size();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        b_t = Some(unsafe { vec_uninit(b.len()) });
+                        transpose(b_layout, b, b_t.as_mut().unwrap())
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                assert_eq!(ldb, n);
+                let b_f = b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(&mut *b);
+                if conj {
+                    for b_elem in &mut *b_f {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+                let mut info = 0;
+                unsafe {
+                    $getrs(
+                        t as u8,
+                        n,
+                        nrhs,
+                        a,
+                        l.lda(),
+                        ipiv,
+                        b_f,
+                        ldb.max(1),
+                        &mut info,
+                    )
+                };
+                if conj {
+                    for b_elem in &mut *b_f {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+                info.as_lapack_result(stringify!($getrs))?;
+                if let Some(b_t) = b_t {
+                    transpose(b_layout, &b_t, b);
+                }
                 Ok(())
             }
         }
@@ -6,6 +6,8 @@ use crate::{error::*, layout::MatrixLayout, *};
 use cauchy::*;
 use num_traits::{ToPrimitive, Zero};
 
+/// `uplo` specifies the triangle of the matrix in the given layout `l`,
+/// i.e. the other triangle is read by LAPACK for the C layout.
 pub trait Solveh_: Sized {
     /// Bunch-Kaufman: wrapper of `*sytrf` and `*hetrf`
     fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot>;
@@ -15,11 +17,20 @@ pub trait Solveh_: Sized {
     fn solveh(l: MatrixLayout, uplo: UPLO, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
 }
 
+/// LAPACK reads the C layout matrix as its transpose, where the triangles are flipped
+fn lapack_uplo(l: MatrixLayout, uplo: UPLO) -> UPLO {
+    match l {
+        MatrixLayout::C { .. } => uplo.t(),
+        MatrixLayout::F { .. } => uplo,
+    }
+}
+
 macro_rules! impl_solveh {
     ($scalar:ty, $trf:path, $tri:path, $trs:path) => {
         impl Solveh_ for $scalar {
             fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot> {
                 let (n, _) = l.size();
+                let uplo = lapack_uplo(l, uplo);
                 let mut ipiv = unsafe { vec_uninit(n as usize) };
                 if n == 0 {
                     return Ok(Vec::new());
@@ -40,7 +51,7 @@ macro_rules! impl_solveh {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($trf))?;
 
                 // actual
                 let lwork = work_size[0].to_usize().unwrap();
@@ -57,16 +68,17 @@ macro_rules! impl_solveh {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($trf))?;
                 Ok(ipiv)
             }
 
             fn invh(l: MatrixLayout, uplo: UPLO, a: &mut [Self], ipiv: &Pivot) -> Result<()> {
                 let (n, _) = l.size();
+                let uplo = lapack_uplo(l, uplo);
                 let mut info = 0;
                 let mut work = unsafe { vec_uninit(n as usize) };
                 unsafe { $tri(uplo as u8, n, a, l.lda(), ipiv, &mut work, &mut info) };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($tri))?;
                 Ok(())
             }
 
@@ -78,9 +90,18 @@ macro_rules! impl_solveh {
                 b: &mut [Self],
             ) -> Result<()> {
                 let (n, _) = l.size();
+                let uplo = lapack_uplo(l, uplo);
+                // The transpose of a Hermitian matrix is its conjugate
+                let conj = matches!(l, MatrixLayout::C { .. });
+                if conj {
+                    b.iter_mut().for_each(|v| *v = v.conj());
+                }
                 let mut info = 0;
                 unsafe { $trs(uplo as u8, n, 1, a, l.lda(), ipiv, b, n, &mut info) };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($trs))?;
+                if conj {
+                    b.iter_mut().for_each(|v| *v = v.conj());
+                }
                 Ok(())
             }
         }
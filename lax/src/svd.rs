@@ -100,7 +100,7 @@ macro_rules! impl_svd {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gesvd))?;
 
                 // calc
                 let lwork = work_size[0].to_usize().unwrap();
@@ -124,7 +124,7 @@ macro_rules! impl_svd {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gesvd))?;
                 match l {
                     MatrixLayout::F { .. } => Ok(SVDOutput { s, u, vt }),
                     MatrixLayout::C { .. } => Ok(SVDOutput { s, u: vt, vt: u }),
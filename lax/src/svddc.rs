@@ -84,7 +84,7 @@ macro_rules! impl_svddc {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gesdd))?;
 
                 // do svd
                 let lwork = work_size[0].to_usize().unwrap();
@@ -108,7 +108,7 @@ macro_rules! impl_svddc {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gesdd))?;
 
                 match l {
                     MatrixLayout::F { .. } => Ok(SVDOutput { s, u, vt }),
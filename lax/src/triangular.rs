@@ -72,7 +72,7 @@ macro_rules! impl_triangular {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($trtrs))?;
 
                 // Re-transpose b
                 if let Some(b_t) = b_t {
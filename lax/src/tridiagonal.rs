@@ -158,7 +158,7 @@ macro_rules! impl_tridiagonal {
                 let a_opnorm_one = a.opnorm_one();
                 let mut info = 0;
                 unsafe { $gttrf(n, &mut a.dl, &mut a.d, &mut a.du, &mut du2, &mut ipiv, &mut info,) };
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gttrf))?;
                 Ok(LUFactorizedTridiagonal {
                     a,
                     du2,
@@ -192,7 +192,7 @@ macro_rules! impl_tridiagonal {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gtcon))?;
                 Ok(rcond)
             }
 
@@ -230,7 +230,7 @@ macro_rules! impl_tridiagonal {
                         &mut info,
                     );
                 }
-                info.as_lapack_result()?;
+                info.as_lapack_result(stringify!($gttrs))?;
                 if let Some(b_t) = b_t {
                     transpose(b_layout, &b_t, b);
                 }
@@ -0,0 +1,69 @@
+//! Query tuning parameters of the LAPACK implementation
+
+use crate::error::*;
+use cauchy::*;
+use std::os::raw::c_char;
+
+extern "C" {
+    // `lapack-sys` does not provide `ilaenv`.
+    // The lengths of the character arguments are passed at the end as the Fortran ABI.
+    fn ilaenv_(
+        ispec: *const i32,
+        name: *const c_char,
+        opts: *const c_char,
+        n1: *const i32,
+        n2: *const i32,
+        n3: *const i32,
+        n4: *const i32,
+        name_len: usize,
+        opts_len: usize,
+    ) -> i32;
+}
+
+pub trait Tuning_: Scalar {
+    /// Optimal block size for a LAPACK routine, wrapper of `ilaenv` with `ISPEC = 1`
+    ///
+    /// `routine` is the name of the routine without the type prefix, e.g. `"GETRF"`,
+    /// and `m`, `n` are the problem dimensions.
+    /// Returns 1 if the routine does not use blocking or is unknown to the implementation.
+    fn optimal_block_size(routine: &str, m: i32, n: i32) -> Result<i32>;
+}
+
+macro_rules! impl_tuning {
+    ($scalar:ty, $prefix:expr) => {
+        impl Tuning_ for $scalar {
+            fn optimal_block_size(routine: &str, m: i32, n: i32) -> Result<i32> {
+                let mut name = vec![$prefix];
+                name.extend(routine.to_ascii_uppercase().bytes());
+                let opts = b" ";
+                let ispec = 1;
+                let unused = -1;
+                let nb = unsafe {
+                    ilaenv_(
+                        &ispec,
+                        name.as_ptr() as *const c_char,
+                        opts.as_ptr() as *const c_char,
+                        &m,
+                        &n,
+                        &unused,
+                        &unused,
+                        name.len(),
+                        opts.len(),
+                    )
+                };
+                if nb < 0 {
+                    return Err(Error::LapackInvalidValue {
+                        routine: "ilaenv",
+                        return_code: nb,
+                    });
+                }
+                Ok(nb)
+            }
+        }
+    };
+} // impl_tuning!
+
+impl_tuning!(f64, b'D');
+impl_tuning!(f32, b'S');
+impl_tuning!(c64, b'Z');
+impl_tuning!(c32, b'C');
@@ -0,0 +1,119 @@
+//! Solve systems of linear equations by the solver chosen from the structure of the matrix
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::cholesky::*;
+use crate::error::*;
+use crate::properties::*;
+use crate::solve::*;
+use crate::solveh::*;
+use crate::triangular::*;
+use crate::tridiagonal::*;
+use crate::types::*;
+
+/// Solver chosen by [AutoSolve]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverKind {
+    /// Back substitution of an upper triangular matrix by `*trtrs`
+    UpperTriangular,
+    /// Forward substitution of a lower triangular matrix by `*trtrs`
+    LowerTriangular,
+    /// LU decomposition of a tridiagonal matrix by `*gttrf`
+    Tridiagonal,
+    /// Cholesky decomposition of a Hermitian positive definite matrix by `*potrf`
+    Cholesky,
+    /// Bunch-Kaufman decomposition of a Hermitian matrix by `*sytrf` or `*hetrf`
+    BunchKaufman,
+    /// LU decomposition of a general matrix by `*getrf`
+    LU,
+}
+
+/// Solve `A * x = b` by the cheapest applicable solver
+///
+/// The structure of `A` is detected by the `O(n^2)` scans of [MatrixProperties]
+/// in the following order:
+///
+/// 1. triangular matrices are solved by substitution,
+/// 2. tridiagonal matrices (larger than `2 x 2`) by the tridiagonal LU decomposition,
+/// 3. Hermitian matrices with positive diagonal elements by the Cholesky decomposition,
+///    which falls back to the Bunch-Kaufman decomposition if `A` is not positive definite,
+/// 4. other Hermitian matrices by the Bunch-Kaufman decomposition,
+/// 5. and the others by the LU decomposition.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]];
+/// assert_eq!(a.solver_kind(), SolverKind::Tridiagonal);
+/// let (x, kind) = a.auto_solve(&array![1.0, 2.0, 3.0]).unwrap();
+/// assert_eq!(kind, SolverKind::Tridiagonal);
+/// assert!(a.dot(&x).abs_diff_eq(&array![1.0, 2.0, 3.0], 1e-9));
+/// ```
+pub trait AutoSolve<A: Scalar> {
+    /// The solver [AutoSolve::auto_solve] tries first
+    fn solver_kind(&self) -> SolverKind;
+
+    /// Solves `A * x = b`, and returns `x` with the solver actually used
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn auto_solve<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<(Array1<A>, SolverKind)>;
+}
+
+impl<A, S> AutoSolve<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solver_kind(&self) -> SolverKind {
+        if !self.is_square() {
+            return SolverKind::LU;
+        }
+        match self.is_triangular() {
+            Some(UPLO::Upper) => return SolverKind::UpperTriangular,
+            Some(UPLO::Lower) => return SolverKind::LowerTriangular,
+            None => {}
+        }
+        if self.nrows() > 2 && self.is_banded() == Some((1, 1)) {
+            return SolverKind::Tridiagonal;
+        }
+        if self.is_hermitian() {
+            if self.diag().iter().all(|d| d.re() > A::Real::zero()) {
+                return SolverKind::Cholesky;
+            }
+            return SolverKind::BunchKaufman;
+        }
+        SolverKind::LU
+    }
+
+    fn auto_solve<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<(Array1<A>, SolverKind)> {
+        let kind = self.solver_kind();
+        let b = b.to_owned();
+        let x = match kind {
+            SolverKind::UpperTriangular => self.solve_triangular(UPLO::Upper, Diag::NonUnit, &b)?,
+            SolverKind::LowerTriangular => self.solve_triangular(UPLO::Lower, Diag::NonUnit, &b)?,
+            SolverKind::Tridiagonal => self.solve_tridiagonal(&b)?,
+            SolverKind::Cholesky => match self.solvec(&b) {
+                Ok(x) => x,
+                // not positive definite
+                Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure {
+                    ..
+                })) => return Ok((self.solveh(&b)?, SolverKind::BunchKaufman)),
+                Err(e) => return Err(e),
+            },
+            SolverKind::BunchKaufman => self.solveh(&b)?,
+            SolverKind::LU => self.solve(&b)?,
+        };
+        Ok((x, kind))
+    }
+}
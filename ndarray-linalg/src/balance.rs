@@ -0,0 +1,51 @@
+//! Balancing of general matrices
+//!
+//! The diagonal similarity transformation `D^-1 A D` equalizes the row and column norms
+//! of a badly scaled matrix, which keeps the eigenvalues and improves the accuracy of
+//! e.g. iterative methods. See [EigBalanced](crate::eig::EigBalanced) for the eigenvalue
+//! problems with balancing.
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Balance a square matrix by a diagonal similarity transformation using `*gebal`
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 1e4], [1e-4, 1.0]];
+/// let (b, d) = a.balance().unwrap();
+/// // undo the scaling `B = D^-1 A D`
+/// let a2 = Array2::from_shape_fn((2, 2), |(i, j)| b[(i, j)] * d[i] / d[j]);
+/// assert!(a2.abs_diff_eq(&a, 1e-9));
+/// assert!(b[(0, 1)].abs() < 1e4);
+/// ```
+pub trait MatrixBalance {
+    type Output;
+    type Scale;
+    /// Returns the balanced matrix `B = D^-1 A D` and the diagonal elements of `D`
+    ///
+    /// No permutation is applied, and the factors are powers of two, i.e. the scaling is exact.
+    /// Returns `NotSquare` error if the matrix is not square.
+    fn balance(&self) -> Result<(Self::Output, Self::Scale)>;
+}
+
+impl<A, S> MatrixBalance for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Scale = Array1<A::Real>;
+
+    fn balance(&self) -> Result<(Self::Output, Self::Scale)> {
+        self.ensure_square()?;
+        let mut a = self.to_owned();
+        let scale = A::balance(a.square_layout()?, a.as_allocated_mut()?)?;
+        Ok((a, Array1::from(scale)))
+    }
+}
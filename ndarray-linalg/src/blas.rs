@@ -0,0 +1,593 @@
+//! Convenience wrappers of BLAS routines
+//!
+//! Unlike `dot`, these routines accumulate the result into an existing matrix
+//! with the scaling factors `alpha` and `beta`, without allocating temporaries.
+//! [ger] and [gerc] are rank-1 updates without allocating the outer product,
+//! [axpy] and [scal] are the vector primitives of iterative solvers,
+//! and [Iamax] finds the pivot element in the same way as LAPACK.
+//! [BlasDot] is the allocating product like `dot`, which always calls BLAS
+//! `*dot`, `*gemv` or `*gemm` by the shapes of the operands.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1., 2.], [3., 4.]];
+//! let b: Array2<f64> = array![[1., 0.], [1., 1.]];
+//! let mut c: Array2<f64> = Array2::eye(2);
+//!
+//! // C = 2 A B - C
+//! gemm(2.0, &a, &b, -1.0, &mut c).unwrap();
+//! assert!(c.abs_diff_eq(&array![[5., 4.], [14., 7.]], 1e-9));
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+pub use lax::{Diag, Side, Transpose, UPLO};
+
+/// Call `f` with a non-empty vector `x` as a contiguous slice for BLAS
+///
+/// A contiguous vector is read in place, while a strided view, e.g. a column of a row-major matrix,
+/// is copied, since a slice over the strided elements would also cover the elements in between,
+/// which may be borrowed mutably by other views.
+fn with_blas_vector<A, S, T>(x: &ArrayBase<S, Ix1>, f: impl FnOnce(&[A]) -> T) -> T
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    match x.as_slice() {
+        Some(x) => f(x),
+        None => f(&x.to_vec()),
+    }
+}
+
+/// Mutable version of [with_blas_vector]
+///
+/// A strided view is copied and the result is written back after `f` returns.
+fn with_blas_vector_mut<A, S, T>(x: &mut ArrayBase<S, Ix1>, f: impl FnOnce(&mut [A]) -> T) -> T
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    if let Some(x) = x.as_slice_mut() {
+        return f(x);
+    }
+    let mut copy = x.to_vec();
+    let t = f(&mut copy);
+    x.iter_mut().zip(copy).for_each(|(x, c)| *x = c);
+    t
+}
+
+/// General matrix-matrix product with accumulation `C = alpha * A * B + beta * C`
+///
+/// This maps directly to BLAS `*gemm`.
+/// `A`, `B` and `C` may have different (C- or Fortran-contiguous) layouts,
+/// e.g. `a.t()` can be used to multiply by the transpose.
+///
+/// Returns `IncompatibleShape` error if the shapes of `A`, `B` and `C` do not match,
+/// and `InvalidStride` or `MemoryNotCont` errors if any of them is not contiguous.
+pub fn gemm<A, Sa, Sb, Sc>(
+    alpha: A,
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    beta: A,
+    c: &mut ArrayBase<Sc, Ix2>,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: DataMut<Elem = A>,
+{
+    let (m, k) = a.dim();
+    let (k_, n) = b.dim();
+    if k != k_ || c.dim() != (m, n) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if m == 0 || n == 0 || k == 0 {
+        // `C` is not read if `beta` is zero as in BLAS
+        if beta.is_zero() {
+            c.fill(A::zero());
+        } else {
+            c.mapv_inplace(|x| x * beta);
+        }
+        return Ok(());
+    }
+    let c_layout = c.layout()?;
+    A::gemm(
+        alpha,
+        a.layout()?,
+        a.as_allocated()?,
+        b.layout()?,
+        b.as_allocated()?,
+        beta,
+        c_layout,
+        c.as_allocated_mut()?,
+    );
+    Ok(())
+}
+
+/// General matrix-vector product with accumulation `y = alpha * A * x + beta * y`
+///
+/// This maps directly to BLAS `*gemv`.
+/// `A` may be either C- or Fortran-contiguous, e.g. `a.t()` can be used to multiply by the transpose,
+/// while `x` and `y` may be strided, in which case they are copied for BLAS.
+///
+/// Returns `IncompatibleShape` error if the shapes of `A`, `x` and `y` do not match,
+/// and `InvalidStride` or `MemoryNotCont` errors if `A` is not contiguous.
+pub fn gemv<A, Sa, Sx, Sy>(
+    alpha: A,
+    a: &ArrayBase<Sa, Ix2>,
+    x: &ArrayBase<Sx, Ix1>,
+    beta: A,
+    y: &mut ArrayBase<Sy, Ix1>,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sx: Data<Elem = A>,
+    Sy: DataMut<Elem = A>,
+{
+    let (m, n) = a.dim();
+    if x.len() != n || y.len() != m {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if m == 0 {
+        return Ok(());
+    }
+    if n == 0 {
+        // `y` is not read if `beta` is zero as in BLAS
+        if beta.is_zero() {
+            y.fill(A::zero());
+        } else {
+            y.mapv_inplace(|y| y * beta);
+        }
+        return Ok(());
+    }
+    let a_layout = a.layout()?;
+    let a = a.as_allocated()?;
+    with_blas_vector(x, |x| {
+        with_blas_vector_mut(y, |y| A::gemv(alpha, a_layout, a, x, 1, beta, y, 1))
+    });
+    Ok(())
+}
+
+/// Symmetric/Hermitian rank-k update `C = alpha * A * A^H + beta * C`
+///
+/// This maps to BLAS `*syrk` for real and `*herk` for complex matrices,
+/// and costs about half of `gemm` since only the triangular part of `C` specified by `uplo`
+/// is referenced and updated. The other part is left untouched.
+/// `alpha` and `beta` are real so that `C` keeps Hermitian.
+/// To compute `A^H * A`, pass `A^T`, e.g. `a.t()` for real `A`.
+///
+/// Returns `NotSquare` error if `C` is not square, `IncompatibleShape` error if
+/// the number of rows of `A` differs from the size of `C`, and `InvalidStride` or `MemoryNotCont` errors
+/// if any of them is not contiguous.
+pub fn syrk<A, Sa, Sc>(
+    alpha: A::Real,
+    a: &ArrayBase<Sa, Ix2>,
+    beta: A::Real,
+    c: &mut ArrayBase<Sc, Ix2>,
+    uplo: UPLO,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sc: DataMut<Elem = A>,
+{
+    c.ensure_square()?;
+    let (n, k) = a.dim();
+    if c.nrows() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if n == 0 {
+        return Ok(());
+    }
+    if k == 0 {
+        let beta = A::from_real(beta);
+        for ((i, j), x) in c.indexed_iter_mut() {
+            let in_triangle = match uplo {
+                UPLO::Upper => i <= j,
+                UPLO::Lower => i >= j,
+            };
+            if in_triangle {
+                // `C` is not read if `beta` is zero as in BLAS
+                *x = if beta.is_zero() { A::zero() } else { *x * beta };
+            }
+        }
+        return Ok(());
+    }
+    let c_layout = c.layout()?;
+    A::syrk(
+        uplo,
+        alpha,
+        a.layout()?,
+        a.as_allocated()?,
+        beta,
+        c_layout,
+        c.as_allocated_mut()?,
+    );
+    Ok(())
+}
+
+/// Multiply by a triangular matrix in place using BLAS `*trmm`
+///
+/// This does about half of the flops of `dot` since the triangular structure is used.
+pub trait TriDot<S: DataMut> {
+    /// Compute `B = op(A) * B` for `Side::Left` or `B = B * op(A)` for `Side::Right`,
+    /// where `A` is `self` and `op` is specified by `trans`
+    ///
+    /// Only the triangular part of `A` specified by `uplo` is referenced,
+    /// and its diagonal is assumed to be one for `Diag::Unit`.
+    fn tri_dot<'a>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix2>,
+        side: Side,
+        uplo: UPLO,
+        trans: Transpose,
+        diag: Diag,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>>;
+}
+
+impl<A, Si, So> TriDot<So> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+    So: DataMut<Elem = A>,
+{
+    fn tri_dot<'a>(
+        &self,
+        b: &'a mut ArrayBase<So, Ix2>,
+        side: Side,
+        uplo: UPLO,
+        trans: Transpose,
+        diag: Diag,
+    ) -> Result<&'a mut ArrayBase<So, Ix2>> {
+        self.ensure_square()?;
+        let n = match side {
+            Side::Left => b.nrows(),
+            Side::Right => b.ncols(),
+        };
+        if self.nrows() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if b.is_empty() {
+            return Ok(b);
+        }
+        let b_layout = b.layout()?;
+        A::trmm(
+            side,
+            uplo,
+            trans,
+            diag,
+            self.layout()?,
+            self.as_allocated()?,
+            b_layout,
+            b.as_allocated_mut()?,
+        );
+        Ok(b)
+    }
+}
+
+/// Index of the largest element in magnitude using BLAS `i*amax`
+///
+/// The magnitude is measured by `|re(x)| + |im(x)|` as BLAS does,
+/// i.e. the same pivot as LU decomposition of LAPACK is selected.
+pub trait Iamax {
+    /// The 0-based index of the first largest element, or `None` if the vector is empty
+    fn iamax(&self) -> Option<usize>;
+}
+
+impl<A, S> Iamax for ArrayBase<S, Ix1>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn iamax(&self) -> Option<usize> {
+        let n = self.len();
+        if n == 0 {
+            return None;
+        }
+        match self.as_slice() {
+            Some(x) => Some(A::iamax(n as i32, x, 1)),
+            // Scan strided lanes, e.g. the columns in `iamax_axis` of a row-major matrix, in place
+            // rather than copying each of them for BLAS
+            None => {
+                let mag = |x: &A| x.re().abs() + x.im().abs();
+                let mut best = (0, mag(&self[0]));
+                for (i, x) in self.iter().enumerate().skip(1) {
+                    let m = mag(x);
+                    if m > best.1 {
+                        best = (i, m);
+                    }
+                }
+                Some(best.0)
+            }
+        }
+    }
+}
+
+/// [Iamax] along each row or column of a matrix
+pub trait IamaxAxis {
+    /// The 0-based indices along `axis` of the largest elements for each lane,
+    /// e.g. the pivot row of each column for `Axis(0)`
+    ///
+    /// Returns `None` if the length of `axis` is zero.
+    fn iamax_axis(&self, axis: Axis) -> Option<Array1<usize>>;
+}
+
+impl<A, S> IamaxAxis for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn iamax_axis(&self, axis: Axis) -> Option<Array1<usize>> {
+        if self.len_of(axis) == 0 {
+            return None;
+        }
+        self.lanes(axis)
+            .into_iter()
+            .map(|lane| lane.iamax())
+            .collect()
+    }
+}
+
+/// Rank-1 update `A = alpha * x * y^T + A` using BLAS `*ger` (`*geru` for complex)
+///
+/// Unlike computing the outer product with `dot`, no temporary matrix is allocated,
+/// while strided `x` and `y` are copied for BLAS.
+/// For complex matrices, see [gerc] for `y^H`.
+///
+/// Returns `IncompatibleShape` error if the shape of `A` is not `(x.len(), y.len())`,
+/// and `InvalidStride` or `MemoryNotCont` errors if `A` is not contiguous.
+pub fn ger<A, Sx, Sy, Sa>(
+    alpha: A,
+    x: &ArrayBase<Sx, Ix1>,
+    y: &ArrayBase<Sy, Ix1>,
+    a: &mut ArrayBase<Sa, Ix2>,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+    Sa: DataMut<Elem = A>,
+{
+    rank_one_update(false, alpha, x, y, a)
+}
+
+/// Rank-1 update `A = alpha * x * y^H + A` using BLAS `*gerc`
+///
+/// This is the same as [ger] for real matrices.
+pub fn gerc<A, Sx, Sy, Sa>(
+    alpha: A,
+    x: &ArrayBase<Sx, Ix1>,
+    y: &ArrayBase<Sy, Ix1>,
+    a: &mut ArrayBase<Sa, Ix2>,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+    Sa: DataMut<Elem = A>,
+{
+    rank_one_update(true, alpha, x, y, a)
+}
+
+fn rank_one_update<A, Sx, Sy, Sa>(
+    conj: bool,
+    alpha: A,
+    x: &ArrayBase<Sx, Ix1>,
+    y: &ArrayBase<Sy, Ix1>,
+    a: &mut ArrayBase<Sa, Ix2>,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+    Sa: DataMut<Elem = A>,
+{
+    if a.dim() != (x.len(), y.len()) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if a.is_empty() {
+        return Ok(());
+    }
+    let a_layout = a.layout()?;
+    let a = a.as_allocated_mut()?;
+    with_blas_vector(x, |x| {
+        with_blas_vector(y, |y| A::ger(conj, alpha, x, 1, y, 1, a_layout, a))
+    });
+    Ok(())
+}
+
+/// Vector update `y = alpha * x + y` using BLAS `*axpy`
+///
+/// Unlike `y += &(x * alpha)`, no temporary vector is allocated for contiguous `x` and `y`.
+/// Strided views, e.g. columns of a row-major matrix, are copied for BLAS, and `y` is written back.
+///
+/// Returns `IncompatibleShape` error if the lengths of `x` and `y` differ.
+pub fn axpy<A, Sx, Sy>(alpha: A, x: &ArrayBase<Sx, Ix1>, y: &mut ArrayBase<Sy, Ix1>) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: DataMut<Elem = A>,
+{
+    let n = x.len();
+    if y.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if n == 0 {
+        return Ok(());
+    }
+    with_blas_vector(x, |x| {
+        with_blas_vector_mut(y, |y| A::axpy(n as i32, alpha, x, 1, y, 1))
+    });
+    Ok(())
+}
+
+/// Vector scaling `x = alpha * x` in place using BLAS `*scal`
+pub fn scal<A, S>(alpha: A, x: &mut ArrayBase<S, Ix1>)
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    let n = x.len();
+    if n == 0 {
+        return;
+    }
+    with_blas_vector_mut(x, |x| A::scal(n as i32, alpha, x, 1));
+}
+
+/// Dot product `x^T y` using BLAS `*dot` (`*dotu` for complex)
+///
+/// For complex vectors, see [dotc] for `x^H y`.
+///
+/// Returns `IncompatibleShape` error if the lengths of `x` and `y` differ.
+pub fn dotu<A, Sx, Sy>(x: &ArrayBase<Sx, Ix1>, y: &ArrayBase<Sy, Ix1>) -> Result<A>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+{
+    vector_dot(false, x, y)
+}
+
+/// Dot product `x^H y` using BLAS `*dotc`
+///
+/// This is the same as [dotu] for real vectors.
+pub fn dotc<A, Sx, Sy>(x: &ArrayBase<Sx, Ix1>, y: &ArrayBase<Sy, Ix1>) -> Result<A>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+{
+    vector_dot(true, x, y)
+}
+
+fn vector_dot<A, Sx, Sy>(conj: bool, x: &ArrayBase<Sx, Ix1>, y: &ArrayBase<Sy, Ix1>) -> Result<A>
+where
+    A: Scalar + Lapack,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+{
+    let n = x.len();
+    if y.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if n == 0 {
+        return Ok(A::zero());
+    }
+    Ok(with_blas_vector(x, |x| {
+        with_blas_vector(y, |y| A::dot(conj, n as i32, x, 1, y, 1))
+    }))
+}
+
+/// View `a` as it is if BLAS can read it, or copy it into C layout
+fn blas_matrix<A, S>(a: &ArrayBase<S, Ix2>) -> CowArray<'_, A, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if a.layout().is_ok() && a.as_allocated().is_ok() {
+        CowArray::from(a.view())
+    } else {
+        CowArray::from(a.as_standard_layout().into_owned())
+    }
+}
+
+/// Product of vectors and matrices like `dot`, which always calls BLAS
+///
+/// - vector · vector is `*dot` (`*dotu` for complex), i.e. without conjugate as `dot`.
+///   Use [dotc] for the inner product `x^H y`.
+/// - matrix · vector and vector · matrix are `*gemv`
+/// - matrix · matrix is `*gemm`
+///
+/// Matrices which BLAS cannot read as they are, e.g. sliced views, are copied.
+/// Returns `IncompatibleShape` error if the shapes do not match.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1., 2.], [3., 4.]];
+/// let x: Array1<f64> = array![1., 1.];
+/// assert_eq!(x.blas_dot(&x).unwrap(), 2.0);
+/// assert_eq!(a.blas_dot(&x).unwrap(), array![3., 7.]);
+/// assert_eq!(x.blas_dot(&a).unwrap(), array![4., 6.]);
+/// assert_eq!(a.blas_dot(&a).unwrap(), a.dot(&a));
+/// ```
+pub trait BlasDot<Rhs> {
+    type Output;
+    fn blas_dot(&self, rhs: &Rhs) -> Result<Self::Output>;
+}
+
+impl<A, S1, S2> BlasDot<ArrayBase<S2, Ix1>> for ArrayBase<S1, Ix1>
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Output = A;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix1>) -> Result<A> {
+        dotu(self, rhs)
+    }
+}
+
+impl<A, S1, S2> BlasDot<ArrayBase<S2, Ix1>> for ArrayBase<S1, Ix2>
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Output = Array1<A>;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix1>) -> Result<Array1<A>> {
+        let mut y = Array1::zeros(self.nrows());
+        gemv(A::one(), &blas_matrix(self), rhs, A::zero(), &mut y)?;
+        Ok(y)
+    }
+}
+
+impl<A, S1, S2> BlasDot<ArrayBase<S2, Ix2>> for ArrayBase<S1, Ix1>
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Output = Array1<A>;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix2>) -> Result<Array1<A>> {
+        // `x^T B = (B^T x)^T`
+        let mut y = Array1::zeros(rhs.ncols());
+        gemv(A::one(), &blas_matrix(rhs).t(), self, A::zero(), &mut y)?;
+        Ok(y)
+    }
+}
+
+impl<A, S1, S2> BlasDot<ArrayBase<S2, Ix2>> for ArrayBase<S1, Ix2>
+where
+    A: Scalar + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn blas_dot(&self, rhs: &ArrayBase<S2, Ix2>) -> Result<Array2<A>> {
+        let mut c = Array2::zeros((self.nrows(), rhs.ncols()));
+        gemm(
+            A::one(),
+            &blas_matrix(self),
+            &blas_matrix(rhs),
+            A::zero(),
+            &mut c,
+        )?;
+        Ok(c)
+    }
+}
@@ -42,6 +42,39 @@
 //! assert!(x.abs_diff_eq(&array![-2., 1., 0.], 1e-9));
 //! # }
 //! ```
+//!
+//! Complex matrices are treated as Hermitian, i.e. `*potrf` computes
+//! `A = L * L^H` with the conjugate transpose, not `A = L * L^T`:
+//!
+//! ```
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::{assert_close_l2, c64, cholesky::*};
+//!
+//! let i = c64::new(0., 1.);
+//! let a: Array2<c64> = array![
+//!     [c64::new(4., 0.), c64::new(2., -2.)],
+//!     [c64::new(2., 2.), c64::new(3., 0.)]
+//! ];
+//!
+//! let lower = a.cholesky(UPLO::Lower).unwrap();
+//! assert_close_l2!(&lower, &array![
+//!     [c64::new(2., 0.), c64::new(0., 0.)],
+//!     [c64::new(1., 1.), c64::new(1., 0.)]
+//! ], 1e-9);
+//!
+//! // `UPLO::Upper` returns `R = L^H` satisfying `A = R^H * R`, like MATLAB's `chol`
+//! let upper = a.cholesky(UPLO::Upper).unwrap();
+//! assert_close_l2!(&upper, &lower.t().mapv(|x| x.conj()), 1e-9);
+//! assert_close_l2!(&upper.t().mapv(|x| x.conj()).dot(&upper), &a, 1e-9);
+//!
+//! // The determinant of a Hermitian matrix is real
+//! let det = a.detc().unwrap();
+//! assert!((det - 4.).abs() < 1e-9);
+//!
+//! let b = array![c64::new(6., 2.), c64::new(2., 5.)];
+//! let x = a.solvec(&b).unwrap();
+//! assert_close_l2!(&x, &array![c64::new(1., 0.), i], 1e-9);
+//! ```
 
 use ndarray::*;
 use num_traits::Float;
@@ -49,7 +82,9 @@ use num_traits::Float;
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
-use crate::triangular::IntoTriangular;
+use crate::opnorm::OperationNorm;
+use crate::solve::{ReciprocalConditionNum, ReciprocalConditionNumInto};
+use crate::triangular::{Diag, IntoTriangular, SolveTriangular};
 use crate::types::*;
 
 pub use lax::UPLO;
@@ -94,6 +129,43 @@ where
     }
 }
 
+impl<A, S> CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Solves `L * x = b` by forward substitution, where `A = L * L^H`
+    ///
+    /// This applies only `L^{-1}`, e.g. to whiten a vector,
+    /// and `solve_upper(&solve_lower(b)?)` is equivalent to `A^{-1} b`.
+    pub fn solve_lower<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        self.solve_factor(UPLO::Lower, b)
+    }
+
+    /// Solves `L^H * x = b` (i.e. `U * x = b`) by back substitution, where `A = L * L^H = U^H * U`
+    pub fn solve_upper<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        self.solve_factor(UPLO::Upper, b)
+    }
+
+    /// Solves the triangular system of `L` for `UPLO::Lower` or `U` for `UPLO::Upper`
+    fn solve_factor<Sb: Data<Elem = A>>(
+        &self,
+        uplo: UPLO,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<Array1<A>> {
+        if let (UPLO::Lower, UPLO::Lower) | (UPLO::Upper, UPLO::Upper) = (self.uplo, uplo) {
+            return self
+                .factor
+                .solve_triangular(uplo, Diag::NonUnit, &b.to_owned());
+        }
+        // The other factor is the conjugate transpose of `self.factor`,
+        // and `F^H x = b` is solved as `F^T conj(x) = conj(b)` without copying `F`.
+        let b = b.mapv(|x| x.conj());
+        let x = self.factor.t().solve_triangular(uplo, Diag::NonUnit, &b)?;
+        Ok(x.mapv_into(|x| x.conj()))
+    }
+}
+
 impl<A, S> DeterminantC for CholeskyFactorized<S>
 where
     A: Scalar + Lapack,
@@ -173,13 +245,66 @@ where
     where
         Sb: DataMut<Elem = A>,
     {
-        A::solve_cholesky(
+        with_contiguous_mut(b, |b| {
+            A::solve_cholesky(
+                self.factor.square_layout()?,
+                self.uplo,
+                self.factor.as_allocated()?,
+                b,
+            )?;
+            Ok(())
+        })?;
+        Ok(b)
+    }
+}
+
+impl<A, S> CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Estimates the reciprocal condition number of `A` in 1-norm from the factor by `*pocon`,
+    /// given the 1-norm of the original matrix `A`, e.g. `a.opnorm_one()` before the factorization
+    ///
+    /// This costs `O(n^2)`, while [ReciprocalConditionNum::rcond] computes the norm from the factor in `O(n^3)`.
+    pub fn rcond_with_norm(&self, a_opnorm_one: A::Real) -> Result<A::Real> {
+        Ok(A::rcond_cholesky(
             self.factor.square_layout()?,
             self.uplo,
             self.factor.as_allocated()?,
-            b.as_slice_mut().unwrap(),
-        )?;
-        Ok(b)
+            a_opnorm_one,
+        )?)
+    }
+}
+
+impl<A, S> ReciprocalConditionNum<A> for CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Estimates the reciprocal condition number of `A` in 1-norm from the factor by `*pocon`
+    ///
+    /// The 1-norm of `A` required by `*pocon` is computed from `A = L * L^H` or `A = U^H * U`,
+    /// which costs `O(n^3)` as the factorization itself.
+    /// Use [CholeskyFactorized::rcond_with_norm] if the norm of `A` is known.
+    fn rcond(&self) -> Result<A::Real> {
+        let factor = self.factor.to_owned().into_triangular(self.uplo);
+        let factor_h = factor.t().mapv(|x| x.conj());
+        let a = match self.uplo {
+            UPLO::Lower => factor.dot(&factor_h),
+            UPLO::Upper => factor_h.dot(&factor),
+        };
+        self.rcond_with_norm(a.opnorm_one()?)
+    }
+}
+
+impl<A, S> ReciprocalConditionNumInto<A> for CholeskyFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn rcond_into(self) -> Result<A::Real> {
+        self.rcond()
     }
 }
 
@@ -233,6 +358,7 @@ where
     type Output = Array2<A>;
 
     fn cholesky(&self, uplo: UPLO) -> Result<Array2<A>> {
+        self.ensure_square()?;
         let a = replicate(self);
         a.cholesky_into(uplo)
     }
@@ -257,6 +383,7 @@ where
     S: DataMut<Elem = A>,
 {
     fn cholesky_inplace(&mut self, uplo: UPLO) -> Result<&mut Self> {
+        self.ensure_square()?;
         A::cholesky(self.square_layout()?, uplo, self.as_allocated_mut()?)?;
         Ok(self.into_triangular(uplo))
     }
@@ -0,0 +1,166 @@
+//! Lyapunov equation and Gramians of linear time-invariant systems
+//!
+//! For the continuous-time system `dx/dt = A x + B u`, `y = C x`,
+//! the Gramians are the solutions of the Lyapunov equations
+//!
+//! - controllability: `A W + W A^H + B B^H = 0`
+//! - observability: `A^H W + W A + C^H C = 0`
+//!
+//! which are solved by the Bartels-Stewart algorithm using LAPACK `*gees` and `*trsyl`.
+
+use ndarray::*;
+use num_traits::Zero;
+
+use crate::convert::{fortran, Adjoint};
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+use lax::Transpose;
+
+/// Solve the continuous Lyapunov equation `A X + X A^H = Q`
+///
+/// `A` is decomposed into the Schur form `A = Z T Z^H`,
+/// and the triangular equation `T Y + Y T^H = Z^H Q Z` is solved for `X = Z Y Z^H`.
+/// The solution is unique if no two eigenvalues of `A` satisfy `λ_i + conj(λ_j) = 0`,
+/// and `LapackComputationalFailure` error is returned if they are (nearly) so.
+///
+/// Returns `NotSquare` error if `A` is not square,
+/// and `IncompatibleShape` error if `Q` has the different shape from `A`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[-1., 2.], [0., -3.]];
+/// let q: Array2<f64> = array![[1., 0.], [0., 1.]];
+/// let x = solve_lyapunov(&a, &q).unwrap();
+/// assert!((a.dot(&x) + x.dot(&a.t())).abs_diff_eq(&q, 1e-9));
+/// ```
+pub fn solve_lyapunov<A, Sa, Sq>(
+    a: &ArrayBase<Sa, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+{
+    let (_, t, z) = schur(a, q)?;
+    lyapunov_schur(t, &z, q)
+}
+
+/// Eigenvalues, Schur form `T` and Schur vectors `Z` of `A = Z T Z^H`
+/// after checking that `Q` has the same shape as `A`
+fn schur<A, Sa, Sq>(
+    a: &ArrayBase<Sa, Ix2>,
+    q: &ArrayBase<Sq, Ix2>,
+) -> Result<(Vec<A::Complex>, Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sq: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if q.dim() != a.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let n = a.nrows();
+    let mut t = fortran(a);
+    let (eigs, z) = A::schur(t.layout()?, t.as_allocated_mut()?)?;
+    let z = Array2::from_shape_vec((n, n).f(), z).unwrap();
+    Ok((eigs, t, z))
+}
+
+/// Solve `A X + X A^H = Q` from the Schur decomposition `A = Z T Z^H`
+fn lyapunov_schur<A, Sq>(t: Array2<A>, z: &Array2<A>, q: &ArrayBase<Sq, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sq: Data<Elem = A>,
+{
+    let n = t.nrows();
+    if n == 0 {
+        return Ok(Array2::zeros((0, 0)));
+    }
+    let zh = z.adjoint();
+    let mut y = fortran(&zh.dot(q).dot(z));
+    let scale = A::sylvester(
+        Transpose::No,
+        Transpose::Hermite,
+        1,
+        n as i32,
+        n as i32,
+        t.as_allocated()?,
+        t.as_allocated()?,
+        y.as_allocated_mut()?,
+    )?;
+    Ok(z.dot(&y).dot(&zh).mapv(|x| x.div_real(scale)))
+}
+
+/// Solve `A W + W A^H + S = 0` for the stable `A` and the Hermitian `S`
+fn gramian<A>(a: &Array2<A>, s: Array2<A>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+{
+    let q = s.mapv(|x| -x);
+    // Check the stability before the Sylvester solver,
+    // which fails for the eigenvalues on the imaginary axis
+    let (eigs, t, z) = schur(a, &q)?;
+    if eigs.iter().any(|e| e.re() >= A::Real::zero()) {
+        return Err(LinalgError::NotStable);
+    }
+    let w = lyapunov_schur(t, &z, &q)?;
+    // Remove the rounding error from the Hermitian solution
+    let wh = w.adjoint();
+    Ok((w + wh).mapv(|x| x.div_real(A::real(2.0))))
+}
+
+/// Controllability Gramian `W`, the solution of `A W + W A^H + B B^H = 0`
+///
+/// Returns `NotStable` error if an eigenvalue of `A` has a non-negative real part,
+/// `NotSquare` error if `A` is not square,
+/// and `IncompatibleShape` error if `B` has a different number of rows from `A`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[-1., 0.], [0., -2.]];
+/// let b: Array2<f64> = array![[1.], [1.]];
+/// let w = controllability_gramian(&a, &b).unwrap();
+/// assert!(w.abs_diff_eq(&array![[1. / 2., 1. / 3.], [1. / 3., 1. / 4.]], 1e-9));
+/// ```
+pub fn controllability_gramian<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if b.nrows() != a.nrows() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    gramian(&a.to_owned(), b.dot(&b.adjoint()))
+}
+
+/// Observability Gramian `W`, the solution of `A^H W + W A + C^H C = 0`
+///
+/// This is the controllability Gramian of the dual system `(A^H, C^H)`.
+/// Returns `NotStable` error if an eigenvalue of `A` has a non-negative real part,
+/// `NotSquare` error if `A` is not square,
+/// and `IncompatibleShape` error if `C` has a different number of columns from `A`.
+pub fn observability_gramian<A, Sa, Sc>(
+    a: &ArrayBase<Sa, Ix2>,
+    c: &ArrayBase<Sc, Ix2>,
+) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+{
+    if c.ncols() != a.ncols() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    gramian(&a.adjoint(), c.adjoint().dot(c))
+}
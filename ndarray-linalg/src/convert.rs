@@ -111,6 +111,17 @@ where
     new
 }
 
+/// Copy the matrix into F layout, which LAPACK reads as it is
+pub(crate) fn fortran<A, S>(a: &ArrayBase<S, Ix2>) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut f = Array2::zeros(a.dim().f());
+    f.assign(a);
+    f
+}
+
 /// Fills in the remainder of a Hermitian matrix that's represented by only one
 /// triangle.
 ///
@@ -142,3 +153,78 @@ where
         }
     }
 }
+
+/// Conjugate transpose (adjoint) `A^H` of a matrix reference
+///
+/// This is the plain transpose for a real matrix. Same as [crate::generate::conjugate],
+/// but always returns an owned array.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[c64::new(1., 2.), c64::new(3., 0.)]];
+/// assert_eq!(a.adjoint(), array![[c64::new(1., -2.)], [c64::new(3., 0.)]]);
+/// let b: Array2<f64> = random((2, 3));
+/// assert_eq!(b.adjoint(), b.t());
+/// ```
+pub trait Adjoint {
+    type Output;
+    fn adjoint(&self) -> Self::Output;
+}
+
+/// Conjugate transpose (adjoint) `A^H` of a matrix consuming it
+///
+/// This does not allocate but reverses the axes,
+/// i.e. the result of a C-contiguous matrix is F-contiguous and vice versa.
+pub trait AdjointInto {
+    type Output;
+    fn adjoint_into(self) -> Self::Output;
+}
+
+impl<A, S> Adjoint for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn adjoint(&self) -> Array2<A> {
+        let mut a: Array2<A> = replicate(&self.t());
+        a.map_inplace(|x| *x = x.conj());
+        a
+    }
+}
+
+impl<A, S> AdjointInto for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    type Output = Self;
+
+    fn adjoint_into(self) -> Self {
+        let mut a = self.reversed_axes();
+        a.map_inplace(|x| *x = x.conj());
+        a
+    }
+}
+
+/// Call `f` with the contiguous slice of `v`
+///
+/// If `v` is not contiguous (e.g. a column of a C-contiguous matrix),
+/// `f` is called with a contiguous copy, which is written back into `v` afterwards.
+pub(crate) fn with_contiguous_mut<A, S, F>(v: &mut ArrayBase<S, Ix1>, f: F) -> Result<()>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+    F: FnOnce(&mut [A]) -> Result<()>,
+{
+    if let Some(slice) = v.as_slice_mut() {
+        return f(slice);
+    }
+    let mut copy = v.to_owned();
+    f(copy.as_slice_mut().unwrap())?;
+    v.assign(&copy);
+    Ok(())
+}
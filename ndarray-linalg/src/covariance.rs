@@ -0,0 +1,240 @@
+//! Correlation and whitening of covariance matrices
+//!
+//! For a covariance matrix `Σ = D^{1/2} P D^{1/2}` with the variances `D = diag(Σ)`
+//! and the correlation matrix `P`, a whitening matrix `W` satisfies `W Σ W^H = I`,
+//! i.e. `W x` has the identity covariance. The correlation-based whitenings
+//! are derived from one eigendecomposition `P = U Θ U^H`,
+//! see A. Kessy, A. Lewin and K. Strimmer, Am. Stat. 72, 309 (2018).
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let cov: Array2<f64> = array![[4., 2.], [2., 9.]];
+//! let corr = cov.cov_to_corr().unwrap();
+//! assert!(corr.abs_diff_eq(&array![[1., 1. / 3.], [1. / 3., 1.]], 1e-12));
+//!
+//! let w = cov.whitening(UPLO::Upper).unwrap();
+//! assert!(w.correlation.abs_diff_eq(&corr, 1e-12));
+//! let zca = w.zca_cor();
+//! assert!(zca.dot(&cov).dot(&zca.t()).abs_diff_eq(&Array2::eye(2), 1e-9));
+//! ```
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::cholesky::*;
+use crate::convert::Adjoint;
+use crate::eigh::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::svd::*;
+use crate::types::*;
+use crate::UPLO;
+
+/// Correlation matrix of a covariance matrix
+pub trait CovToCorr {
+    type Output;
+    /// Scale the covariance matrix `Σ` to the correlation matrix `D^{-1/2} Σ D^{-1/2}` with `D = diag(Σ)`
+    ///
+    /// Returns `NotPositiveDefinite` error if a diagonal element is not positive.
+    fn cov_to_corr(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> CovToCorr for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn cov_to_corr(&self) -> Result<Array2<A>> {
+        let std_dev = std_dev(self)?;
+        Ok(corr_from_std_dev(self, &std_dev))
+    }
+}
+
+/// Standard deviations, i.e. the square roots of the diagonal of the covariance matrix
+fn std_dev<A, S>(cov: &ArrayBase<S, Ix2>) -> Result<Array1<A::Real>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    cov.ensure_square()?;
+    let d = cov.diag().mapv(|x| x.re());
+    if !d.iter().all(|&d| d > A::Real::zero()) {
+        return Err(LinalgError::NotPositiveDefinite);
+    }
+    Ok(d.mapv(Float::sqrt))
+}
+
+fn corr_from_std_dev<A, S>(cov: &ArrayBase<S, Ix2>, std_dev: &Array1<A::Real>) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut corr = cov.to_owned();
+    for ((i, j), c) in corr.indexed_iter_mut() {
+        *c = c.div_real(std_dev[i] * std_dev[j]);
+    }
+    // Remove the rounding error on the unit diagonal
+    corr.diag_mut().fill(A::one());
+    corr
+}
+
+/// Correlation matrix and its eigendecomposition computed by [Whiten::whitening]
+#[derive(Debug, Clone)]
+pub struct Whitening<A: Scalar> {
+    /// Standard deviations `D^{1/2}`
+    pub std_dev: Array1<A::Real>,
+    /// Correlation matrix `P`
+    pub correlation: Array2<A>,
+    /// Eigenvalues `Θ` of the correlation matrix in ascending order
+    pub eigvals: Array1<A::Real>,
+    /// Eigenvectors `U` of the correlation matrix as columns
+    pub eigvecs: Array2<A>,
+}
+
+impl<A: Scalar> Whitening<A> {
+    /// `U Θ^{-1/2} U^H D^{-1/2}`, the whitening closest to the original variables in the correlation scale
+    pub fn zca_cor(&self) -> Array2<A> {
+        let uh = self.eigvecs.t().mapv(|x| x.conj());
+        self.scaled_eigvecs().dot(&uh).dot(&self.inv_std_dev())
+    }
+
+    /// `Θ^{-1/2} U^H D^{-1/2}`, whose rows are the normalized principal components of the correlation matrix
+    pub fn pca_cor(&self) -> Array2<A> {
+        self.scaled_eigvecs()
+            .t()
+            .mapv(|x| x.conj())
+            .dot(&self.inv_std_dev())
+    }
+
+    /// `U Θ^{-1/2}`
+    fn scaled_eigvecs(&self) -> Array2<A> {
+        let mut u = self.eigvecs.clone();
+        for (mut col, &t) in u.axis_iter_mut(Axis(1)).zip(self.eigvals.iter()) {
+            let s = Float::sqrt(t).recip();
+            col.map_inplace(|x| *x = x.mul_real(s));
+        }
+        u
+    }
+
+    /// `D^{-1/2}`
+    fn inv_std_dev(&self) -> Array2<A> {
+        Array2::from_diag(&self.std_dev.mapv(|s| A::from_real(s.recip())))
+    }
+}
+
+/// Whitening of a covariance matrix
+pub trait Whiten {
+    type Elem: Scalar;
+    /// Compute the correlation matrix and its eigendecomposition by one `eigh`
+    ///
+    /// Returns `NotPositiveDefinite` error if a variance or an eigenvalue of the correlation matrix
+    /// is not positive.
+    fn whitening(&self, uplo: UPLO) -> Result<Whitening<Self::Elem>>;
+}
+
+impl<A, S> Whiten for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn whitening(&self, uplo: UPLO) -> Result<Whitening<A>> {
+        let std_dev = std_dev(self)?;
+        let correlation = corr_from_std_dev(self, &std_dev);
+        let (eigvals, eigvecs) = correlation.eigh(uplo)?;
+        if !eigvals.iter().all(|&t| t > A::Real::zero()) {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        Ok(Whitening {
+            std_dev,
+            correlation,
+            eigvals,
+            eigvecs,
+        })
+    }
+}
+
+/// Maximum number of the diagonal shifts in [NearestSpd::nearest_spd]
+const NEAREST_SPD_MAX_ITER: usize = 100;
+
+/// Nearest Hermitian positive definite matrix, e.g. to repair a noisy covariance matrix
+pub trait NearestSpd {
+    type Output;
+    /// Compute the nearest Hermitian positive definite matrix in the Frobenius norm
+    ///
+    /// This is Higham's algorithm, N. J. Higham, Linear Algebra Appl. 103, 103 (1988).
+    /// The Hermitian part `B = (A + A^H) / 2` is averaged with its polar factor `H = V Σ V^H`
+    /// from the SVD `B = U Σ V^H`, which is the nearest positive semidefinite matrix `(B + H) / 2`.
+    /// Since it may still fail in Cholesky decomposition by rounding errors,
+    /// the diagonal is shifted by `-λ_min k^2 + ε |λ_min|` with the smallest eigenvalue `λ_min`
+    /// in the `k`-th retry until Cholesky decomposition succeeds.
+    ///
+    /// Returns `NotSquare` error if the matrix is not square,
+    /// and `NotConverged` error if Cholesky decomposition fails after 100 shifts.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // eigenvalues are 3 and -1
+    /// let a: Array2<f64> = array![[1., 2.], [2., 1.]];
+    /// assert!(a.cholesky(UPLO::Lower).is_err());
+    /// let b = a.nearest_spd().unwrap();
+    /// assert!(b.cholesky(UPLO::Lower).is_ok());
+    /// // eigenvalue -1 is clipped to (nearly) zero
+    /// assert!(b.abs_diff_eq(&array![[1.5, 1.5], [1.5, 1.5]], 1e-9));
+    /// ```
+    fn nearest_spd(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> NearestSpd for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn nearest_spd(&self) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        if n == 0 {
+            return Ok(Array2::zeros((0, 0)));
+        }
+        let hermitian_part = |a: &Array2<A>| (a + &a.adjoint()).mapv(|x| x.mul_real(A::real(0.5)));
+        let b = hermitian_part(&self.to_owned());
+        let (_, sigma, vt) = b.svd(false, true)?;
+        let vt = vt.unwrap();
+        let mut vs = vt.adjoint();
+        for (mut col, &s) in vs.axis_iter_mut(Axis(1)).zip(sigma.iter()) {
+            col.map_inplace(|x| *x = x.mul_real(s));
+        }
+        let h = vs.dot(&vt);
+        let mut a = hermitian_part(&(b + h).mapv(|x| x.mul_real(A::real(0.5))));
+
+        for k in 0..NEAREST_SPD_MAX_ITER {
+            if a.cholesky(UPLO::Lower).is_ok() {
+                return Ok(a);
+            }
+            let min = a
+                .eigvalsh(UPLO::Lower)?
+                .fold(<A::Real as Float>::infinity(), |m, &e| Float::min(m, e));
+            let k = A::real(k as f64);
+            let shift = -min * k * k
+                + Float::max(
+                    A::Real::epsilon() * Float::abs(min),
+                    A::Real::min_positive_value(),
+                );
+            for i in 0..n {
+                a[(i, i)] += A::from_real(shift);
+            }
+        }
+        Err(LinalgError::NotConverged {
+            iterations: NEAREST_SPD_MAX_ITER,
+        })
+    }
+}
@@ -1,9 +1,15 @@
 //! Eigenvalue decomposition for non-symmetric square matrices
 
 use crate::error::*;
+use crate::generate::companion;
+use crate::inner::*;
 use crate::layout::*;
+use crate::svd::SVD;
 use crate::types::*;
 use ndarray::*;
+use num_traits::{Float, One, Zero};
+
+pub use lax::Balance;
 
 /// Eigenvalue decomposition of general matrix reference
 pub trait Eig {
@@ -46,6 +52,11 @@ where
     type EigVec = Array2<A::Complex>;
 
     fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        self.ensure_square()?;
+        if self.nrows() <= 1 {
+            // The entry of a 1x1 matrix is its eigenvalue
+            return Ok((self.diag().mapv(|x| x.as_c()), Array2::eye(self.nrows())));
+        }
         let mut a = self.to_owned();
         let layout = a.square_layout()?;
         let (s, t) = A::eig(true, layout, a.as_allocated_mut()?)?;
@@ -71,8 +82,250 @@ where
     type EigVal = Array1<A::Complex>;
 
     fn eigvals(&self) -> Result<Self::EigVal> {
+        self.ensure_square()?;
+        if self.nrows() <= 1 {
+            return Ok(self.diag().mapv(|x| x.as_c()));
+        }
         let mut a = self.to_owned();
         let (s, _) = A::eig(false, a.square_layout()?, a.as_allocated_mut()?)?;
         Ok(ArrayBase::from(s))
     }
 }
+
+/// Eigenvalue decomposition of general matrix reference with a balancing option
+///
+/// `Eig::eig` always balances the matrix by `Balance::Both` as LAPACK `*geev` does.
+/// This may not improve or even harm the accuracy, e.g. for a matrix whose small entries
+/// are not negligible, and `Balance::None` disables it.
+pub trait EigBalanced {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues with the right eigenvector of the original matrix
+    /// after balancing specified by `balance`
+    fn eig_balanced(&self, balance: Balance) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+impl<A, S> EigBalanced for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_balanced(&self, balance: Balance) -> Result<(Self::EigVal, Self::EigVec)> {
+        self.ensure_square()?;
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let (s, t) = A::eig_balanced(true, layout, balance, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok((
+            ArrayBase::from(s),
+            Array2::from_shape_vec((n, n).f(), t).unwrap(),
+        ))
+    }
+}
+
+/// Calculate eigenvalues without eigenvectors with a balancing option, see [EigBalanced]
+pub trait EigValsBalanced {
+    type EigVal;
+    fn eigvals_balanced(&self, balance: Balance) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EigValsBalanced for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+
+    fn eigvals_balanced(&self, balance: Balance) -> Result<Self::EigVal> {
+        self.ensure_square()?;
+        let mut a = self.to_owned();
+        let (s, _) = A::eig_balanced(false, a.square_layout()?, balance, a.as_allocated_mut()?)?;
+        Ok(ArrayBase::from(s))
+    }
+}
+
+/// Eigenvalue decomposition of general matrix reference with the sensitivity of the result
+pub trait EigExpert {
+    type EigVal;
+    type EigVec;
+    type RCond;
+    /// Calculate eigenvalues, the right eigenvectors, and the reciprocal condition numbers
+    /// of the eigenvalues and eigenvectors using LAPACK `*geevx`
+    ///
+    /// A small reciprocal condition number indicates an ill-conditioned eigenvalue or eigenvector,
+    /// e.g. near-degenerated eigenvalues have small reciprocal condition numbers of eigenvectors.
+    /// The matrix is balanced only by permutation, which does not change the condition numbers,
+    /// and thus they are those of the original matrix.
+    fn eig_expert(&self) -> Result<(Self::EigVal, Self::EigVec, Self::RCond, Self::RCond)>;
+}
+
+impl<A, S> EigExpert for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+    type RCond = Array1<A::Real>;
+
+    fn eig_expert(&self) -> Result<(Self::EigVal, Self::EigVec, Self::RCond, Self::RCond)> {
+        self.ensure_square()?;
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let out = A::eig_expert(true, true, layout, Balance::Permute, a.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok((
+            ArrayBase::from(out.eigs),
+            Array2::from_shape_vec((n, n).f(), out.vr).unwrap(),
+            ArrayBase::from(out.rconde.unwrap()),
+            ArrayBase::from(out.rcondv.unwrap()),
+        ))
+    }
+}
+
+/// Eigenvalue decomposition of general matrix reference with both the left and right eigenvectors
+pub trait EigBoth {
+    type EigVal;
+    type EigVec;
+    /// Calculate eigenvalues with the left and right eigenvectors `(λ, Y, X)`
+    ///
+    /// $$ y_i^H A = \lambda_i y_i^H, \quad A x_i = \lambda_i x_i $$
+    ///
+    /// The right eigenvectors are normalized to have the unit 2-norm as [Eig::eig],
+    /// and the left eigenvectors are scaled to be biorthonormal, i.e. `y_i^H x_j = δ_ij`,
+    /// which holds for the distinct eigenvalues.
+    /// Then `|y_i|` is the condition number of `λ_i`,
+    /// which is infinite and the left eigenvector is not finite for a defective eigenvalue.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1., 2.], [0., 3.]];
+    /// let (e, y, x) = a.eig_both().unwrap();
+    /// let a = a.mapv(c64::from);
+    /// let yh = y.t().mapv(|y| y.conj());
+    /// assert!(yh.dot(&a).abs_diff_eq(&Array2::from_diag(&e).dot(&yh), 1e-9));
+    /// assert!(yh.dot(&x).abs_diff_eq(&Array2::eye(2), 1e-9));
+    /// ```
+    fn eig_both(&self) -> Result<(Self::EigVal, Self::EigVec, Self::EigVec)>;
+}
+
+impl<A, S> EigBoth for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Complex>;
+    type EigVec = Array2<A::Complex>;
+
+    fn eig_both(&self) -> Result<(Self::EigVal, Self::EigVec, Self::EigVec)> {
+        self.ensure_square()?;
+        let mut a = self.to_owned();
+        let layout = a.square_layout()?;
+        let out = A::eig_generic(
+            true,
+            true,
+            false,
+            layout,
+            Balance::Both,
+            a.as_allocated_mut()?,
+        )?;
+        let n = layout.len() as usize;
+        let mut vl = Array2::from_shape_vec((n, n).f(), out.vl).unwrap();
+        let vr = Array2::from_shape_vec((n, n).f(), out.vr).unwrap();
+        for (mut y, x) in vl.axis_iter_mut(Axis(1)).zip(vr.axis_iter(Axis(1))) {
+            let s = y.inner(&x).conj();
+            y.mapv_inplace(|y| y / s);
+        }
+        Ok((ArrayBase::from(out.eigs), vl, vr))
+    }
+}
+
+/// Check the result of [Eig::eig] for a defective (non-diagonalizable) matrix
+///
+/// The eigenvectors of a defective matrix do not form a basis,
+/// and the reconstruction `V diag(λ) V^{-1}` fails.
+/// The computed eigenvectors of a defective eigenvalue are nearly parallel with the angle `~√ε`,
+/// since the rounding error splits the eigenvalue by `~√ε`, i.e. `κ(V) ~ 1 / √ε`
+/// for the 2-norm condition number `κ(V)` of the eigenvector matrix.
+/// Thus the matrix is flagged as likely defective if `κ(V) > ε^{-1/3}` with a margin,
+/// which also flags a matrix close to a defective one.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // Jordan block
+/// let a: Array2<f64> = array![[1., 1.], [0., 1.]];
+/// let (defective, cond) = a.eig().unwrap().is_defective().unwrap();
+/// assert!(defective && cond > 1e5);
+///
+/// let a: Array2<f64> = array![[1., 1.], [0., 2.]];
+/// let (defective, cond) = a.eig().unwrap().is_defective().unwrap();
+/// assert!(!defective && cond < 10.);
+/// ```
+pub trait EigDefective {
+    type Real;
+    /// Returns whether the matrix is likely defective, and the condition number `κ(V)`
+    ///
+    /// `κ(V)` is infinite if `V` is exactly singular.
+    fn is_defective(&self) -> Result<(bool, Self::Real)>;
+}
+
+impl<A> EigDefective for (Array1<A>, Array2<A>)
+where
+    A: Scalar + Lapack,
+{
+    type Real = A::Real;
+
+    fn is_defective(&self) -> Result<(bool, A::Real)> {
+        let (_, s, _) = self.1.svd(false, false)?;
+        let cond = match (s.first(), s.last()) {
+            (Some(&max), Some(&min)) => max / min,
+            _ => A::Real::one(),
+        };
+        let threshold = Float::powf(A::Real::epsilon(), A::real(-1.0 / 3.0));
+        Ok((cond > threshold, cond))
+    }
+}
+
+/// Roots of the polynomial `c[0] x^n + c[1] x^(n-1) + ... + c[n]`
+///
+/// The roots are computed as the eigenvalues of the [companion] matrix as `numpy.roots`.
+/// Leading zero coefficients are ignored, and trailing zero coefficients are counted as
+/// roots at zero, which are placed at the end of the result.
+/// An empty array is returned if the polynomial is a non-zero constant or all coefficients are zero.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // x^3 - 3 x^2 + 2 x = x (x - 1)(x - 2)
+/// let r = roots(&array![0.0, 1.0, -3.0, 2.0, 0.0]).unwrap();
+/// assert_eq!(r.len(), 3);
+/// assert_eq!(r[2], c64::new(0.0, 0.0));
+/// ```
+pub fn roots<A, S>(c: &ArrayBase<S, Ix1>) -> Result<Array1<A::Complex>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let first = match c.iter().position(|c| !c.is_zero()) {
+        Some(first) => first,
+        None => return Ok(Array1::zeros(0)),
+    };
+    let last = c.iter().rposition(|c| !c.is_zero()).unwrap();
+    let zeros = c.len() - 1 - last;
+    let c = c.slice(s![first..=last]);
+    let mut roots = if c.len() > 1 {
+        companion(&c)?.eigvals()?.to_vec()
+    } else {
+        Vec::new()
+    };
+    roots.resize(roots.len() + zeros, A::Complex::zero());
+    Ok(Array1::from(roots))
+}
@@ -34,15 +34,17 @@
 //! ```
 
 use ndarray::*;
+use num_traits::{Float, One, Zero};
 
-use crate::diagonal::*;
+use crate::convert::{triangular_fill_hermitian, Adjoint};
 use crate::error::*;
 use crate::layout::*;
-use crate::operator::LinearOperator;
 use crate::types::*;
 use crate::UPLO;
 
 /// Eigenvalue decomposition of Hermite matrix reference
+///
+/// Matrices up to 3x3 are decomposed by closed-form formulas without calling LAPACK.
 pub trait Eigh {
     type EigVal;
     type EigVec;
@@ -125,13 +127,19 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
-        let layout = self.square_layout()?;
-        // XXX Force layout to be Fortran (see #146)
-        match layout {
-            MatrixLayout::C { .. } => self.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
+        if let Some(s) = eigh_closed_form(self, uplo) {
+            return Ok((s, self));
         }
-        let s = A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)?;
+        if self.layout().is_err() {
+            // Strided matrix is decomposed in a contiguous copy
+            self.ensure_square()?;
+            let mut a = self.to_owned();
+            let (s, _) = a.eigh_inplace(uplo)?;
+            self.assign(&a);
+            return Ok((s, self));
+        }
+        let (layout, uplo) = into_fortran_hermite(self, uplo)?;
+        let s = A::eigh(true, layout, uplo, self.as_allocated_mut()?)?;
         Ok((ArrayBase::from(s), self))
     }
 }
@@ -146,6 +154,10 @@ where
 
     /// Solves the generalized eigenvalue problem.
     ///
+    /// The eigenvectors overwrite the first matrix `A`, and are normalized to be
+    /// `B`-orthonormal, i.e. `V^H B V = I`, as `*sygv` and `*hegv` with `itype = 1`.
+    /// The second matrix `B` is overwritten by its Cholesky factor and should not be used.
+    ///
     /// # Panics
     ///
     /// Panics if the shapes of the matrices are different.
@@ -155,23 +167,29 @@ where
             self.1.shape(),
             "The shapes of the matrices must be identical.",
         );
-        let layout = self.0.square_layout()?;
-        // XXX Force layout to be Fortran (see #146)
-        match layout {
-            MatrixLayout::C { .. } => self.0.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
+        if self.0.layout().is_err() || self.1.layout().is_err() {
+            // Strided matrices are decomposed in contiguous copies
+            self.0.ensure_square()?;
+            let mut ab = (self.0.to_owned(), self.1.to_owned());
+            let (s, _) = ab.eigh_inplace(uplo)?;
+            self.0.assign(&ab.0);
+            self.1.assign(&ab.1);
+            return Ok((s, self));
         }
-
-        let layout = self.1.square_layout()?;
-        match layout {
-            MatrixLayout::C { .. } => self.1.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
+        let (layout, uplo_a) = into_fortran_hermite(&mut self.0, uplo)?;
+        let (_, uplo_b) = into_fortran_hermite(&mut self.1, uplo)?;
+        if !matches!(
+            (uplo_a, uplo_b),
+            (UPLO::Upper, UPLO::Upper) | (UPLO::Lower, UPLO::Lower)
+        ) {
+            // LAPACK reads the same triangle of both matrices
+            triangular_fill_hermitian(&mut self.1, uplo_b);
         }
 
         let s = A::eigh_generalized(
             true,
-            self.0.square_layout()?,
-            uplo,
+            layout,
+            uplo_a,
             self.0.as_allocated_mut()?,
             self.1.as_allocated_mut()?,
         )?;
@@ -180,6 +198,340 @@ where
     }
 }
 
+/// Make the Hermitian matrix `a` Fortran layout, and returns the triangle LAPACK should read
+///
+/// LAPACK reads a C layout matrix as its transpose, i.e. the conjugate for a Hermitian matrix,
+/// where the `uplo` triangle of `a` becomes the other one.
+/// Thus the axes are swapped and the elements are conjugated.
+fn into_fortran_hermite<A, S>(a: &mut ArrayBase<S, Ix2>, uplo: UPLO) -> Result<(MatrixLayout, UPLO)>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    match a.square_layout()? {
+        MatrixLayout::C { .. } => {
+            a.swap_axes(0, 1);
+            a.map_inplace(|x| *x = x.conj());
+            Ok((a.square_layout()?, uplo.t()))
+        }
+        layout @ MatrixLayout::F { .. } => Ok((layout, uplo)),
+    }
+}
+
+/// Closed-form `eigh` of a Hermitian matrix up to 3x3, overwriting `a` by the eigenvectors
+///
+/// The LAPACK call is dominated by its overhead for such a small matrix.
+/// The 3x3 eigenvalues are given by the trigonometric solution of the characteristic polynomial.
+/// Only the best separated eigenvector is taken as the null vector of `A - λI`,
+/// and the other two are found by the 2x2 problem in its orthogonal complement,
+/// since the null vector is inaccurate for close eigenvalues.
+/// Returns `None` for the other sizes or a non-finite matrix, which are left to LAPACK.
+fn eigh_closed_form<A, S>(a: &mut ArrayBase<S, Ix2>, uplo: UPLO) -> Option<Array1<A::Real>>
+where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    let n = a.nrows();
+    if a.ncols() != n || n > 3 {
+        return None;
+    }
+    if n <= 1 {
+        let w = a.diag().mapv(|x| x.re());
+        a.fill(A::one());
+        return Some(w);
+    }
+    let mut m = [[A::zero(); 3]; 3];
+    for i in 0..n {
+        m[i][i] = A::from_real(a[(i, i)].re());
+        for j in (i + 1)..n {
+            m[i][j] = match uplo {
+                UPLO::Upper => a[(i, j)],
+                UPLO::Lower => a[(j, i)].conj(),
+            };
+            m[j][i] = m[i][j].conj();
+        }
+    }
+    // Checked before the scaling, since `Float::max` ignores NaN
+    if !m
+        .iter()
+        .flatten()
+        .all(|x| x.re().is_finite() && x.im().is_finite())
+    {
+        return None;
+    }
+    // Scale to avoid overflow in the squares
+    let scale = m
+        .iter()
+        .flatten()
+        .fold(A::Real::zero(), |max, x| Float::max(max, x.abs()));
+    if !scale.is_finite() {
+        return None;
+    }
+    if scale.is_zero() {
+        a.assign(&Array2::eye(n));
+        return Some(Array1::zeros(n));
+    }
+    m.iter_mut().flatten().for_each(|x| *x = x.div_real(scale));
+
+    let (w, v) = if n == 2 {
+        let (w, v) = eigh2(m[0][0].re(), m[0][1], m[1][1].re());
+        (
+            vec![w[0], w[1]],
+            vec![[v[0][0], v[0][1], A::zero()], [v[1][0], v[1][1], A::zero()]],
+        )
+    } else {
+        let (w, v) = eigh3(&m)?;
+        (w.to_vec(), v.to_vec())
+    };
+    for (j, v) in v.iter().enumerate() {
+        for i in 0..n {
+            a[(i, j)] = v[i];
+        }
+    }
+    Some(w.into_iter().map(|w| w * scale).collect())
+}
+
+/// Eigenpairs of the 2x2 Hermitian matrix `[[a, b], [b^*, c]]` in ascending order
+fn eigh2<A: Scalar>(a: A::Real, b: A, c: A::Real) -> ([A::Real; 2], [[A; 2]; 2]) {
+    let half = A::real(0.5);
+    let mean = (a + c) * half;
+    let d = (a - c) * half;
+    let r = Float::hypot(d, b.abs());
+    if r.is_zero() {
+        return ([mean, mean], [[A::one(), A::zero()], [A::zero(), A::one()]]);
+    }
+    // Null vector of `A - (mean + r) I` from the row without cancellation
+    let x = if d >= A::Real::zero() {
+        [A::from_real(d + r), b.conj()]
+    } else {
+        [b, A::from_real(r - d)]
+    };
+    let norm = Float::sqrt(x[0].square() + x[1].square());
+    let x = [x[0].div_real(norm), x[1].div_real(norm)];
+    ([mean - r, mean + r], [[-x[1].conj(), x[0].conj()], x])
+}
+
+/// Eigenpairs of a 3x3 Hermitian matrix normalized as `max |a_ij| = 1` in ascending order
+///
+/// Returns `None` if the null vector is not found, which is left to LAPACK.
+fn eigh3<A: Scalar>(m: &[[A; 3]; 3]) -> Option<([A::Real; 3], [[A; 3]; 3])> {
+    let two = A::real(2.0);
+    let three = A::real(3.0);
+    let q = (m[0][0].re() + m[1][1].re() + m[2][2].re()) / three;
+    let d = [m[0][0].re() - q, m[1][1].re() - q, m[2][2].re() - q];
+    let (a01, a02, a12) = (m[0][1], m[0][2], m[1][2]);
+    let p = Float::sqrt(
+        (d[0] * d[0]
+            + d[1] * d[1]
+            + d[2] * d[2]
+            + two * (a01.square() + a02.square() + a12.square()))
+            / A::real(6.0),
+    );
+    if p.is_zero() {
+        let mut e = [[A::zero(); 3]; 3];
+        (0..3).for_each(|i| e[i][i] = A::one());
+        return Some(([q; 3], e));
+    }
+    // det(A - qI)
+    let det = d[0] * d[1] * d[2] + two * (a01 * a12 * a02.conj()).re()
+        - d[0] * a12.square()
+        - d[1] * a02.square()
+        - d[2] * a01.square();
+    let r = det / (two * p * p * p);
+    let phi = Float::acos(Float::max(Float::min(r, A::Real::one()), -A::Real::one())) / three;
+    let max = q + two * p * Float::cos(phi);
+    let min = q + two * p * Float::cos(phi + A::real(2.0 * std::f64::consts::FRAC_PI_3));
+    let mid = three * q - max - min;
+    let isolated = if mid - min < max - mid { max } else { min };
+
+    // Null vector of `A - λI` is the cross product of its two rows
+    let rows: Vec<[A; 3]> = (0..3)
+        .map(|i| {
+            let mut row = m[i];
+            row[i] -= A::from_real(isolated);
+            row
+        })
+        .collect();
+    let u = [(0, 1), (0, 2), (1, 2)]
+        .iter()
+        .map(|&(i, j)| cross(&rows[i], &rows[j]))
+        .max_by(|x, y| norm_sq(x).partial_cmp(&norm_sq(y)).unwrap())
+        .unwrap();
+    let u = normalize(u)?;
+
+    // Orthonormal basis `e1, e2` of the complement of `u`
+    let k = (0..3)
+        .min_by(|&i, &j| u[i].abs().partial_cmp(&u[j].abs()).unwrap())
+        .unwrap();
+    let mut ek = [A::zero(); 3];
+    ek[k] = A::one();
+    let e1 = normalize(conj(cross(&u, &ek)))?;
+    let e2 = normalize(conj(cross(&u, &e1)))?;
+    let me1 = matvec(m, &e1);
+    let me2 = matvec(m, &e2);
+    let (mu, y) = eigh2(dotc(&e1, &me1).re(), dotc(&e1, &me2), dotc(&e2, &me2).re());
+
+    let mut pairs = [(dotc(&u, &matvec(m, &u)).re(), u); 3];
+    for i in 0..2 {
+        let mut v = [A::zero(); 3];
+        for j in 0..3 {
+            v[j] = e1[j] * y[i][0] + e2[j] * y[i][1];
+        }
+        pairs[i + 1] = (mu[i], v);
+    }
+    pairs.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+    Some((
+        [pairs[0].0, pairs[1].0, pairs[2].0],
+        [pairs[0].1, pairs[1].1, pairs[2].1],
+    ))
+}
+
+fn cross<A: Scalar>(a: &[A; 3], b: &[A; 3]) -> [A; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn conj<A: Scalar>(a: [A; 3]) -> [A; 3] {
+    [a[0].conj(), a[1].conj(), a[2].conj()]
+}
+
+fn dotc<A: Scalar>(a: &[A; 3], b: &[A; 3]) -> A {
+    a[0].conj() * b[0] + a[1].conj() * b[1] + a[2].conj() * b[2]
+}
+
+fn matvec<A: Scalar>(m: &[[A; 3]; 3], v: &[A; 3]) -> [A; 3] {
+    [
+        dotc(&conj(m[0]), v),
+        dotc(&conj(m[1]), v),
+        dotc(&conj(m[2]), v),
+    ]
+}
+
+fn norm_sq<A: Scalar>(a: &[A; 3]) -> A::Real {
+    a[0].square() + a[1].square() + a[2].square()
+}
+
+fn normalize<A: Scalar>(a: [A; 3]) -> Option<[A; 3]> {
+    let norm = Float::sqrt(norm_sq(&a));
+    if norm.is_zero() || !norm.is_finite() {
+        return None;
+    }
+    Some([
+        a[0].div_real(norm),
+        a[1].div_real(norm),
+        a[2].div_real(norm),
+    ])
+}
+
+/// Reorder eigenpairs, e.g. the result of `eigh`
+///
+/// LAPACK returns the eigenvalues in ascending order,
+/// while e.g. principal component analysis wants the largest one first.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2., 1.], [1., 2.]];
+/// let (w, v) = a.eigh(UPLO::Upper).unwrap().sort_descending();
+/// assert!(w.abs_diff_eq(&array![3., 1.], 1e-9));
+/// assert!(a.dot(&v).abs_diff_eq(&(&v * &w), 1e-9));
+/// ```
+pub trait EighSort {
+    /// Sort the eigenvalues in descending order and the eigenvectors (columns) consistently
+    ///
+    /// The sort is stable, i.e. degenerated eigenvalues keep their order.
+    /// Panics if the number of eigenvalues and eigenvectors differ.
+    fn sort_descending(self) -> Self;
+}
+
+impl<A, R> EighSort for (Array1<R>, Array2<A>)
+where
+    A: Scalar,
+    R: PartialOrd + Copy,
+{
+    fn sort_descending(self) -> Self {
+        let (w, v) = self;
+        assert_eq!(
+            w.len(),
+            v.ncols(),
+            "Numbers of eigenvalues and eigenvectors differ"
+        );
+        let mut idx: Vec<usize> = (0..w.len()).collect();
+        idx.sort_by(|&i, &j| w[j].partial_cmp(&w[i]).unwrap_or(std::cmp::Ordering::Equal));
+        (w.select(Axis(0), &idx), v.select(Axis(1), &idx))
+    }
+}
+
+/// Gaps between eigenvalues of the result of `eigh`
+///
+/// The eigenvectors of nearly degenerated eigenvalues are ill-conditioned;
+/// their perturbation is of the order of `|ΔA| / gap`, while the subspace spanned by them is well-defined.
+/// The eigenvalues are assumed to be sorted in ascending or descending order.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = Array2::from_diag(&array![1.0, 2.0, 2.0 + 1e-12, 3.0]);
+/// let eig = a.eigh(UPLO::Upper).unwrap();
+/// assert!(eig.min_gap().unwrap() < 1e-11);
+/// assert_eq!(eig.degenerate(1e-8), vec![false, true, true, false]);
+/// assert_eq!(eig.degenerate_blocks(1e-8), vec![0..1, 1..3, 3..4]);
+/// ```
+pub trait EighGap<R> {
+    /// The minimum gap between consecutive eigenvalues, or `None` for less than two eigenvalues
+    fn min_gap(&self) -> Option<R>;
+
+    /// Flag eigenpairs whose gap to a neighboring eigenvalue is not larger than `tol`
+    fn degenerate(&self, tol: R) -> Vec<bool>;
+
+    /// Split eigenpairs into blocks where consecutive gaps are not larger than `tol`
+    ///
+    /// Each range of indices should be treated as a single invariant subspace.
+    fn degenerate_blocks(&self, tol: R) -> Vec<std::ops::Range<usize>>;
+}
+
+impl<A, R> EighGap<R> for (Array1<R>, Array2<A>)
+where
+    A: Scalar,
+    R: Float,
+{
+    fn min_gap(&self) -> Option<R> {
+        let w = &self.0;
+        (1..w.len())
+            .map(|i| Float::abs(w[i] - w[i - 1]))
+            .fold(None, |m, g| Some(m.map_or(g, |m: R| m.min(g))))
+    }
+
+    fn degenerate(&self, tol: R) -> Vec<bool> {
+        let w = &self.0;
+        let n = w.len();
+        (0..n)
+            .map(|i| {
+                (i > 0 && Float::abs(w[i] - w[i - 1]) <= tol)
+                    || (i + 1 < n && Float::abs(w[i + 1] - w[i]) <= tol)
+            })
+            .collect()
+    }
+
+    fn degenerate_blocks(&self, tol: R) -> Vec<std::ops::Range<usize>> {
+        let w = &self.0;
+        let mut blocks = Vec::new();
+        let mut start = 0;
+        for i in 1..=w.len() {
+            if i == w.len() || Float::abs(w[i] - w[i - 1]) > tol {
+                blocks.push(start..i);
+                start = i;
+            }
+        }
+        blocks
+    }
+}
+
 /// Calculate eigenvalues without eigenvectors
 pub trait EigValsh {
     type EigVal;
@@ -231,11 +583,61 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigvalsh_inplace(&mut self, uplo: UPLO) -> Result<Self::EigVal> {
+        self.ensure_square()?;
+        if self.nrows() <= 1 {
+            return Ok(self.diag().mapv(|x| x.re()));
+        }
+        if self.layout().is_err() {
+            // Strided matrix is decomposed in a contiguous copy
+            return self.to_owned().eigvalsh_into(uplo);
+        }
+        // The eigenvalues of the transpose, i.e. the conjugate, are the same
+        let uplo = match self.square_layout()? {
+            MatrixLayout::C { .. } => uplo.t(),
+            MatrixLayout::F { .. } => uplo,
+        };
         let s = A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)?;
         Ok(ArrayBase::from(s))
     }
 }
 
+/// Numerical rank of a Hermitian matrix from its eigenvalues
+///
+/// The singular values of a Hermitian matrix are the absolute values of its eigenvalues,
+/// and thus this gives the same rank as [crate::SVDAnalyze::svd_analysis] by one `eigh` cheaper than SVD.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1., 1.], [1., 1.]];
+/// assert_eq!(a.rank_symmetric(UPLO::Upper, None).unwrap(), 1);
+/// ```
+pub trait RankSymmetric {
+    type Real;
+    /// Count the eigenvalues whose magnitude is larger than `rcond` times the largest one.
+    ///
+    /// If `rcond` is `None`, the machine epsilon times the size of the matrix is used.
+    fn rank_symmetric(&self, uplo: UPLO, rcond: Option<Self::Real>) -> Result<usize>;
+}
+
+impl<A, S> RankSymmetric for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Real = A::Real;
+
+    fn rank_symmetric(&self, uplo: UPLO, rcond: Option<A::Real>) -> Result<usize> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        let w = self.eigvalsh(uplo)?;
+        let rcond = rcond.unwrap_or_else(|| A::Real::epsilon() * A::real(n as f64));
+        let tol = w.fold(A::Real::zero(), |m, &w| Float::max(m, Float::abs(w))) * rcond;
+        Ok(w.iter().filter(|&&w| Float::abs(w) > tol).count())
+    }
+}
+
 /// Calculate symmetric square-root matrix using `eigh`
 pub trait SymmetricSqrt {
     type Output;
@@ -269,9 +671,301 @@ where
     type Output = Array2<A>;
 
     fn ssqrt_into(self, uplo: UPLO) -> Result<Self::Output> {
+        self.apply_fn_into(uplo, Float::sqrt)
+    }
+}
+
+/// Apply a real function to the eigenvalues of a Hermitian matrix
+///
+/// The matrix function `f(A) = V diag(f(λ)) V^H` is reconstructed from a single `eigh`,
+/// e.g. the square root (see [SymmetricSqrt]), the clipping of the spectrum, or the sigmoid.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2., 1.], [1., 2.]];
+/// // clip the negative part of the spectrum
+/// let a_clip = a.apply_fn(UPLO::Upper, |e| e.max(1.5)).unwrap();
+/// assert!(a_clip.abs_diff_eq(&array![[2.25, 0.75], [0.75, 2.25]], 1e-9));
+/// ```
+pub trait SymmetricFunction {
+    type Elem: Scalar;
+    type Output;
+    fn apply_fn<F>(&self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(<Self::Elem as Scalar>::Real) -> <Self::Elem as Scalar>::Real;
+}
+
+/// Apply a real function to the eigenvalues of a Hermitian matrix
+pub trait SymmetricFunctionInto {
+    type Elem: Scalar;
+    type Output;
+    fn apply_fn_into<F>(self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(<Self::Elem as Scalar>::Real) -> <Self::Elem as Scalar>::Real;
+}
+
+impl<A, S> SymmetricFunction for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+    type Output = Array2<A>;
+
+    fn apply_fn<F>(&self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
+        self.to_owned().apply_fn_into(uplo, f)
+    }
+}
+
+impl<A, S> SymmetricFunctionInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A> + DataOwned,
+{
+    type Elem = A;
+    type Output = Array2<A>;
+
+    fn apply_fn_into<F>(self, uplo: UPLO, f: F) -> Result<Self::Output>
+    where
+        F: Fn(A::Real) -> A::Real,
+    {
         let (e, v) = self.eigh_into(uplo)?;
-        let e_sqrt = Array::from_iter(e.iter().map(|r| Scalar::from_real(r.sqrt())));
-        let ev = e_sqrt.into_diagonal().apply2(&v.t());
-        Ok(v.apply2(&ev))
+        let fe = e.mapv(|e| A::from_real(f(e)));
+        let vh: Array2<A> = v.t().mapv(|x| x.conj());
+        Ok((v * &fe).dot(&vh))
+    }
+}
+
+/// How the threshold of [TruncatedEigh::truncated_eigh] is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdMode {
+    /// Keep the eigenvalues `λ > threshold`
+    Absolute,
+    /// Keep the eigenvalues `λ > threshold * λ_max` for the largest eigenvalue `λ_max`
+    Relative,
+}
+
+/// Truncate the spectrum of a Hermitian matrix below a threshold
+///
+/// The matrix `V diag(λ_kept) V^H` is reconstructed from a single `eigh`,
+/// where the eigenvalues not above the threshold are replaced by zero,
+/// e.g. to denoise a covariance matrix.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2., 1.], [1., 2.]];
+/// // eigenvalues are 1 and 3
+/// let (b, kept) = a.truncated_eigh(UPLO::Upper, 0.5, ThresholdMode::Relative).unwrap();
+/// assert_eq!(kept, 1);
+/// assert!(b.abs_diff_eq(&array![[1.5, 1.5], [1.5, 1.5]], 1e-9));
+/// ```
+pub trait TruncatedEigh {
+    type Elem: Scalar;
+    type Output;
+    /// Returns the truncated matrix and the number of the kept eigenvalues
+    fn truncated_eigh(
+        &self,
+        uplo: UPLO,
+        threshold: <Self::Elem as Scalar>::Real,
+        mode: ThresholdMode,
+    ) -> Result<(Self::Output, usize)>;
+}
+
+impl<A, S> TruncatedEigh for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+    type Output = Array2<A>;
+
+    fn truncated_eigh(
+        &self,
+        uplo: UPLO,
+        threshold: A::Real,
+        mode: ThresholdMode,
+    ) -> Result<(Self::Output, usize)> {
+        let (e, v) = self.eigh(uplo)?;
+        let tol = match mode {
+            ThresholdMode::Absolute => threshold,
+            ThresholdMode::Relative => {
+                // eigenvalues are in ascending order
+                let max = e.iter().last().cloned().unwrap_or_else(A::Real::zero);
+                threshold * max
+            }
+        };
+        let kept = e.iter().filter(|&&e| e > tol).count();
+        // the kept eigenvalues are the last ones
+        let k = e.len() - kept;
+        let vk = v.slice(s![.., k..]);
+        let ek = e.slice(s![k..]).mapv(A::from_real);
+        Ok(((&vk * &ek).dot(&vk.adjoint()), kept))
+    }
+}
+
+/// Eigenvalue decomposition of a stack of Hermitian matrices
+///
+/// For an array of the shape `(k, n, n)`, `w[i, ..]` and `v[i, .., ..]` of the result `(w, v)`
+/// are the eigenvalues and eigenvectors of `a[i, .., ..].eigh(uplo)`, i.e.
+/// the eigenvalues are stacked into the shape `(k, n)` and the eigenvectors into `(k, n, n)`.
+/// The matrices are decomposed in parallel if the `rayon` feature is enabled.
+/// Returns `NotSquare` error if the last two axes have different lengths.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array3<f64> = array![[[2., 1.], [1., 2.]], [[3., 0.], [0., 1.]]];
+/// let (w, v) = a.batched_eigh(UPLO::Upper).unwrap();
+/// assert!(w.abs_diff_eq(&array![[1., 3.], [1., 3.]], 1e-9));
+/// for (a, (w, v)) in a.outer_iter().zip(w.outer_iter().zip(v.outer_iter())) {
+///     assert!(a.dot(&v).abs_diff_eq(&(&v * &w), 1e-9));
+/// }
+/// ```
+pub trait BatchedEigh {
+    type EigVal;
+    type EigVec;
+    fn batched_eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+/// Shape `(k, n)` of a stack of `k` square matrices of the size `n`
+fn batch_shape<S: RawData>(a: &ArrayBase<S, Ix3>) -> Result<(usize, usize)> {
+    let (k, n, m) = a.dim();
+    if n != m {
+        return Err(LinalgError::NotSquare {
+            rows: n as i32,
+            cols: m as i32,
+        });
+    }
+    Ok((k, n))
+}
+
+fn stack_eigh<A: Scalar>(
+    k: usize,
+    n: usize,
+    eighs: Vec<(Array1<A::Real>, Array2<A>)>,
+) -> (Array2<A::Real>, Array3<A>) {
+    let mut w = Array2::zeros((k, n));
+    let mut v = Array3::zeros((k, n, n));
+    for (i, (wi, vi)) in eighs.into_iter().enumerate() {
+        w.row_mut(i).assign(&wi);
+        v.index_axis_mut(Axis(0), i).assign(&vi);
+    }
+    (w, v)
+}
+
+#[cfg(not(feature = "rayon"))]
+impl<A, S> BatchedEigh for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array2<A::Real>;
+    type EigVec = Array3<A>;
+
+    fn batched_eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        let (k, n) = batch_shape(self)?;
+        let eighs = self
+            .outer_iter()
+            .map(|a| a.eigh(uplo))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(stack_eigh(k, n, eighs))
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, S> BatchedEigh for ArrayBase<S, Ix3>
+where
+    A: Scalar + Lapack + Send + Sync,
+    A::Real: Send,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array2<A::Real>;
+    type EigVec = Array3<A>;
+
+    fn batched_eigh(&self, uplo: UPLO) -> Result<(Self::EigVal, Self::EigVec)> {
+        use rayon::prelude::*;
+        let (k, n) = batch_shape(self)?;
+        let a = self.view();
+        let eighs = (0..k)
+            .into_par_iter()
+            .map(|i| a.index_axis(Axis(0), i).eigh(uplo))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(stack_eigh(k, n, eighs))
+    }
+}
+
+/// Eigenvalue decomposition of a real symmetric tridiagonal matrix using LAPACK `*stevd`
+///
+/// `d` is the diagonal and `e` is the off-diagonal, whose length must be one less than `d`.
+/// Returns the eigenvalues in ascending order and the corresponding eigenvectors as columns.
+/// This is the final stage of `eigh`, and is much faster than `eigh` of the dense matrix.
+/// Returns `IncompatibleShape` error if the lengths of `d` and `e` mismatch.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let d = array![2.0, 2.0];
+/// let e = array![1.0];
+/// let (w, v) = eigh_tridiagonal(&d, &e).unwrap();
+/// assert!(w.abs_diff_eq(&array![1.0, 3.0], 1e-9));
+/// let a = array![[2.0, 1.0], [1.0, 2.0]];
+/// assert!(a.dot(&v).abs_diff_eq(&(&v * &w), 1e-9));
+/// ```
+pub fn eigh_tridiagonal<A, S1, S2>(
+    d: &ArrayBase<S1, Ix1>,
+    e: &ArrayBase<S2, Ix1>,
+) -> Result<(Array1<A>, Array2<A>)>
+where
+    A: Scalar<Real = A> + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let (w, v) = calc_eigh_tridiagonal(true, d, e)?;
+    let n = w.len();
+    let v = Array2::from_shape_vec((n, n).f(), v).unwrap();
+    Ok((w, v))
+}
+
+/// Eigenvalues of a real symmetric tridiagonal matrix using LAPACK `*stevd`
+///
+/// See [eigh_tridiagonal] for the arguments.
+pub fn eigvalsh_tridiagonal<A, S1, S2>(
+    d: &ArrayBase<S1, Ix1>,
+    e: &ArrayBase<S2, Ix1>,
+) -> Result<Array1<A>>
+where
+    A: Scalar<Real = A> + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    let (w, _) = calc_eigh_tridiagonal(false, d, e)?;
+    Ok(w)
+}
+
+fn calc_eigh_tridiagonal<A, S1, S2>(
+    calc_v: bool,
+    d: &ArrayBase<S1, Ix1>,
+    e: &ArrayBase<S2, Ix1>,
+) -> Result<(Array1<A>, Vec<A>)>
+where
+    A: Scalar<Real = A> + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    if e.len() + 1 != d.len().max(1) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
     }
+    let mut w = d.to_vec();
+    let mut e = e.to_vec();
+    let v = A::eigh_tridiagonal(calc_v, &mut w, &mut e)?;
+    Ok((ArrayBase::from(w), v))
 }
@@ -35,4 +35,52 @@ pub enum LinalgError {
     /// Strides of the array is not supported
     #[error(transparent)]
     Shape(#[from] ShapeError),
+
+    /// The norm of the order `p` is not supported
+    #[error("Unsupported norm: p = {}", p)]
+    UnsupportedNorm { p: f64 },
+
+    /// Iterative method does not converge within the maximum number of iterations
+    #[error("Not converged in {} iterations", iterations)]
+    NotConverged { iterations: usize },
+
+    /// The block to be concatenated has incompatible number of rows or columns
+    #[error("Incompatible block {}: length {} != {}", index, actual, expected)]
+    IncompatibleBlock {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    /// The leading coefficient of a polynomial is zero
+    #[error("Leading coefficient of polynomial is zero")]
+    ZeroLeadingCoefficient,
+
+    /// The problem has no solution, e.g. a non-generic total least squares problem
+    #[error("No solution exists")]
+    NoSolution,
+
+    /// The indices are not a permutation of `0..n`
+    #[error("Not a permutation of 0..{}", n)]
+    InvalidPermutation { n: usize },
+
+    /// The vector is expected to have the unit norm
+    #[error("Vector is not normalized")]
+    NotNormalized,
+
+    /// The matrix has an eigenvalue whose real part is not negative
+    #[error("Matrix is not stable")]
+    NotStable,
+
+    /// The Hermitian matrix has a non-positive diagonal element or eigenvalue
+    #[error("Matrix is not positive definite")]
+    NotPositiveDefinite,
+
+    /// The matrix has an eigenvalue on the imaginary axis
+    #[error("Matrix has an eigenvalue on the imaginary axis")]
+    EigenvalueOnImaginaryAxis,
+
+    /// The solution expected to be real has a non-negligible imaginary part
+    #[error("Solution is not real: |Im x| / |x| = {}", ratio)]
+    NotReal { ratio: f64 },
 }
@@ -0,0 +1,276 @@
+//! Matrix exponential and trigonometric functions
+//!
+//! - [Matrix exponential - Wikipedia](https://en.wikipedia.org/wiki/Matrix_exponential)
+
+use ndarray::*;
+use num_traits::{Float, ToPrimitive};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Upper bounds of `|A|_1` for the Padé approximants of the degrees 3, 5, 7, 9 and 13,
+/// see N. J. Higham, SIAM J. Matrix Anal. Appl. 26, 1179 (2005)
+const THETA: [(usize, f64); 5] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068e0),
+    (13, 5.371920351148152e0),
+];
+
+/// Coefficients of the Padé approximants of the degree 3, 5, 7 and 9
+const PADE_3: [f64; 4] = [120., 60., 12., 1.];
+const PADE_5: [f64; 6] = [30240., 15120., 3360., 420., 30., 1.];
+const PADE_7: [f64; 8] = [
+    17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+];
+const PADE_9: [f64; 10] = [
+    17643225600.,
+    8821612800.,
+    2075673600.,
+    302702400.,
+    30270240.,
+    2162160.,
+    110880.,
+    3960.,
+    90.,
+    1.,
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.,
+    32382376266240000.,
+    7771770303897600.,
+    1187353796428800.,
+    129060195264000.,
+    10559470521600.,
+    670442572800.,
+    33522128640.,
+    1323241920.,
+    40840800.,
+    960960.,
+    16380.,
+    182.,
+    1.,
+];
+
+/// Matrix exponential `exp(A)`
+///
+/// This uses the scaling and squaring method with the Padé approximant of the degree 13
+/// by N. J. Higham, SIAM J. Matrix Anal. Appl. 26, 1179 (2005), i.e. the same as `scipy.linalg.expm`.
+/// Returns `NotSquare` error if `A` is not square.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // rotation by the angle 1
+/// let a: Array2<f64> = array![[0., -1.], [1., 0.]];
+/// let (c, s) = (1.0_f64.cos(), 1.0_f64.sin());
+/// assert!(a.expm().unwrap().abs_diff_eq(&array![[c, -s], [s, c]], 1e-12));
+/// ```
+pub trait Expm {
+    type Output;
+    fn expm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Expm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn expm(&self) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        if n == 0 {
+            return Ok(Array2::zeros((0, 0)));
+        }
+        let norm = self.opnorm_one()?;
+        for &(m, theta) in &THETA[..4] {
+            if norm <= A::real(theta) {
+                let b: &[f64] = match m {
+                    3 => &PADE_3,
+                    5 => &PADE_5,
+                    7 => &PADE_7,
+                    _ => &PADE_9,
+                };
+                let (u, v) = pade(&self.to_owned(), b);
+                return pade_ratio(u, v);
+            }
+        }
+
+        // Scale `A` to be `|A / 2^s|_1 <= θ_13`
+        let theta = A::real(THETA[4].1);
+        let s = if norm > theta {
+            Float::ceil(Float::log2(norm / theta)).to_i32().unwrap()
+        } else {
+            0
+        };
+        let a = self.mapv(|x| x.mul_real(Float::powi(A::real(2.0), -s)));
+        let b = PADE_13;
+        let eye = Array2::<A>::eye(n);
+        let a2 = a.dot(&a);
+        let a4 = a2.dot(&a2);
+        let a6 = a4.dot(&a2);
+        let u = a6.dot(&lincomb(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)]))
+            + lincomb(&[(b[7], &a6), (b[5], &a4), (b[3], &a2), (b[1], &eye)]);
+        let u = a.dot(&u);
+        let v = a6.dot(&lincomb(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)]))
+            + lincomb(&[(b[6], &a6), (b[4], &a4), (b[2], &a2), (b[0], &eye)]);
+        let mut r = pade_ratio(u, v)?;
+        for _ in 0..s {
+            r = r.dot(&r);
+        }
+        Ok(r)
+    }
+}
+
+/// Linear combination `Σ c_i A_i` of the matrices of the same shape
+fn lincomb<A: Scalar>(terms: &[(f64, &Array2<A>)]) -> Array2<A> {
+    let mut sum = Array2::zeros(terms[0].1.dim());
+    for &(c, a) in terms {
+        let c = A::real(c);
+        azip!((s in &mut sum, &a in a) *s += a.mul_real(c));
+    }
+    sum
+}
+
+/// The odd part `U` and the even part `V` of the Padé approximant with the coefficients `b`
+fn pade<A: Scalar>(a: &Array2<A>, b: &[f64]) -> (Array2<A>, Array2<A>) {
+    let n = a.nrows();
+    let a2 = a.dot(a);
+    let mut power = Array2::<A>::eye(n);
+    let mut u = Array2::<A>::zeros((n, n));
+    let mut v = Array2::<A>::zeros((n, n));
+    for k in 0..b.len() / 2 {
+        let (even, odd) = (A::real(b[2 * k]), A::real(b[2 * k + 1]));
+        azip!((v in &mut v, &p in &power) *v += p.mul_real(even));
+        azip!((u in &mut u, &p in &power) *u += p.mul_real(odd));
+        power = power.dot(&a2);
+    }
+    (a.dot(&u), v)
+}
+
+/// `(V - U)^{-1} (V + U)`
+fn pade_ratio<A: Scalar + Lapack>(u: Array2<A>, v: Array2<A>) -> Result<Array2<A>> {
+    let p = &v + &u;
+    let q = v - u;
+    let f = q.factorize_into()?;
+    let mut r = p;
+    for mut col in r.axis_iter_mut(Axis(1)) {
+        f.solve_inplace(&mut col)?;
+    }
+    Ok(r)
+}
+
+/// Upper bound of `|A|_1` for the Taylor series of `cos(A)` and `sin(A)`
+const TRIG_THETA: f64 = 0.5;
+
+/// Degree of the Taylor series of `cos(A)` and `sin(A)`,
+/// whose truncation error is below `θ^TRIG_DEGREE / TRIG_DEGREE! < 10^-21` for `|A|_1 <= θ`
+const TRIG_DEGREE: usize = 18;
+
+/// Matrix cosine `cos(A)`
+///
+/// `cos(A / 2^s)` and `sin(A / 2^s)` are computed by the Taylor series with `|A / 2^s|_1 <= 1/2`,
+/// and then the double angle formulas `cos 2X = 2 cos^2 X - I` and `sin 2X = 2 sin X cos X`
+/// are applied `s` times, see N. J. Higham, Functions of Matrices (SIAM, 2008), Chapter 12.
+/// For a Hermitian matrix, [SymmetricFunction](crate::eigh::SymmetricFunction) with `cos` is cheaper.
+/// Returns `NotSquare` error if `A` is not square.
+///
+/// `cos(√A t)` and `sin(√A t)` solve the second-order system `x'' = -A x`,
+/// e.g. `cos(Ω t)` for the diagonal `Ω` of the angular frequencies.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1., 2.], [0., 3.]];
+/// let c = a.cosm().unwrap();
+/// let s = a.sinm().unwrap();
+/// assert!((c.dot(&c) + s.dot(&s)).abs_diff_eq(&Array2::eye(2), 1e-12));
+/// assert!((c[(0, 0)] - 1.0_f64.cos()).abs() < 1e-12);
+/// assert!((s[(1, 1)] - 3.0_f64.sin()).abs() < 1e-12);
+/// ```
+pub trait Cosm {
+    type Output;
+    fn cosm(&self) -> Result<Self::Output>;
+}
+
+/// Matrix sine `sin(A)`, see [Cosm] for the algorithm
+pub trait Sinm {
+    type Output;
+    fn sinm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Cosm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn cosm(&self) -> Result<Array2<A>> {
+        Ok(cos_sin(self)?.0)
+    }
+}
+
+impl<A, S> Sinm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn sinm(&self) -> Result<Array2<A>> {
+        Ok(cos_sin(self)?.1)
+    }
+}
+
+/// `(cos(A), sin(A))` by the Taylor series and the double angle formulas
+fn cos_sin<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array2<A>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    let n = a.nrows();
+    if n == 0 {
+        return Ok((Array2::zeros((0, 0)), Array2::zeros((0, 0))));
+    }
+    let norm = a.opnorm_one()?;
+    let theta = A::real(TRIG_THETA);
+    let s = if norm > theta {
+        Float::ceil(Float::log2(norm / theta)).to_i32().unwrap()
+    } else {
+        0
+    };
+    let x = a.mapv(|x| x.mul_real(Float::powi(A::real(2.0), -s)));
+    let x2 = x.dot(&x);
+
+    // Horner's method in `X^2`, where the coefficients of `X^{2k}` and `X^{2k+1}`
+    // are `(-1)^k / (2k)!` and `(-1)^k / (2k+1)!`
+    let eye = Array2::<A>::eye(n);
+    let mut c = eye.clone();
+    let mut sn = eye.clone();
+    for k in (1..=TRIG_DEGREE / 2).rev() {
+        let k = k as f64;
+        let cc = -1.0 / ((2.0 * k - 1.0) * (2.0 * k));
+        let cs = -1.0 / ((2.0 * k) * (2.0 * k + 1.0));
+        c = x2.dot(&c).mapv(|x| x.mul_real(A::real(cc))) + &eye;
+        sn = x2.dot(&sn).mapv(|x| x.mul_real(A::real(cs))) + &eye;
+    }
+    let mut sn = x.dot(&sn);
+
+    for _ in 0..s {
+        let two = A::real(2.0);
+        let c2 = c.dot(&c).mapv(|x| x.mul_real(two)) - &eye;
+        sn = sn.dot(&c).mapv(|x| x.mul_real(two));
+        c = c2;
+    }
+    Ok((c, sn))
+}
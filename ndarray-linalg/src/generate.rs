@@ -3,8 +3,10 @@
 use ndarray::*;
 use rand::prelude::*;
 
+use super::cholesky::*;
 use super::convert::*;
 use super::error::*;
+use super::layout::*;
 use super::qr::*;
 use super::types::*;
 
@@ -91,6 +93,90 @@ where
     ArrayBase::eye(n) + &ah.dot(&a)
 }
 
+/// Draw `n` samples from the multivariate normal distribution `N(mu, sigma)`
+///
+/// `sigma` is factorized once as `L L^H` by Cholesky decomposition,
+/// and each sample is computed as `mu + L z` from a standard normal vector `z`.
+/// For complex `A`, `z` is drawn from the circularly-symmetric complex normal distribution,
+/// i.e. `E[z z^H] = I` and `E[z z^T] = 0`, so that the samples have the covariance `sigma`.
+/// Returns the samples as the rows of a `n x mu.len()` matrix.
+///
+/// Returns `IncompatibleShape` error if the sizes of `mu` and `sigma` mismatch,
+/// and the error of Cholesky decomposition if `sigma` is not positive definite.
+pub fn sample_mvn<A, S1, S2, R>(
+    mu: &ArrayBase<S1, Ix1>,
+    sigma: &ArrayBase<S2, Ix2>,
+    n: usize,
+    rng: &mut R,
+) -> Result<Array2<A>>
+where
+    A: StandardNormal + Lapack,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    R: Rng,
+{
+    let dim = mu.len();
+    if sigma.dim() != (dim, dim) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let l = sigma.cholesky(UPLO::Lower)?;
+    let mut z = Array2::<A>::zeros((dim, n));
+    A::fill_standard_normal(z.as_slice_mut().unwrap(), rng);
+    let mut x = l.dot(&z).reversed_axes();
+    x += mu;
+    Ok(x)
+}
+
+/// Scalar types drawn from the standard normal distribution by the Box-Muller transform
+///
+/// A complex number is drawn from the circularly-symmetric complex normal distribution,
+/// i.e. each of the real and imaginary parts has the variance 1/2.
+pub trait StandardNormal: Scalar {
+    /// Fill `x` with independent standard normal random numbers
+    fn fill_standard_normal<R: Rng>(x: &mut [Self], rng: &mut R);
+}
+
+/// A pair of independent standard normal random numbers by the Box-Muller transform
+fn box_muller<R: Rng>(rng: &mut R) -> (f64, f64) {
+    // `1 - u` is in (0, 1] to avoid `ln(0)`
+    let r = (-2.0 * (1.0 - rng.gen::<f64>()).ln()).sqrt();
+    let theta = 2.0 * std::f64::consts::PI * rng.gen::<f64>();
+    (r * theta.cos(), r * theta.sin())
+}
+
+macro_rules! impl_standard_normal_real {
+    ($real:ty) => {
+        impl StandardNormal for $real {
+            fn fill_standard_normal<R: Rng>(x: &mut [Self], rng: &mut R) {
+                for pair in x.chunks_mut(2) {
+                    let (z1, z2) = box_muller(rng);
+                    pair[0] = z1 as $real;
+                    if let Some(x) = pair.get_mut(1) {
+                        *x = z2 as $real;
+                    }
+                }
+            }
+        }
+    };
+}
+impl_standard_normal_real!(f32);
+impl_standard_normal_real!(f64);
+
+macro_rules! impl_standard_normal_complex {
+    ($complex:ty) => {
+        impl StandardNormal for $complex {
+            fn fill_standard_normal<R: Rng>(x: &mut [Self], rng: &mut R) {
+                for x in x {
+                    let (re, im) = box_muller(rng);
+                    *x = Self::complex(re * 0.5_f64.sqrt(), im * 0.5_f64.sqrt());
+                }
+            }
+        }
+    };
+}
+impl_standard_normal_complex!(c32);
+impl_standard_normal_complex!(c64);
+
 /// construct matrix from diag
 pub fn from_diag<A>(d: &[A]) -> Array2<A>
 where
@@ -104,6 +190,250 @@ where
     e
 }
 
+/// construct Toeplitz matrix from the first column `c` and the first row `r`
+///
+/// The diagonal is `c[0]`, and `r[0]` is ignored as in `scipy.linalg.toeplitz`.
+/// The shape of the result is `(c.len(), r.len())`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let t = toeplitz(&array![1, 2, 3], &array![0, 4, 5]);
+/// assert_eq!(t, array![[1, 4, 5], [2, 1, 4], [3, 2, 1]]);
+/// ```
+pub fn toeplitz<A, S1, S2>(c: &ArrayBase<S1, Ix1>, r: &ArrayBase<S2, Ix1>) -> Array2<A>
+where
+    A: Clone,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    Array2::from_shape_fn((c.len(), r.len()), |(i, j)| {
+        if i >= j {
+            c[i - j].clone()
+        } else {
+            r[j - i].clone()
+        }
+    })
+}
+
+/// construct circulant matrix from the first column `c`
+///
+/// Each column is the previous one rotated downwards,
+/// i.e. the Toeplitz matrix whose first row is `c[0], c[n-1], ..., c[1]`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let c = circulant(&array![1, 2, 3]);
+/// assert_eq!(c, array![[1, 3, 2], [2, 1, 3], [3, 2, 1]]);
+/// ```
+pub fn circulant<A, S>(c: &ArrayBase<S, Ix1>) -> Array2<A>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+{
+    let n = c.len();
+    Array2::from_shape_fn((n, n), |(i, j)| c[(n + i - j) % n].clone())
+}
+
+/// construct Vandermonde matrix whose columns are the powers `x^0, x^1, ..., x^degree`
+///
+/// The columns are in decreasing order of the powers `x^degree, ..., x^0` unless `increasing`,
+/// as `numpy.vander`. The shape of the result is `(x.len(), degree + 1)`.
+/// Combined with [LeastSquaresSvd](crate::least_squares::LeastSquaresSvd),
+/// this fits a polynomial to data.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let v = vandermonde(&array![1.0, 2.0, 3.0], 2, true);
+/// assert_eq!(v, array![[1.0, 1.0, 1.0], [1.0, 2.0, 4.0], [1.0, 3.0, 9.0]]);
+/// let v = vandermonde(&array![1.0, 2.0, 3.0], 2, false);
+/// assert_eq!(v, array![[1.0, 1.0, 1.0], [4.0, 2.0, 1.0], [9.0, 3.0, 1.0]]);
+/// ```
+pub fn vandermonde<A, S>(x: &ArrayBase<S, Ix1>, degree: usize, increasing: bool) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut v = Array2::zeros((x.len(), degree + 1));
+    for (mut row, &x) in v.axis_iter_mut(Axis(0)).zip(x.iter()) {
+        let mut p = A::one();
+        for k in 0..=degree {
+            let j = if increasing { k } else { degree - k };
+            row[j] = p;
+            p *= x;
+        }
+    }
+    v
+}
+
+/// construct companion matrix of the polynomial `c[0] x^n + c[1] x^(n-1) + ... + c[n]`
+///
+/// The coefficients are in decreasing order of the powers as `scipy.linalg.companion`,
+/// and the first row of the `n x n` result is `-c[1..] / c[0]` with ones on the subdiagonal.
+/// Its eigenvalues are the roots of the polynomial.
+///
+/// Returns `ZeroLeadingCoefficient` error if `c[0]` is zero,
+/// and `Shape` error if less than two coefficients are given.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // 2 x^2 - 6 x + 4 = 2 (x - 1)(x - 2)
+/// let c = companion(&array![2.0, -6.0, 4.0]).unwrap();
+/// assert_eq!(c, array![[3.0, -2.0], [1.0, 0.0]]);
+/// ```
+pub fn companion<A, S>(c: &ArrayBase<S, Ix1>) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if c.len() < 2 {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if c[0].is_zero() {
+        return Err(LinalgError::ZeroLeadingCoefficient);
+    }
+    let n = c.len() - 1;
+    let mut m = Array2::zeros((n, n));
+    for j in 0..n {
+        m[(0, j)] = -c[j + 1] / c[0];
+    }
+    for i in 1..n {
+        m[(i, i - 1)] = A::one();
+    }
+    Ok(m)
+}
+
+/// construct block diagonal matrix from (possibly non-square) blocks
+///
+/// The shape of the result is the sum of the shapes of the blocks,
+/// and the empty slice gives a `0x0` matrix.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[1.0, 2.0]];
+/// let b = array![[3.0], [4.0]];
+/// let c = block_diag(&[a.view(), b.view()]);
+/// assert_eq!(c, array![[1.0, 2.0, 0.0], [0.0, 0.0, 3.0], [0.0, 0.0, 4.0]]);
+/// ```
+pub fn block_diag<A, S>(blocks: &[ArrayBase<S, Ix2>]) -> Array2<A>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let n = blocks.iter().map(|b| b.nrows()).sum();
+    let m = blocks.iter().map(|b| b.ncols()).sum();
+    let mut a = Array::zeros((n, m));
+    let (mut i, mut j) = (0, 0);
+    for b in blocks {
+        let (bn, bm) = b.dim();
+        a.slice_mut(s![i..i + bn, j..j + bm]).assign(b);
+        i += bn;
+        j += bm;
+    }
+    a
+}
+
+/// construct Kronecker sum `A ⊕ B = A ⊗ I + I ⊗ B` of square matrices
+///
+/// The result is `(n_a n_b) x (n_a n_b)` in the same index convention as `ndarray::linalg::kron`,
+/// and its eigenvalues are `λ_i(A) + μ_j(B)` with the eigenvectors `x_i ⊗ y_j`.
+/// For example, the Laplacian on a 2D grid is the Kronecker sum of the 1D Laplacians.
+/// [kron_solve](crate::solve::kron_solve) solves the Kronecker product instead.
+///
+/// Returns `NotSquare` error if `A` or `B` is not square.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[1.0, 2.0], [0.0, 3.0]];
+/// let b = array![[10.0]];
+/// assert_eq!(kron_sum(&a, &b).unwrap(), array![[11.0, 2.0], [0.0, 13.0]]);
+/// ```
+pub fn kron_sum<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    b.ensure_square()?;
+    let (na, nb) = (a.nrows(), b.nrows());
+    let mut m = Array2::zeros((na * nb, na * nb));
+    for ((i, j), &a) in a.indexed_iter() {
+        for k in 0..nb {
+            m[(i * nb + k, j * nb + k)] = a;
+        }
+    }
+    for i in 0..na {
+        let mut block = m.slice_mut(s![i * nb..(i + 1) * nb, i * nb..(i + 1) * nb]);
+        block += b;
+    }
+    Ok(m)
+}
+
+/// concatenate matrices horizontally
+///
+/// Different from `hstack` stacking vectors, this concatenates the columns of matrices.
+/// Returns `IncompatibleBlock` error with the index of the first block
+/// whose number of rows differs from the first one.
+/// The empty slice gives a `0x0` matrix.
+pub fn hconcat<A, S>(xs: &[ArrayBase<S, Ix2>]) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    concat_blocks(Axis(1), xs)
+}
+
+/// concatenate matrices vertically
+///
+/// Different from `vstack` stacking vectors, this concatenates the rows of matrices.
+/// Returns `IncompatibleBlock` error with the index of the first block
+/// whose number of columns differs from the first one.
+/// The empty slice gives a `0x0` matrix.
+pub fn vconcat<A, S>(xs: &[ArrayBase<S, Ix2>]) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    concat_blocks(Axis(0), xs)
+}
+
+fn concat_blocks<A, S>(axis: Axis, xs: &[ArrayBase<S, Ix2>]) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    if xs.is_empty() {
+        return Ok(Array2::zeros((0, 0)));
+    }
+    let other = Axis(1 - axis.index());
+    let expected = xs[0].len_of(other);
+    if let Some((index, x)) = xs
+        .iter()
+        .enumerate()
+        .find(|(_, x)| x.len_of(other) != expected)
+    {
+        return Err(LinalgError::IncompatibleBlock {
+            index,
+            expected,
+            actual: x.len_of(other),
+        });
+    }
+    let views: Vec<_> = xs.iter().map(|x| x.view()).collect();
+    Ok(concatenate(axis, &views)?)
+}
+
 /// stack vectors into matrix horizontally
 pub fn hstack<A, S>(xs: &[ArrayBase<S, Ix1>]) -> Result<Array<A, Ix2>>
 where
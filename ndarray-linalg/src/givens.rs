@@ -0,0 +1,64 @@
+//! Givens rotations
+//!
+//! Building blocks for structured updates of factorizations, e.g. QR updates or bidiagonalization.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let mut a: Array2<f64> = array![[3.0, 1.0], [4.0, 2.0]];
+//! // eliminate a[(1, 0)] by rotating the rows 0 and 1
+//! let (c, s, r) = givens(a[(0, 0)], a[(1, 0)]);
+//! apply_givens(&mut a, Axis(0), 0, 1, c, s);
+//! assert!((a[(0, 0)] - r).abs() < 1e-9);
+//! assert!(a[(1, 0)].abs() < 1e-9);
+//! ```
+
+use ndarray::*;
+
+use crate::types::*;
+
+/// Generate a Givens rotation `(c, s, r)` which eliminates `b`, i.e.
+///
+/// ```text
+/// [  c        s ] [ a ]   [ r ]
+/// [ -conj(s)  c ] [ b ] = [ 0 ]
+/// ```
+///
+/// with real `c` and `c^2 + |s|^2 = 1`.
+/// This uses LAPACK `*lartg` which avoids the overflow and underflow of the naive formula.
+pub fn givens<A: Scalar + Lapack>(a: A, b: A) -> (A::Real, A, A) {
+    A::lartg(a, b)
+}
+
+/// Apply a Givens rotation generated by [givens] to the rows (`Axis(0)`) or columns (`Axis(1)`) `i` and `j` in place
+///
+/// Each pair of elements `x` (in `i`) and `y` (in `j`) is replaced by
+/// `c x + s y` and `c y - conj(s) x`, the convention of LAPACK `*rot`.
+/// For `Axis(0)`, this multiplies the rotation from the left.
+///
+/// Panics if `i == j` or the indices are out of bounds.
+pub fn apply_givens<A, S>(
+    m: &mut ArrayBase<S, Ix2>,
+    axis: Axis,
+    i: usize,
+    j: usize,
+    c: A::Real,
+    s: A,
+) where
+    A: Scalar,
+    S: DataMut<Elem = A>,
+{
+    let (x, y) = match axis {
+        Axis(0) => m.multi_slice_mut((s![i, ..], s![j, ..])),
+        _ => m.multi_slice_mut((s![.., i], s![.., j])),
+    };
+    let c = A::from_real(c);
+    Zip::from(x).and(y).for_each(|x, y| {
+        let (xv, yv) = (*x, *y);
+        *x = c * xv + s * yv;
+        *y = c * yv - s.conj() * xv;
+    });
+}
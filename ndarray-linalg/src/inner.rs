@@ -1,3 +1,4 @@
+use crate::error::*;
 use crate::types::*;
 use ndarray::*;
 
@@ -30,3 +31,58 @@ where
             .into_inner()
     }
 }
+
+/// Outer product `x y^H` as a `(x.len(), y.len())` matrix
+///
+/// Different from `outer_t`, this takes complex conjugate of `y` elements
+/// as the adjoint of `InnerProduct`, i.e. `outer(x, y).dot(z) == x * y.inner(z)`.
+pub fn outer<A, Sx, Sy>(x: &ArrayBase<Sx, Ix1>, y: &ArrayBase<Sy, Ix1>) -> Array2<A>
+where
+    A: Scalar,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+{
+    Array2::from_shape_fn((x.len(), y.len()), |(i, j)| x[i] * y[j].conj())
+}
+
+/// Outer product `x y^T` as a `(x.len(), y.len())` matrix without complex conjugate
+pub fn outer_t<A, Sx, Sy>(x: &ArrayBase<Sx, Ix1>, y: &ArrayBase<Sy, Ix1>) -> Array2<A>
+where
+    A: Scalar,
+    Sx: Data<Elem = A>,
+    Sy: Data<Elem = A>,
+{
+    Array2::from_shape_fn((x.len(), y.len()), |(i, j)| x[i] * y[j])
+}
+
+/// Element-wise (Hadamard) product `a[i, j] * b[i, j]` without complex conjugate
+///
+/// Returns `Shape` error if the shapes of `a` and `b` differ.
+pub fn hadamard<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.shape() != b.shape() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(Zip::from(a).and(b).map_collect(|a, b| *a * *b))
+}
+
+/// Element-wise (Hadamard) product `conj(a[i, j]) * b[i, j]`
+///
+/// The complex conjugate of `a` is taken as `InnerProduct`,
+/// i.e. the sum of the elements is the Frobenius inner product of `a` and `b`.
+/// Returns `Shape` error if the shapes of `a` and `b` differ.
+pub fn hadamard_conj<A, Sa, Sb>(a: &ArrayBase<Sa, Ix2>, b: &ArrayBase<Sb, Ix2>) -> Result<Array2<A>>
+where
+    A: Scalar,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    if a.shape() != b.shape() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    Ok(Zip::from(a).and(b).map_collect(|a, b| a.conj() * *b))
+}
@@ -0,0 +1,16 @@
+//! Conversions between `ndarray` and other linear algebra crates
+//!
+//! Each conversion is enabled by the feature of the same name as the crate:
+//!
+//! - `nalgebra`: `DMatrix` <-> `Array2`
+//! - `sprs`: `CsMat` (CSR or CSC) -> `Array2`
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "sprs")]
+mod sprs;
+
+#[cfg(feature = "nalgebra")]
+pub use self::nalgebra::*;
+#[cfg(feature = "sprs")]
+pub use self::sprs::*;
@@ -0,0 +1,70 @@
+//! Conversions from/to `nalgebra::DMatrix`
+
+use nalgebra::DMatrix;
+use ndarray::*;
+
+use crate::types::*;
+
+/// Convert a matrix reference into `nalgebra::DMatrix`
+pub trait ToNalgebra<A> {
+    /// Copy the matrix into a `DMatrix`
+    ///
+    /// A Fortran-contiguous matrix is copied as a single slice,
+    /// since `DMatrix` is also column-major.
+    fn to_nalgebra(&self) -> DMatrix<A>;
+}
+
+/// Convert a matrix into `nalgebra::DMatrix`
+pub trait IntoNalgebra<A> {
+    /// Convert the matrix into a `DMatrix`
+    ///
+    /// The allocation is reused if the matrix is Fortran-contiguous,
+    /// without copy unless the array is a slice of a larger one.
+    fn into_nalgebra(self) -> DMatrix<A>;
+}
+
+impl<A, S> ToNalgebra<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    fn to_nalgebra(&self) -> DMatrix<A> {
+        let (n, m) = self.dim();
+        match self.t().as_slice() {
+            Some(data) => DMatrix::from_column_slice(n, m, data),
+            None => DMatrix::from_fn(n, m, |i, j| self[(i, j)]),
+        }
+    }
+}
+
+impl<A> IntoNalgebra<A> for Array2<A>
+where
+    A: Scalar,
+{
+    fn into_nalgebra(self) -> DMatrix<A> {
+        let (n, m) = self.dim();
+        if !self.t().is_standard_layout() {
+            return self.to_nalgebra();
+        }
+        if n * m == 0 {
+            return DMatrix::from_vec(n, m, Vec::new());
+        }
+        // The buffer of a sliced array also keeps the elements out of the slice,
+        // while the elements in the slice are contiguous from `ptr`
+        let ptr = self.as_ptr();
+        let mut data = self.into_raw_vec();
+        let offset = (ptr as usize - data.as_ptr() as usize) / std::mem::size_of::<A>();
+        if offset != 0 || data.len() != n * m {
+            data.drain(..offset);
+            data.truncate(n * m);
+        }
+        DMatrix::from_vec(n, m, data)
+    }
+}
+
+/// Convert `nalgebra::DMatrix` into a Fortran-contiguous `Array2` without copy
+pub fn from_nalgebra<A: Scalar>(m: DMatrix<A>) -> Array2<A> {
+    let shape = m.shape().f();
+    let data: Vec<A> = m.data.into();
+    Array2::from_shape_vec(shape, data).unwrap()
+}
@@ -0,0 +1,55 @@
+//! Dense-ize `sprs` sparse matrices
+
+use ndarray::*;
+use sprs::CsMat;
+use std::ops::Range;
+
+use crate::error::*;
+use crate::types::*;
+
+/// Convert a sparse matrix in CSR or CSC storage into a dense matrix
+pub fn from_sprs<A: Scalar>(m: &CsMat<A>) -> Array2<A> {
+    let (n, k) = m.shape();
+    sprs_block(m, 0..n, 0..k).unwrap()
+}
+
+/// Extract the block `m[rows, cols]` of a sparse matrix in CSR or CSC storage as a dense matrix
+///
+/// Only the outer dimensions (rows for CSR, columns for CSC) in the range are visited,
+/// so this is cheap for a small block of a large matrix.
+/// Returns `IncompatibleShape` error if the ranges are out of the matrix.
+pub fn sprs_block<A: Scalar>(
+    m: &CsMat<A>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> Result<Array2<A>> {
+    if rows.start > rows.end || cols.start > cols.end || rows.end > m.rows() || cols.end > m.cols()
+    {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut block = Array2::zeros((rows.len(), cols.len()));
+    let (outer, inner) = if m.is_csr() {
+        (rows.clone(), cols.clone())
+    } else {
+        (cols.clone(), rows.clone())
+    };
+    for i in outer.clone() {
+        let vec = m.outer_view(i).unwrap();
+        for (j, &val) in vec.iter() {
+            if !inner.contains(&j) {
+                continue;
+            }
+            let (r, c) = if m.is_csr() { (i, j) } else { (j, i) };
+            block[(r - rows.start, c - cols.start)] = val;
+        }
+    }
+    Ok(block)
+}
+
+/// Extract the diagonal block `m[range, range]` of a sparse matrix as a dense matrix
+///
+/// This is intended for e.g. domain decomposition, where the dense blocks are
+/// solved by LAPACK.
+pub fn sprs_diagonal_block<A: Scalar>(m: &CsMat<A>, range: Range<usize>) -> Result<Array2<A>> {
+    sprs_block(m, range.clone(), range)
+}
@@ -1,8 +1,8 @@
 //! Arnoldi iteration
 
 use super::*;
-use crate::{norm::Norm, operator::LinearOperator};
-use num_traits::One;
+use crate::{error::Result, norm::Norm, operator::LinearOperator};
+use num_traits::{Float, One, Zero};
 use std::iter::*;
 
 /// Execute Arnoldi iteration as Rust iterator
@@ -123,3 +123,64 @@ where
     let mgs = MGS::new(v.len(), tol);
     Arnoldi::new(a, v, mgs).complete()
 }
+
+/// Krylov subspace generated by [arnoldi_reorthogonalized]
+pub(crate) struct KrylovBasis<A: Scalar> {
+    /// Orthonormal basis of the subspace
+    pub mgs: MGS<A>,
+    /// Columns of the Hessenberg matrix down to the diagonal, i.e. `h[j]` has `j + 1` elements
+    pub h: Vec<Array1<A>>,
+    /// Subdiagonal of the Hessenberg matrix, whose last element is the norm of the residual
+    pub sub: Vec<A::Real>,
+    /// Whether the residual vanishes, i.e. the subspace is invariant
+    pub invariant: bool,
+}
+
+/// Arnoldi iteration from `v0` with full reorthogonalization
+///
+/// `stop` is called after each step, and the iteration ends when it returns `true`,
+/// the subspace becomes invariant, or its dimension reaches `max_dim` or the dimension of `v0`.
+/// Panics if `v0` is zero.
+pub(crate) fn arnoldi_reorthogonalized<A, S>(
+    matvec: impl Fn(&Array1<A>) -> Array1<A>,
+    v0: &ArrayBase<S, Ix1>,
+    max_dim: usize,
+    mut stop: impl FnMut(&KrylovBasis<A>) -> Result<bool>,
+) -> Result<KrylovBasis<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let n = v0.len();
+    let norm = v0.norm_l2();
+    assert!(
+        !norm.is_zero(),
+        "initial vector of Krylov subspace must be non-zero"
+    );
+    let mut basis = KrylovBasis {
+        mgs: MGS::new(n, A::Real::zero()),
+        h: Vec::new(),
+        sub: Vec::new(),
+        invariant: false,
+    };
+    let mut v = v0.mapv(|v| v.div_real(norm));
+    basis.mgs.append(v.view());
+    let max_dim = std::cmp::max(std::cmp::min(max_dim, n), 1);
+    loop {
+        let mut w = matvec(&v);
+        let wnorm = w.norm_l2();
+        // Reorthogonalize twice to keep the basis orthogonal in floating point
+        let c1 = basis.mgs.decompose(&mut w);
+        let c2 = basis.mgs.decompose(&mut w);
+        let m = basis.mgs.len();
+        let b = c2[m].re();
+        basis.h.push(&c1.slice(s![..m]) + &c2.slice(s![..m]));
+        basis.sub.push(b);
+        basis.invariant = b <= A::Real::epsilon() * wnorm;
+        if stop(&basis)? || basis.invariant || m == max_dim {
+            return Ok(basis);
+        }
+        v = w.mapv(|w| w.div_real(b));
+        basis.mgs.append(v.view());
+    }
+}
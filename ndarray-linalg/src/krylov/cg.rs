@@ -0,0 +1,93 @@
+//! Conjugate gradient method
+
+use super::*;
+use crate::{inner::InnerProduct, norm::Norm, operator::LinearOperator};
+use num_traits::Zero;
+
+/// Result of [cg]
+#[derive(Debug, Clone)]
+pub struct CgResult<A: Scalar> {
+    /// The approximated solution `x`
+    pub solution: Array1<A>,
+    /// The residual norms `|b - Ax|` of the initial guess and each iteration
+    ///
+    /// These are from the recursively updated residual, which may deviate from `b - Ax`
+    /// by rounding errors after many iterations.
+    pub residual_norms: Vec<A::Real>,
+    /// Whether `|b - Ax| <= tol |b|` is reached
+    pub converged: bool,
+}
+
+/// Solve `Ax = b` for a Hermitian positive definite linear operator `a` by the conjugate gradient method
+///
+/// - [Conjugate gradient method - Wikipedia](https://en.wikipedia.org/wiki/Conjugate_gradient_method)
+///
+/// The iteration stops when `|b - Ax| <= tol |b|` or after `max_iter` iterations.
+/// The initial guess is zero.
+///
+/// Panics if the size of `b` mismatches to `a`.
+pub fn cg<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    b: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> CgResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    cg_preconditioned(a, b, |r| r.clone(), tol, max_iter)
+}
+
+/// Solve `Ax = b` by the conjugate gradient method with the preconditioner `m_inv(r) = M^{-1} r`
+///
+/// `M` should be Hermitian positive definite and approximate `A`,
+/// e.g. the diagonal of `A` or an incomplete Cholesky factorization.
+/// The residual norms and the stopping criterion are those of the original system `|b - Ax|`.
+///
+/// Panics if the size of `b` mismatches to `a`.
+pub fn cg_preconditioned<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    b: &ArrayBase<S, Ix1>,
+    m_inv: impl Fn(&Array1<A>) -> Array1<A>,
+    tol: A::Real,
+    max_iter: usize,
+) -> CgResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let n = b.len();
+    let bnorm = b.norm_l2();
+    let mut x = Array1::zeros(n);
+    let mut r = b.to_owned();
+    let mut residual_norms = vec![bnorm];
+    let mut converged = bnorm.is_zero();
+    let mut z = m_inv(&r);
+    let mut p = z.clone();
+    // `r^H M^{-1} r` is real for Hermitian `M`
+    let mut rz = r.inner(&z).re();
+    for _ in 0..max_iter {
+        if converged {
+            break;
+        }
+        let ap = a.apply(&p);
+        let alpha = A::from_real(rz / p.inner(&ap).re());
+        azip!((x in &mut x, &p in &p) *x += alpha * p);
+        azip!((r in &mut r, &ap in &ap) *r -= alpha * ap);
+        let rnorm = r.norm_l2();
+        residual_norms.push(rnorm);
+        converged = rnorm <= tol * bnorm;
+
+        z = m_inv(&r);
+        let rz_next = r.inner(&z).re();
+        let beta = A::from_real(rz_next / rz);
+        rz = rz_next;
+        azip!((p in &mut p, &z in &z) *p = z + beta * *p);
+    }
+    CgResult {
+        solution: x,
+        residual_norms,
+        converged,
+    }
+}
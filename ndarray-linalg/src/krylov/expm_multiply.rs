@@ -0,0 +1,78 @@
+//! Action of the matrix exponential
+
+use super::arnoldi::arnoldi_reorthogonalized;
+use super::*;
+use crate::{
+    error::{LinalgError, Result},
+    expm::Expm,
+    norm::Norm,
+    operator::LinearOperator,
+};
+use num_traits::Zero;
+
+/// Compute `exp(t A) v` by the Krylov subspace approximation without forming `exp(t A)`
+///
+/// The Arnoldi iteration starting from `v` gives `A Q_m ≈ Q_m H_m`, and
+/// `exp(t A) v ≈ |v| Q_m exp(t H_m) e_1` is computed by [Expm] of the small Hessenberg matrix `H_m`.
+/// The dimension of the Krylov subspace grows until the a posteriori error estimate
+/// `|v| |t| h_{m+1,m} |[exp(t H_m) e_1]_m|` by Y. Saad, SIAM J. Numer. Anal. 29, 209 (1992)
+/// is smaller than `tol |v|`, or the subspace becomes invariant.
+///
+/// Returns `NotConverged` error if the dimension reaches `max_dim`.
+/// The required dimension grows with `|t A|`, and thus split `t` into several steps for a large `|t A|`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a: Array2<f64> = array![[0., -1.], [1., 0.]];
+/// let v = array![1., 0.];
+/// let w = expm_multiply(a.view(), &v, 1.0, 1e-12, 10).unwrap();
+/// assert!(w.abs_diff_eq(&array![1.0_f64.cos(), 1.0_f64.sin()], 1e-10));
+/// ```
+pub fn expm_multiply<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    v: &ArrayBase<S, Ix1>,
+    t: A,
+    tol: A::Real,
+    max_dim: usize,
+) -> Result<Array1<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let beta = v.norm_l2();
+    if beta.is_zero() || t.is_zero() {
+        return Ok(v.to_owned());
+    }
+    // First column of `exp(t H_m)` and the convergence of the last step
+    let mut y = Array1::zeros(0);
+    let mut converged = false;
+    let basis = arnoldi_reorthogonalized(
+        |q| a.apply(q),
+        v,
+        max_dim,
+        |basis| {
+            let m = basis.h.len();
+            let mut hm = Array2::<A>::zeros((m, m));
+            for (j, col) in basis.h.iter().enumerate() {
+                hm.slice_mut(s![..col.len(), j]).assign(col);
+                if j + 1 < m {
+                    hm[(j + 1, j)] = A::from_real(basis.sub[j]);
+                }
+            }
+            let e = hm.mapv(|x| x * t).expm()?;
+            y = e.column(0).to_owned();
+            let error = beta * t.abs() * basis.sub[m - 1] * y[m - 1].abs();
+            converged = error <= tol * beta;
+            Ok(converged)
+        },
+    )?;
+    if !(converged || basis.invariant) {
+        return Err(LinalgError::NotConverged {
+            iterations: basis.h.len(),
+        });
+    }
+    let y = y.mapv(|y| y.mul_real(beta));
+    Ok(basis.mgs.get_q().dot(&y))
+}
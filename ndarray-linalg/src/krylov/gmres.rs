@@ -0,0 +1,138 @@
+//! Generalized minimal residual method (GMRES)
+
+use super::*;
+use crate::{givens::givens, norm::Norm, operator::LinearOperator};
+use num_traits::{Float, Zero};
+
+/// Result of [gmres]
+#[derive(Debug, Clone)]
+pub struct GmresResult<A: Scalar> {
+    /// The approximated solution `x`
+    pub solution: Array1<A>,
+    /// The residual norms `|b - Ax|` of the initial guess and each iteration
+    ///
+    /// These are the estimates from the least square problem in the Krylov subspace,
+    /// except the last one computed explicitly at the end of each restart cycle.
+    pub residual_norms: Vec<A::Real>,
+    /// Whether `|b - Ax| <= tol |b|` is reached
+    pub converged: bool,
+}
+
+/// Solve `Ax = b` for a general (non-Hermitian) linear operator `a` by restarted GMRES
+///
+/// - [Generalized minimal residual method - Wikipedia](https://en.wikipedia.org/wiki/Generalized_minimal_residual_method)
+///
+/// The Krylov subspace is built by Arnoldi iteration with the modified Gram-Schmit orthogonalizer,
+/// and the least square problem in it is solved using Givens rotations.
+/// The iteration restarts from the current solution after every `restart` iterations,
+/// and stops when `|b - Ax| <= tol |b|` or after `max_iter` iterations in total.
+/// The initial guess is zero.
+///
+/// Panics if `restart` is zero or the size of `b` mismatches to `a`.
+pub fn gmres<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    b: &ArrayBase<S, Ix1>,
+    restart: usize,
+    tol: A::Real,
+    max_iter: usize,
+) -> GmresResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    gmres_preconditioned(a, b, |v| v.clone(), restart, tol, max_iter)
+}
+
+/// Solve `Ax = b` by restarted GMRES with the right preconditioner `m_inv(v) = M^{-1} v`
+///
+/// This solves `A M^{-1} u = b` by [gmres] and returns `x = M^{-1} u`,
+/// where `M` should approximate `A` so that `A M^{-1}` is close to the identity,
+/// e.g. [jacobi_preconditioner](crate::jacobi_preconditioner).
+/// Since the preconditioner is applied from the right,
+/// the residual norms and the stopping criterion are those of the original system `|b - Ax|`.
+///
+/// Panics if `restart` is zero or the size of `b` mismatches to `a`.
+pub fn gmres_preconditioned<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    b: &ArrayBase<S, Ix1>,
+    m_inv: impl Fn(&Array1<A>) -> Array1<A>,
+    restart: usize,
+    tol: A::Real,
+    max_iter: usize,
+) -> GmresResult<A>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    assert!(restart > 0, "restart of GMRES must be positive");
+    let n = b.len();
+    let bnorm = b.norm_l2();
+    let mut x = Array1::zeros(n);
+    let mut residual_norms = vec![bnorm];
+    let mut r = b.to_owned();
+    let mut iter = 0;
+    let mut converged = bnorm.is_zero();
+    while !converged && iter < max_iter {
+        let beta = residual_norms[residual_norms.len() - 1];
+        let mut v = r.mapv(|r| r.div_real(beta));
+        let mut mgs = MGS::new(n, A::Real::epsilon());
+        mgs.append(v.view());
+
+        let m = std::cmp::min(restart, max_iter - iter);
+        let mut h: Array2<A> = Array2::zeros((m + 1, m));
+        let mut g: Array1<A> = Array1::zeros(m + 1);
+        g[0] = A::from_real(beta);
+        let mut rotations: Vec<(A::Real, A)> = Vec::with_capacity(m);
+        let mut k = 0;
+        while k < m {
+            let mut w = a.apply(&m_inv(&v));
+            let result = mgs.div_append(&mut w);
+            for (i, c) in result.coeff().iter().enumerate() {
+                h[(i, k)] = *c;
+            }
+            // Make the new column of H upper triangular
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (hi, hj) = (h[(i, k)], h[(i + 1, k)]);
+                h[(i, k)] = A::from_real(c) * hi + s * hj;
+                h[(i + 1, k)] = A::from_real(c) * hj - s.conj() * hi;
+            }
+            let (c, s, rkk) = givens(h[(k, k)], h[(k + 1, k)]);
+            rotations.push((c, s));
+            h[(k, k)] = rkk;
+            h[(k + 1, k)] = A::zero();
+            g[k + 1] = -s.conj() * g[k];
+            g[k] = A::from_real(c) * g[k];
+            k += 1;
+            iter += 1;
+
+            let res = g[k].abs();
+            residual_norms.push(res);
+            if res <= tol * bnorm || result.is_dependent() {
+                break;
+            }
+            v = w;
+        }
+
+        // Solve the upper triangular system `H y = g` by back substitution
+        let mut y = g.slice(s![..k]).to_owned();
+        for i in (0..k).rev() {
+            for j in (i + 1)..k {
+                let hy = h[(i, j)] * y[j];
+                y[i] -= hy;
+            }
+            y[i] /= h[(i, i)];
+        }
+        let q = mgs.get_q();
+        x += &m_inv(&q.slice(s![.., ..k]).dot(&y));
+
+        r = b - &a.apply(&x);
+        let rnorm = r.norm_l2();
+        *residual_norms.last_mut().unwrap() = rnorm;
+        converged = rnorm <= tol * bnorm;
+    }
+    GmresResult {
+        solution: x,
+        residual_norms,
+        converged,
+    }
+}
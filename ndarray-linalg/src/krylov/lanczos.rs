@@ -0,0 +1,124 @@
+//! Lanczos iteration
+
+use super::arnoldi::arnoldi_reorthogonalized;
+use super::*;
+use crate::operator::LinearOperator;
+use crate::{eigh::eigh_tridiagonal, error::Result, lobpcg::TruncatedOrder, norm::Norm};
+use num_traits::{Float, Zero};
+
+/// Result of [lanczos]
+#[derive(Debug, Clone)]
+pub struct LanczosResult<A: Scalar> {
+    /// The Ritz values sorted as specified by the order,
+    /// i.e. descending for `Largest` and ascending for `Smallest`
+    pub eigvals: Array1<A::Real>,
+    /// The Ritz vectors as columns
+    pub eigvecs: Array2<A>,
+    /// The residual norms `|Ax - λx|` of each Ritz pair
+    pub residual_norms: Array1<A::Real>,
+    /// Whether all residual norms satisfy the tolerance
+    pub converged: bool,
+}
+
+/// Find `k` largest or smallest eigenpairs of a Hermitian linear operator `a` by Lanczos iteration
+///
+/// - [Lanczos algorithm - Wikipedia](https://en.wikipedia.org/wiki/Lanczos_algorithm)
+///
+/// The Krylov subspace is generated from the initial vector `v0`, and the Lanczos vectors are
+/// fully reorthogonalized to avoid the spurious copies of eigenvalues.
+/// The Ritz pairs are computed from the tridiagonal matrix by [eigh_tridiagonal].
+/// The iteration stops when the residual norms of all `k` Ritz pairs are smaller than
+/// `tol` times the largest absolute Ritz value, or the dimension of the Krylov subspace
+/// reaches `max_iter` or the dimension of `a`.
+///
+/// Less than `k` pairs are returned as not converged if `v0` lies in an invariant subspace
+/// of the dimension less than `k`.
+/// Panics if `k` is zero or `v0` is zero.
+pub fn lanczos<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    v0: &ArrayBase<S, Ix1>,
+    k: usize,
+    order: TruncatedOrder,
+    tol: A::Real,
+    max_iter: usize,
+) -> Result<LanczosResult<A>>
+where
+    A: Scalar + Lapack,
+    A::Real: Lapack,
+    S: Data<Elem = A>,
+{
+    assert!(k > 0, "number of eigenpairs must be positive");
+    assert!(
+        !v0.norm_l2().is_zero(),
+        "initial vector of Lanczos must be non-zero"
+    );
+    // Ritz values, the coefficients of the Ritz vectors, the residual norms and the convergence
+    // of the last step
+    let mut ritz = None;
+    let (_, _, mgs) = lanczos_tridiagonal(
+        |v| a.apply(v),
+        v0,
+        max_iter,
+        |alpha, beta| {
+            let m = alpha.len();
+            let (theta, s) = eigh_tridiagonal(
+                &Array1::from(alpha.to_vec()),
+                &Array1::from(beta[..m - 1].to_vec()),
+            )?;
+            let size = std::cmp::min(k, m);
+            let idx: Vec<usize> = match order {
+                TruncatedOrder::Largest => (m - size..m).rev().collect(),
+                TruncatedOrder::Smallest => (0..size).collect(),
+            };
+            let residual_norms: Array1<A::Real> = idx
+                .iter()
+                .map(|&i| beta[m - 1] * Float::abs(s[(m - 1, i)]))
+                .collect();
+            let scale = theta
+                .iter()
+                .fold(A::Real::zero(), |x, t| Float::max(x, Float::abs(*t)));
+            let converged = size == k && residual_norms.iter().all(|&r| r <= tol * scale);
+            ritz = Some((
+                theta.select(Axis(0), &idx),
+                s.select(Axis(1), &idx),
+                residual_norms,
+                converged,
+            ));
+            Ok(converged)
+        },
+    )?;
+    let (eigvals, s, residual_norms, converged) = ritz.unwrap();
+    let eigvecs = mgs.get_q().dot(&s.mapv(A::from_real));
+    Ok(LanczosResult {
+        eigvals,
+        eigvecs,
+        residual_norms,
+        converged,
+    })
+}
+
+/// Lanczos tridiagonalization of a Hermitian operator from `v0` with full reorthogonalization
+///
+/// Returns the diagonal `alpha` and the subdiagonal `beta` of the tridiagonal matrix with the Lanczos vectors.
+/// `beta` has as many elements as `alpha`, and the last one is the norm of the residual.
+/// `stop(alpha, beta)` is called after each step, and the iteration ends when it returns `true`,
+/// the Krylov subspace becomes invariant, or its dimension reaches `steps` or the dimension of `v0`.
+/// Panics if `v0` is zero.
+pub(crate) fn lanczos_tridiagonal<A, S>(
+    matvec: impl Fn(&Array1<A>) -> Array1<A>,
+    v0: &ArrayBase<S, Ix1>,
+    steps: usize,
+    mut stop: impl FnMut(&[A::Real], &[A::Real]) -> Result<bool>,
+) -> Result<(Vec<A::Real>, Vec<A::Real>, MGS<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let mut alpha = Vec::new();
+    let basis = arnoldi_reorthogonalized(matvec, v0, steps, |basis| {
+        let m = basis.h.len();
+        alpha.push(basis.h[m - 1][m - 1].re());
+        stop(&alpha, &basis.sub)
+    })?;
+    Ok((alpha, basis.sub, basis.mgs))
+}
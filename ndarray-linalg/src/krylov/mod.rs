@@ -4,12 +4,24 @@ use crate::types::*;
 use ndarray::*;
 
 pub mod arnoldi;
+pub mod cg;
+pub mod expm_multiply;
+pub mod gmres;
 pub mod householder;
+pub mod lanczos;
 pub mod mgs;
+pub mod power;
+pub mod svds;
 
 pub use arnoldi::{arnoldi_householder, arnoldi_mgs, Arnoldi};
+pub use cg::{cg, cg_preconditioned, CgResult};
+pub use expm_multiply::expm_multiply;
+pub use gmres::{gmres, gmres_preconditioned, GmresResult};
 pub use householder::{householder, Householder};
+pub use lanczos::{lanczos, LanczosResult};
 pub use mgs::{mgs, MGS};
+pub use power::{orthogonal_iteration, power_iteration, Deflate, Deflated};
+pub use svds::{svds, SvdsResult};
 
 /// Q-matrix
 ///
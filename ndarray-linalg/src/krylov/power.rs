@@ -0,0 +1,217 @@
+//! Power iteration
+
+use super::*;
+use crate::{
+    blas::gerc,
+    convert::Adjoint,
+    eig::EigVals,
+    error::{LinalgError, Result},
+    inner::InnerProduct,
+    norm::Norm,
+    operator::LinearOperator,
+    qr::QRInto,
+};
+use num_traits::{Float, One, Zero};
+
+/// Find the dominant eigenpair of a linear operator `a` by power iteration
+///
+/// - [Power iteration - Wikipedia](https://en.wikipedia.org/wiki/Power_iteration)
+///
+/// The eigenvalue is estimated by the Rayleigh quotient `x^H A x` of the normalized vector `x`,
+/// and the iteration stops when `|Ax - λx| <= tol |λ|`.
+/// Returns `NotConverged` error after `max_iter` iterations,
+/// e.g. if the dominant eigenvalues are a complex conjugate pair of a real operator.
+/// Wrap `a` by [Deflated] to find the next eigenpairs.
+///
+/// Panics if `x0` is zero.
+pub fn power_iteration<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    x0: &ArrayBase<S, Ix1>,
+    tol: A::Real,
+    max_iter: usize,
+) -> Result<(A, Array1<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let norm = x0.norm_l2();
+    assert!(
+        !norm.is_zero(),
+        "initial vector of power iteration must be non-zero"
+    );
+    let mut x = x0.mapv(|x| x.div_real(norm));
+    for _ in 0..max_iter {
+        let y = a.apply(&x);
+        let ynorm = y.norm_l2();
+        if ynorm.is_zero() {
+            // `x` is an eigenvector of the zero eigenvalue
+            return Ok((A::zero(), x));
+        }
+        let lambda = x.inner(&y);
+        let residual = Zip::from(&y)
+            .and(&x)
+            .map_collect(|&y, &x| y - lambda * x)
+            .norm_l2();
+        if residual <= tol * lambda.abs() {
+            return Ok((lambda, x));
+        }
+        x = y.mapv(|y| y.div_real(ynorm));
+    }
+    Err(LinalgError::NotConverged {
+        iterations: max_iter,
+    })
+}
+
+/// Find the dominant invariant subspace of a linear operator `a` by orthogonal iteration
+///
+/// This is the block generalization of [power_iteration] for the `k` columns of `x0`,
+/// where the block `AQ` is re-orthonormalized by the thin QR decomposition in every step.
+/// The iteration stops when the span of `Q` is invariant, i.e. `|AQ - QT|_F <= tol |T|_F`
+/// for `T = Q^H A Q`, and returns the Ritz values (the eigenvalues of `T`) in descending order
+/// of the absolute value with the orthonormal basis `Q` of the subspace.
+/// The convergence is linear with the ratio `|λ_{k+1} / λ_k|`, and thus is robust
+/// for the close dominant eigenvalues `λ_1, ..., λ_k` unlike power iteration.
+/// See G. H. Golub and C. F. Van Loan, Matrix Computations (4th ed.), Section 7.3.2.
+/// Returns `NotConverged` error after `max_iter` iterations.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = array![[3.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 0.5]];
+/// let x0 = array![[1.0, 0.0], [1.0, 1.0], [1.0, -1.0]];
+/// let (e, q) = orthogonal_iteration(a.view(), &x0, 1e-12, 1000).unwrap();
+/// assert!(e.abs_diff_eq(&array![c64::new(3.0, 0.0), c64::new(2.0, 0.0)], 1e-9));
+/// // `Q` spans the first two axes
+/// assert!(q.row(2).norm_l2() < 1e-9);
+/// ```
+pub fn orthogonal_iteration<A, S>(
+    a: impl LinearOperator<Elem = A>,
+    x0: &ArrayBase<S, Ix2>,
+    tol: A::Real,
+    max_iter: usize,
+) -> Result<(Array1<A::Complex>, Array2<A>)>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let (mut q, _) = x0.to_owned().qr_into()?;
+    for _ in 0..max_iter {
+        let z = a.apply2(&q);
+        let t = q.adjoint().dot(&z);
+        let residual = (&z - &q.dot(&t)).norm_l2();
+        if residual <= tol * t.norm_l2() {
+            let mut ritz = t.eigvals()?.to_vec();
+            ritz.sort_by(|x, y| y.abs().partial_cmp(&x.abs()).unwrap());
+            return Ok((Array1::from(ritz), q));
+        }
+        q = z.qr_into()?.0;
+    }
+    Err(LinalgError::NotConverged {
+        iterations: max_iter,
+    })
+}
+
+/// Linear operator `A - λ v v^H` removing a found eigenpair `(λ, v)` from `A`
+///
+/// This is the Hotelling deflation with the normalized eigenvector `v`.
+/// The eigenvalue `λ` is replaced by zero while the other eigenvalues are kept.
+/// The other eigenvectors are also kept if `A` is Hermitian,
+/// and wrapping repeatedly removes several eigenpairs.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a = array![[2.0, 1.0], [1.0, 2.0]];
+/// let x0 = array![1.0, 0.0];
+/// let (e1, v1) = power_iteration(a.view(), &x0, 1e-12, 1000).unwrap();
+/// assert!((e1 - 3.0).abs() < 1e-9);
+/// let (e2, _) = power_iteration(Deflated::new(a.view(), e1, v1), &x0, 1e-12, 1000).unwrap();
+/// assert!((e2 - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Deflated<A: Scalar, F> {
+    a: F,
+    eigval: A,
+    eigvec: Array1<A>,
+}
+
+impl<A, F> Deflated<A, F>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+{
+    /// Deflate the eigenpair `(eigval, eigvec)` from `a`. `eigvec` is normalized in this function.
+    pub fn new<S>(a: F, eigval: A, eigvec: ArrayBase<S, Ix1>) -> Self
+    where
+        S: Data<Elem = A>,
+    {
+        let norm = eigvec.norm_l2();
+        let eigvec = eigvec.mapv(|v| v.div_real(norm));
+        Deflated { a, eigval, eigvec }
+    }
+}
+
+impl<A, F> LinearOperator for Deflated<A, F>
+where
+    A: Scalar + Lapack,
+    F: LinearOperator<Elem = A>,
+{
+    type Elem = A;
+
+    fn apply<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let mut y = self.a.apply(x);
+        let c = self.eigval * self.eigvec.inner(x);
+        azip!((y in &mut y, &v in &self.eigvec) *y -= c * v);
+        y
+    }
+}
+
+/// Remove a found eigenpair `(λ, v)` from a matrix in place, i.e. `A = A - λ v v^H`
+///
+/// This is the Hotelling deflation, the same as [Deflated] but modifying the matrix.
+/// For a Hermitian matrix, the eigenvalue `λ` is replaced by zero
+/// while the other eigenpairs are kept.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let mut a = array![[2.0, 1.0], [1.0, 2.0]];
+/// let x0 = array![1.0, 0.0];
+/// let (e1, v1) = power_iteration(a.view(), &x0, 1e-12, 1000).unwrap();
+/// a.deflate(e1, &v1).unwrap();
+/// let (e2, _) = power_iteration(a.view(), &x0, 1e-12, 1000).unwrap();
+/// assert!((e2 - 1.0).abs() < 1e-9);
+/// ```
+pub trait Deflate<A: Scalar> {
+    /// Subtract `eigenvalue * eigenvector * eigenvector^H`
+    ///
+    /// Returns `NotNormalized` error if `|‖v‖ - 1| > √ε`,
+    /// `IncompatibleShape` error if the matrix is not `(n, n)` for `v` of length `n`,
+    /// and `InvalidStride` or `MemoryNotCont` errors if the matrix is not contiguous.
+    fn deflate<S>(&mut self, eigenvalue: A, eigenvector: &ArrayBase<S, Ix1>) -> Result<()>
+    where
+        S: Data<Elem = A>;
+}
+
+impl<A, Sa> Deflate<A> for ArrayBase<Sa, Ix2>
+where
+    A: Scalar + Lapack,
+    Sa: DataMut<Elem = A>,
+{
+    fn deflate<S>(&mut self, eigenvalue: A, eigenvector: &ArrayBase<S, Ix1>) -> Result<()>
+    where
+        S: Data<Elem = A>,
+    {
+        let norm = eigenvector.norm_l2();
+        if Float::abs(norm - A::Real::one()) > Float::sqrt(A::Real::epsilon()) {
+            return Err(LinalgError::NotNormalized);
+        }
+        gerc(-eigenvalue, eigenvector, eigenvector, self)
+    }
+}
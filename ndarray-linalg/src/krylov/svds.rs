@@ -0,0 +1,182 @@
+//! Golub-Kahan-Lanczos bidiagonalization
+
+use super::*;
+use crate::{error::Result, generate::random, norm::Norm, svd::SVD};
+use num_traits::{Float, Zero};
+
+/// Result of [svds]
+#[derive(Debug, Clone)]
+pub struct SvdsResult<A: Scalar> {
+    /// The largest singular values in descending order
+    pub singular_values: Array1<A::Real>,
+    /// The left singular vectors as columns
+    pub u: Array2<A>,
+    /// The right singular vectors as columns
+    pub v: Array2<A>,
+    /// The residual norms `|A^H u - σ v|` of each triplet, where `A v = σ u` holds by construction
+    pub residual_norms: Array1<A::Real>,
+    /// Whether all residual norms satisfy the tolerance
+    pub converged: bool,
+}
+
+/// Find `k` largest singular triplets of a linear operator of `shape` by Golub-Kahan-Lanczos bidiagonalization
+///
+/// - [Bidiagonalization - Wikipedia](https://en.wikipedia.org/wiki/Bidiagonalization)
+///
+/// The operator is accessed only by the products `matvec(x) = A x` and `matvec_adjoint(y) = A^H y`,
+/// and thus `A` of `shape = (m, n)` is not formed. This is the Krylov counterpart
+/// of [TruncatedSvd](crate::TruncatedSvd) for large operators.
+///
+/// The bidiagonalization `A Q = P B` starts from a random vector, and both bases
+/// are fully reorthogonalized. The Ritz triplets are computed by the SVD of the small bidiagonal matrix `B`.
+/// The iteration stops when the residual norms of all `k` triplets are smaller than
+/// `tol` times the largest singular value, or the dimension of the Krylov subspace
+/// reaches `min(m, n)`.
+///
+/// Less than `k` triplets are returned as not converged if the start vector lies in an invariant subspace
+/// of the dimension less than `k`.
+/// Panics if `k` is zero or larger than `min(m, n)`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a: Array2<f64> = array![[3., 0., 0.], [0., 2., 0.], [0., 0., 1.], [0., 0., 0.]];
+/// let result = svds(|x| a.dot(x), |y| a.t().dot(y), a.dim(), 2, 1e-10).unwrap();
+/// assert!(result.converged);
+/// assert!(result.singular_values.abs_diff_eq(&array![3., 2.], 1e-9));
+/// ```
+pub fn svds<A>(
+    matvec: impl Fn(&Array1<A>) -> Array1<A>,
+    matvec_adjoint: impl Fn(&Array1<A>) -> Array1<A>,
+    shape: (usize, usize),
+    k: usize,
+    tol: A::Real,
+) -> Result<SvdsResult<A>>
+where
+    A: Scalar + Lapack,
+    A::Real: Lapack,
+{
+    let (m, n) = shape;
+    let max_dim = std::cmp::min(m, n);
+    assert!(
+        k > 0 && k <= max_dim,
+        "number of singular triplets must be in 1..=min(m, n)"
+    );
+    let mut pm = MGS::new(m, A::Real::zero());
+    let mut qm = MGS::new(n, A::Real::zero());
+    let v0: Array1<A> = random(n);
+    let mut q = v0.mapv(|v| v.div_real(v0.norm_l2()));
+    qm.append(q.view());
+
+    let mut alpha: Vec<A::Real> = Vec::new();
+    let mut beta: Vec<A::Real> = Vec::new();
+    loop {
+        // `A q_j = β_{j-1} p_{j-1} + α_j p_j`
+        let mut p = matvec(&q);
+        let pnorm = p.norm_l2();
+        pm.decompose(&mut p);
+        let a = pm.decompose(&mut p)[pm.len()].re();
+        if a <= A::Real::epsilon() * pnorm {
+            // `A q_j` is in the span of `P`, and thus `B` cannot be extended.
+            // The triplets of the current `B` are returned, where `β_{j-1} q_j` is the residual.
+            let b = beta.pop().unwrap_or_else(A::Real::zero);
+            let ritz = BidiagonalRitz::new(&alpha, &beta, b, k, tol);
+            return Ok(ritz.into_result(&pm, &qm));
+        }
+        alpha.push(a);
+        let p = p.mapv(|p| p.div_real(a));
+        pm.append(p.view());
+
+        // `A^H p_j = α_j q_j + β_j q_{j+1}`
+        let mut w = matvec_adjoint(&p);
+        let wnorm = w.norm_l2();
+        qm.decompose(&mut w);
+        let b = qm.decompose(&mut w)[qm.len()].re();
+        let ritz = BidiagonalRitz::new(&alpha, &beta, b, k, tol);
+        let invariant = b <= A::Real::epsilon() * wnorm;
+        if ritz.converged || invariant || alpha.len() == max_dim {
+            return Ok(ritz.into_result(&pm, &qm));
+        }
+        beta.push(b);
+        q = w.mapv(|w| w.div_real(b));
+        qm.append(q.view());
+    }
+}
+
+/// Ritz triplets of the `j x j` upper bidiagonal matrix `B`
+struct BidiagonalRitz<R> {
+    s: Array1<R>,
+    /// Left singular vectors of `B`
+    u: Array2<R>,
+    /// Right singular vectors of `B`
+    w: Array2<R>,
+    residual_norms: Array1<R>,
+    converged: bool,
+}
+
+impl<R: Scalar<Real = R> + Lapack + Float> BidiagonalRitz<R> {
+    /// SVD of `B` with the diagonal `alpha` and the superdiagonal `beta`,
+    /// where `A^H P = Q B^H + b q e_j^T`
+    fn new(alpha: &[R], beta: &[R], b: R, k: usize, tol: R) -> Self {
+        let j = alpha.len();
+        let size = std::cmp::min(k, j);
+        if j == 0 {
+            return BidiagonalRitz {
+                s: Array1::zeros(0),
+                u: Array2::zeros((0, 0)),
+                w: Array2::zeros((0, 0)),
+                residual_norms: Array1::zeros(0),
+                converged: false,
+            };
+        }
+        let mut bd = Array2::<R>::zeros((j, j));
+        for i in 0..j {
+            bd[(i, i)] = alpha[i];
+            if i + 1 < j {
+                bd[(i, i + 1)] = beta[i];
+            }
+        }
+        let (u, s, vt) = bd
+            .svd(true, true)
+            .expect("SVD of a small bidiagonal matrix");
+        let u = u.unwrap().slice(s![.., ..size]).to_owned();
+        let w = vt.unwrap().slice(s![..size, ..]).t().to_owned();
+        let residual_norms: Array1<R> = (0..size).map(|i| b * Float::abs(u[(j - 1, i)])).collect();
+        let scale = s.iter().fold(R::zero(), |x, s| Float::max(x, *s));
+        let converged = size == k && residual_norms.iter().all(|&r| r <= tol * scale);
+        BidiagonalRitz {
+            s: s.slice(s![..size]).to_owned(),
+            u,
+            w,
+            residual_norms,
+            converged,
+        }
+    }
+
+    /// Ritz vectors `P U` and `Q W`
+    fn into_result<A>(self, pm: &MGS<A>, qm: &MGS<A>) -> SvdsResult<A>
+    where
+        A: Scalar<Real = R> + Lapack,
+    {
+        let (m, n) = (pm.dim(), qm.dim());
+        let j = self.u.nrows();
+        let (u, v) = if j == 0 {
+            (Array2::zeros((m, 0)), Array2::zeros((n, 0)))
+        } else {
+            (
+                pm.get_q().dot(&self.u.mapv(A::from_real)),
+                qm.get_q()
+                    .slice(s![.., ..j])
+                    .dot(&self.w.mapv(A::from_real)),
+            )
+        };
+        SvdsResult {
+            singular_values: self.s,
+            u,
+            v,
+            residual_norms: self.residual_norms,
+            converged: self.converged,
+        }
+    }
+}
@@ -5,17 +5,67 @@ use ndarray::*;
 
 pub use lax::layout::MatrixLayout;
 
+/// Access to the memory of a matrix as LAPACK reads it
+///
+/// This is the layout detection used by the wrappers of this crate,
+/// and can be used to call LAPACK routines which are not wrapped yet.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0]];
+/// let (layout, slice) = a.as_lapack_slice().unwrap();
+/// assert_eq!(layout, MatrixLayout::C { row: 2, lda: 2 });
+/// assert_eq!(slice, &[1.0, 2.0, 3.0, 4.0]);
+/// ```
 pub trait AllocatedArray {
     type Elem;
+    /// Row-major (C) or column-major (F) layout of the matrix
+    ///
+    /// Returns `InvalidStride` error if neither axis is contiguous.
     fn layout(&self) -> Result<MatrixLayout>;
+    /// `layout` of a square matrix, or `NotSquare` error
     fn square_layout(&self) -> Result<MatrixLayout>;
     /// Returns Ok iff the matrix is square (without computing the layout).
     fn ensure_square(&self) -> Result<()>;
+    /// The memory of the matrix, or `MemoryNotCont` error if it is not contiguous
     fn as_allocated(&self) -> Result<&[Self::Elem]>;
+
+    /// The layout and the memory of the matrix at once, without copy
+    fn as_lapack_slice(&self) -> Result<(MatrixLayout, &[Self::Elem])> {
+        Ok((self.layout()?, self.as_allocated()?))
+    }
+
+    /// Transposed view of the matrix and its layout, without copy
+    ///
+    /// The transpose of a C layout matrix is an F layout matrix on the same memory
+    /// and vice versa, and thus only the axes and the layout flag are swapped.
+    /// Use `a.t().as_standard_layout().into_owned()` to obtain a transpose in C layout.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a: Array2<f64> = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    /// let (layout, at) = a.logical_transpose().unwrap();
+    /// assert_eq!(layout, MatrixLayout::F { col: 2, lda: 3 });
+    /// assert_eq!(at, a.t());
+    /// assert_eq!(at.as_ptr(), a.as_ptr());
+    /// ```
+    fn logical_transpose(&self) -> Result<(MatrixLayout, ArrayView2<'_, Self::Elem>)>;
 }
 
+/// Mutable access to the memory of a matrix as LAPACK reads it, see [AllocatedArray]
 pub trait AllocatedArrayMut: AllocatedArray {
+    /// The mutable memory of the matrix, or `MemoryNotCont` error if it is not contiguous
     fn as_allocated_mut(&mut self) -> Result<&mut [Self::Elem]>;
+
+    /// The layout and the mutable memory of the matrix at once, without copy
+    fn as_lapack_slice_mut(&mut self) -> Result<(MatrixLayout, &mut [Self::Elem])> {
+        let layout = self.layout()?;
+        Ok((layout, self.as_allocated_mut()?))
+    }
 }
 
 impl<A, S> AllocatedArray for ArrayBase<S, Ix2>
@@ -71,6 +121,10 @@ where
             .as_slice_memory_order()
             .ok_or_else(|| LinalgError::MemoryNotCont)?)
     }
+
+    fn logical_transpose(&self) -> Result<(MatrixLayout, ArrayView2<'_, A>)> {
+        Ok((self.layout()?.t(), self.t()))
+    }
 }
 
 impl<A, S> AllocatedArrayMut for ArrayBase<S, Ix2>
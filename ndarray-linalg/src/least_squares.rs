@@ -24,6 +24,9 @@
 //! The traits are implemented for value types `f32`, `f64`, `c32` and `c64`
 //! and vector or matrix right-hand-sides (`ArrayBase<S, Ix1>` or `ArrayBase<S, Ix2>`).
 //!
+//! If `A` is too large to be held in memory, `LeastSquaresAccumulator` takes
+//! `A` and `b` in blocks of rows and solves the normal equations instead.
+//!
 //! ## Example
 //! ```rust
 //! use approx::AbsDiffEq; // for abs_diff_eq
@@ -63,9 +66,16 @@
 use lax::*;
 use ndarray::*;
 
+use crate::cholesky::*;
 use crate::error::*;
+use crate::generate::conjugate;
 use crate::layout::*;
+use crate::norm::*;
+use crate::opnorm::*;
+use crate::svd::*;
+use crate::svddc::*;
 use crate::types::*;
+use num_traits::{Float, One, Zero};
 
 /// Result of a LeastSquares computation
 ///
@@ -83,13 +93,101 @@ pub struct LeastSquaresResult<E: Scalar, I: Dimension> {
     /// The solution vector or matrix `x` which is the best
     /// solution to `Ax = b`, i.e. minimizing the 2-norm `||b - Ax||`
     pub solution: Array<E, I>,
-    /// The rank of the matrix A in `Ax = b`
+    /// The rank of the matrix A in `Ax = b`, which is common to all the columns of a matrix `b`
     pub rank: i32,
     /// If n < m and rank(A) == n, the sum of squares
     /// If b is a (m x 1) vector, this is a 0-dimensional array (single value)
     /// If b is a (m x k) matrix, this is a (k x 1) column vector
+    /// whose `j`-th element is the residual sum of squares `||b_j - A x_j||^2` of the `j`-th column
     pub residual_sum_of_squares: Option<Array<E::Real, I::Smaller>>,
 }
+
+impl<E: Scalar> LeastSquaresResult<E, Ix1> {
+    /// The coefficient of determination `R^2 = 1 - SS_res / SS_tot` for the original RHS `b`
+    ///
+    /// `SS_tot` is the sum of squares of `b` around its mean,
+    /// i.e. the model is assumed to have an intercept.
+    /// Returns `None` if `residual_sum_of_squares` is not available.
+    ///
+    /// ```rust
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a = array![[1., 0.], [1., 1.], [1., 2.], [1., 3.]];
+    /// let b = array![1., 3., 4., 7.];
+    /// let result = a.least_squares(&b).unwrap();
+    /// let r2 = result.r_squared(&b).unwrap();
+    /// assert!((r2 - (1. - 0.7 / 18.75)).abs() < 1e-9);
+    /// ```
+    pub fn r_squared<S: Data<Elem = E>>(&self, b: &ArrayBase<S, Ix1>) -> Option<E::Real> {
+        let ss_res = self.residual_sum_of_squares.as_ref()?[()];
+        let mean = b.sum().div_real(E::real(b.len()));
+        let ss_tot: E::Real = b.iter().map(|&b| (b - mean).square()).sum();
+        Some(E::Real::one() - ss_res / ss_tot)
+    }
+
+    /// The adjusted `R^2 = 1 - (1 - R^2) (n - 1) / (n - n_params)` for `n = b.len()`
+    ///
+    /// `n_params` is the number of the parameters including the intercept,
+    /// i.e. the number of columns of `A`.
+    /// Returns `None` if `residual_sum_of_squares` is not available or `n <= n_params`.
+    pub fn adjusted_r_squared<S: Data<Elem = E>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+        n_params: usize,
+    ) -> Option<E::Real> {
+        let n = b.len();
+        if n <= n_params {
+            return None;
+        }
+        let r2 = self.r_squared(b)?;
+        Some(E::Real::one() - (E::Real::one() - r2) * E::real(n - 1) / E::real(n - n_params))
+    }
+}
+
+impl<E: Scalar + Lapack> LeastSquaresResult<E, Ix2> {
+    /// The residual norms `||b_j - A x_j||` of each column
+    ///
+    /// Returns `None` if `residual_sum_of_squares` is not available.
+    pub fn residual_norms(&self) -> Option<Array1<E::Real>> {
+        Some(self.residual_sum_of_squares.as_ref()?.mapv(Float::sqrt))
+    }
+
+    /// The relative residual norms `||b_j - A x_j|| / ||b_j||` of each column for the original RHS `b`
+    ///
+    /// The column of `b = 0` gives NaN.
+    /// Returns `None` if `residual_sum_of_squares` is not available.
+    ///
+    /// ```rust
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a = array![[1., 0.], [0., 1.], [0., 0.]];
+    /// let b = array![[1., 3.], [0., 0.], [1., 4.]];
+    /// let result = a.least_squares(&b).unwrap();
+    /// let rel = result.relative_residuals(&b).unwrap();
+    /// assert!(rel.abs_diff_eq(&array![0.5_f64.sqrt(), 0.8], 1e-9));
+    /// ```
+    pub fn relative_residuals<S: Data<Elem = E>>(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+    ) -> Option<Array1<E::Real>> {
+        let mut r = self.residual_norms()?;
+        for (r, b) in r.iter_mut().zip(b.axis_iter(Axis(1))) {
+            *r /= b.norm_l2();
+        }
+        Some(r)
+    }
+
+    /// The norms `||x_j||` of each column of the solution
+    pub fn solution_norms(&self) -> Array1<E::Real> {
+        self.solution
+            .axis_iter(Axis(1))
+            .map(|x| x.norm_l2())
+            .collect()
+    }
+}
+
 /// Solve least squares for immutable references
 pub trait LeastSquaresSvd<D, E, I>
 where
@@ -147,6 +245,84 @@ where
     ) -> Result<LeastSquaresResult<E, I>>;
 }
 
+/// Solve least squares for immutable references, keeping the RHS overwritten by LAPACK
+pub trait LeastSquaresSvdRaw<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs` as [LeastSquaresSvd::least_squares],
+    /// and return the whole overwritten copy of `rhs` with the result.
+    ///
+    /// The overwritten RHS has `max(m, n)` rows for `A` of `m x n`. The first `n` rows are the solution,
+    /// and if `m > n` and rank(A) == n, the remaining `m - n` rows are the components of the residual
+    /// in the basis of the QR decomposition of `A`, whose sum of squares is `residual_sum_of_squares`.
+    ///
+    /// ```rust
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a = array![[1., 0.], [0., 1.], [0., 0.]];
+    /// let b = array![1., 2., 3.];
+    /// let (result, raw) = a.least_squares_raw(&b).unwrap();
+    /// assert!(raw.slice(s![..2]).abs_diff_eq(&result.solution, 1e-12));
+    /// assert!((raw[2].abs() - 3.).abs() < 1e-12);
+    /// ```
+    fn least_squares_raw(
+        &self,
+        rhs: &ArrayBase<D, I>,
+    ) -> Result<(LeastSquaresResult<E, I>, Array<E, I>)>;
+}
+
+impl<E, D1, D2> LeastSquaresSvdRaw<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_raw(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+    ) -> Result<(LeastSquaresResult<E, Ix1>, Array1<E>)> {
+        let (m, n) = self.dim();
+        if m != rhs.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut a = self.to_owned();
+        let mut b = Array1::<E>::zeros(std::cmp::max(m, n));
+        b.slice_mut(s![..m]).assign(rhs);
+        let result = compute_least_squares_srhs(&mut a, &mut b)?;
+        Ok((result, b))
+    }
+}
+
+impl<E, D1, D2> LeastSquaresSvdRaw<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_raw(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+    ) -> Result<(LeastSquaresResult<E, Ix2>, Array2<E>)> {
+        let (m, n) = self.dim();
+        if m != rhs.nrows() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut a = self.to_owned();
+        let shape = (std::cmp::max(m, n), rhs.ncols());
+        let mut b = match a.layout()? {
+            MatrixLayout::C { .. } => Array2::<E>::zeros(shape),
+            MatrixLayout::F { .. } => Array2::<E>::zeros(shape.f()),
+        };
+        b.slice_mut(s![..m, ..]).assign(rhs);
+        let result = compute_least_squares_nrhs(&mut a, &mut b)?;
+        Ok((result, b))
+    }
+}
+
 /// Solve least squares for immutable references and a single
 /// column vector as a right-hand side.
 /// `E` is one of `f32`, `f64`, `c32`, `c64`. `D1`, `D2` can be any
@@ -265,6 +441,9 @@ where
     /// by calling `A.least_squares(rhs)`, where `rhs` is a
     /// vector. `A` and `rhs` are overwritten in the call.
     ///
+    /// A non-contiguous `rhs`, e.g. a column of a larger matrix, is
+    /// solved on a contiguous copy which is written back into `rhs`.
+    ///
     /// `A` and `rhs` must have the same layout, i.e. they must
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
@@ -282,6 +461,12 @@ where
             let mut new_rhs = Array1::<E>::zeros((n,));
             new_rhs.slice_mut(s![0..m]).assign(rhs);
             compute_least_squares_srhs(self, &mut new_rhs)
+        } else if rhs.as_slice_memory_order().is_none() {
+            // solve on a contiguous copy of a strided rhs, and write it back
+            let mut new_rhs = rhs.to_owned();
+            let result = compute_least_squares_srhs(self, &mut new_rhs)?;
+            rhs.assign(&new_rhs);
+            Ok(result)
         } else {
             compute_least_squares_srhs(self, rhs)
         }
@@ -300,12 +485,20 @@ where
     let LeastSquaresOutput::<E> {
         singular_values,
         rank,
-    } = E::least_squares(
-        a.layout()?,
-        a.as_allocated_mut()?,
-        rhs.as_slice_memory_order_mut()
-            .ok_or_else(|| LinalgError::MemoryNotCont)?,
-    )?;
+    } = if a.is_empty() {
+        // The minimum norm solution is zero, which is set in `rhs` for `m = 0`
+        LeastSquaresOutput {
+            singular_values: Vec::new(),
+            rank: 0,
+        }
+    } else {
+        E::least_squares(
+            a.layout()?,
+            a.as_allocated_mut()?,
+            rhs.as_slice_memory_order_mut()
+                .ok_or_else(|| LinalgError::MemoryNotCont)?,
+        )?
+    };
 
     let (m, n) = (a.shape()[0], a.shape()[1]);
     let solution = rhs.slice(s![0..n]).to_owned();
@@ -348,6 +541,9 @@ where
     /// by calling `A.least_squares(rhs)`, where `rhs` is a
     /// matrix. `A` and `rhs` are overwritten in the call.
     ///
+    /// A non-contiguous `rhs`, e.g. a block of columns of a larger matrix,
+    /// is solved on a contiguous copy which is written back into `rhs`.
+    ///
     /// `A` and `rhs` must have the same layout, i.e. they must
     /// be both either row- or column-major format, otherwise a
     /// `IncompatibleShape` error is raised.
@@ -369,6 +565,17 @@ where
             };
             new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
             compute_least_squares_nrhs(self, &mut new_rhs)
+        } else if rhs.layout().is_err() {
+            // solve on a contiguous copy of a strided rhs, and write it back
+            let k = rhs.shape()[1];
+            let mut new_rhs = match self.layout()? {
+                MatrixLayout::C { .. } => Array2::<E>::zeros((m, k)),
+                MatrixLayout::F { .. } => Array2::<E>::zeros((m, k).f()),
+            };
+            new_rhs.assign(rhs);
+            let result = compute_least_squares_nrhs(self, &mut new_rhs)?;
+            rhs.assign(&new_rhs);
+            Ok(result)
         } else {
             compute_least_squares_nrhs(self, rhs)
         }
@@ -384,17 +591,25 @@ where
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
 {
-    let a_layout = a.layout()?;
-    let rhs_layout = rhs.layout()?;
     let LeastSquaresOutput::<E> {
         singular_values,
         rank,
-    } = E::least_squares_nrhs(
-        a_layout,
-        a.as_allocated_mut()?,
-        rhs_layout,
-        rhs.as_allocated_mut()?,
-    )?;
+    } = if a.is_empty() {
+        // The minimum norm solution is zero, which is set in `rhs` for `m = 0`
+        LeastSquaresOutput {
+            singular_values: Vec::new(),
+            rank: 0,
+        }
+    } else {
+        let a_layout = a.layout()?;
+        let rhs_layout = rhs.layout()?;
+        E::least_squares_nrhs(
+            a_layout,
+            a.as_allocated_mut()?,
+            rhs_layout,
+            rhs.as_allocated_mut()?,
+        )?
+    };
 
     let solution: Array2<E> = rhs.slice(s![..a.shape()[1], ..]).to_owned();
     let singular_values = Array::from_shape_vec((singular_values.len(),), singular_values)?;
@@ -424,6 +639,420 @@ fn compute_residual_array1<E: Scalar, D: Data<Elem = E>>(
     )
 }
 
+/// Out-of-core least squares solver using the normal equations
+///
+/// Rows of `A` and `b` are fed in blocks by [`update`](#method.update),
+/// and only the sufficient statistics `A^H A` and `A^H b` are kept in memory.
+/// [`solve`](#method.solve) then solves the normal equations
+/// `A^H A x = A^H b` by the Cholesky decomposition.
+///
+/// Squaring the matrix squares its condition number, so prefer
+/// `LeastSquaresSvd` when `A` fits in memory or is ill-conditioned.
+///
+/// ```rust
+/// use approx::AbsDiffEq;
+/// use ndarray::{array, s};
+/// use ndarray_linalg::LeastSquaresAccumulator;
+///
+/// let a = array![[1., 1.], [1., 2.], [1., 3.], [1., 4.]];
+/// let b = array![6., 5., 7., 10.];
+/// let mut acc = LeastSquaresAccumulator::new(2);
+/// acc.update(&a.slice(s![..2, ..]), &b.slice(s![..2])).unwrap();
+/// acc.update(&a.slice(s![2.., ..]), &b.slice(s![2..])).unwrap();
+/// let x = acc.solve().unwrap();
+/// assert!(x.abs_diff_eq(&array![3.5, 1.4], 1e-9));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LeastSquaresAccumulator<E: Scalar> {
+    /// `A^H A` of the rows fed so far
+    gram: Array2<E>,
+    /// `A^H b` of the rows fed so far
+    rhs: Array1<E>,
+    /// Number of rows fed so far
+    rows: usize,
+}
+
+impl<E> LeastSquaresAccumulator<E>
+where
+    E: Scalar + Lapack,
+{
+    /// Create an empty accumulator for `A` with `n` columns
+    pub fn new(n: usize) -> Self {
+        LeastSquaresAccumulator {
+            gram: Array2::zeros((n, n)),
+            rhs: Array1::zeros(n),
+            rows: 0,
+        }
+    }
+
+    /// Feed a block of rows `a` of `A` and the corresponding entries `b` of the RHS
+    ///
+    /// `a` must have as many columns as the accumulator and as many rows as
+    /// `b` has entries, otherwise a `IncompatibleShape` error is raised.
+    pub fn update<D1, D2>(&mut self, a: &ArrayBase<D1, Ix2>, b: &ArrayBase<D2, Ix1>) -> Result<()>
+    where
+        D1: Data<Elem = E>,
+        D2: Data<Elem = E>,
+    {
+        if a.ncols() != self.rhs.len() || a.nrows() != b.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let ah: Array2<E> = conjugate(a);
+        self.gram += &ah.dot(a);
+        self.rhs += &ah.dot(b);
+        self.rows += a.nrows();
+        Ok(())
+    }
+
+    /// Number of rows fed so far
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The accumulated `A^H A`
+    pub fn gram(&self) -> &Array2<E> {
+        &self.gram
+    }
+
+    /// The accumulated `A^H b`
+    pub fn rhs(&self) -> &Array1<E> {
+        &self.rhs
+    }
+
+    /// Solve the normal equations `A^H A x = A^H b` for the rows fed so far
+    ///
+    /// This fails if `A^H A` is not positive definite,
+    /// e.g. when fewer linearly independent rows than columns have been fed.
+    pub fn solve(&self) -> Result<Array1<E>> {
+        self.gram.solvec(&self.rhs)
+    }
+}
+
+/// Result of [LeastSquaresSvdStats], the least squares solution with its statistics
+/// as the linear regression `b = A x + e` with the i.i.d. errors `e`
+#[derive(Debug, Clone)]
+pub struct LeastSquaresStats<E: Scalar> {
+    /// The solution `x` minimizing `||b - Ax||`
+    pub solution: Array1<E>,
+    /// The numerical rank of `A`
+    pub rank: usize,
+    /// The residual sum of squares `||b - Ax||^2`
+    pub residual_sum_of_squares: E::Real,
+    /// The unbiased estimate of the error variance `sigma^2 = RSS / (n - rank)` for `n` rows
+    pub residual_variance: E::Real,
+    /// The covariance matrix of the solution `sigma^2 (A^H A)^{-1} = sigma^2 V S^{-2} V^H`
+    pub covariance: Array2<E>,
+    /// The standard errors of the solution, i.e. the square roots of the diagonal of `covariance`
+    pub standard_errors: Array1<E::Real>,
+}
+
+/// Solve least squares with the covariance and the standard errors of the solution
+pub trait LeastSquaresSvdStats<D, E>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+{
+    /// Solve a least squares problem `Ax = rhs` by the thin SVD `A = U S V^H`
+    ///
+    /// The singular values less than `max(n, m) * eps * s_max` are truncated,
+    /// and `(A^H A)^{-1}` is replaced by the pseudo-inverse for a rank-deficient `A`.
+    /// Returns `IncompatibleShape` error if the length of `rhs` differs from the number of rows,
+    /// or the number of rows does not exceed the rank, i.e. no degree of freedom is left
+    /// to estimate `sigma^2`.
+    ///
+    /// ```rust
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // fit `y = c0 + c1 t`
+    /// let a = array![[1., 0.], [1., 1.], [1., 2.], [1., 3.]];
+    /// let b = array![1., 3., 4., 7.];
+    /// let stats = a.least_squares_stats(&b).unwrap();
+    /// assert!(stats.solution.abs_diff_eq(&array![0.9, 1.9], 1e-9));
+    /// assert!((stats.residual_variance - 0.35).abs() < 1e-9);
+    /// ```
+    fn least_squares_stats(&self, rhs: &ArrayBase<D, Ix1>) -> Result<LeastSquaresStats<E>>;
+}
+
+impl<E, D1, D2> LeastSquaresSvdStats<D2, E> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_stats(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<LeastSquaresStats<E>> {
+        let (n, m) = self.dim();
+        if rhs.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let (u, s, vt) = self.to_owned().svddc_into(UVTFlag::Some)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let smax = s.iter().cloned().fold(E::Real::zero(), Float::max);
+        let threshold = E::real(std::cmp::max(n, m)) * E::Real::epsilon() * smax;
+        let rank = s.iter().take_while(|&&s| s > threshold).count();
+        if n <= rank {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+
+        // x = V S^{-1} U^H b over the numerical rank
+        let uh: Array2<E> = conjugate(&u.slice(s![.., ..rank]));
+        let mut c = uh.dot(rhs);
+        azip!((c in &mut c, &s in &s.slice(s![..rank])) *c = c.div_real(s));
+        let v: Array2<E> = conjugate(&vt.slice(s![..rank, ..]));
+        let solution = v.dot(&c);
+
+        let residual = rhs - &self.dot(&solution);
+        let residual_sum_of_squares: E::Real = residual.iter().map(|r| r.square()).sum();
+        let residual_variance = residual_sum_of_squares / E::real(n - rank);
+
+        // covariance = sigma^2 V S^{-2} V^H
+        let mut vs = v.clone();
+        for (mut col, &s) in vs.axis_iter_mut(Axis(1)).zip(s.iter()) {
+            col.mapv_inplace(|x| x.mul_real(residual_variance / (s * s)));
+        }
+        let covariance = vs.dot(&vt.slice(s![..rank, ..]));
+        let standard_errors = covariance.diag().mapv(|c| Float::sqrt(c.re()));
+
+        Ok(LeastSquaresStats {
+            solution,
+            rank,
+            residual_sum_of_squares,
+            residual_variance,
+            covariance,
+            standard_errors,
+        })
+    }
+}
+
+/// Solve weighted least squares `min sum_i w_i |b_i - (Ax)_i|^2`
+pub trait LeastSquaresWeighted<D, E>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+{
+    /// Solve by scaling the rows of `A` and `rhs` by `sqrt(w_i)` and calling `least_squares`
+    ///
+    /// The residual sum of squares in the result is also weighted.
+    /// Returns `IncompatibleShape` error if the lengths of `rhs` or `weights` differ from
+    /// the number of rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is negative.
+    fn least_squares_weighted<Dw: Data<Elem = E::Real>>(
+        &self,
+        rhs: &ArrayBase<D, Ix1>,
+        weights: &ArrayBase<Dw, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>>;
+}
+
+impl<E, D1, D2> LeastSquaresWeighted<D2, E> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_weighted<Dw: Data<Elem = E::Real>>(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+        weights: &ArrayBase<Dw, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let n = self.nrows();
+        if rhs.len() != n || weights.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        assert!(
+            weights.iter().all(|&w| w >= E::Real::zero()),
+            "weights of least squares must be non-negative"
+        );
+        let sqrt_w = weights.mapv(Float::sqrt);
+        let mut a = self.to_owned();
+        for (mut row, &w) in a.axis_iter_mut(Axis(0)).zip(sqrt_w.iter()) {
+            row.mapv_inplace(|x| x.mul_real(w));
+        }
+        let b = Zip::from(rhs)
+            .and(&sqrt_w)
+            .map_collect(|&b, &w| b.mul_real(w));
+        a.least_squares_into(b)
+    }
+}
+
+/// Solve total least squares (errors-in-variables) `min ||[E | r]||_F` s.t. `(A + E) x = b + r`
+pub trait TotalLeastSquares<D, E>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+{
+    /// Solve by the SVD of the augmented matrix `[A | rhs]`,
+    /// and returns the solution with the smallest singular value, i.e. the TLS residual `||[E | r]||_F`
+    ///
+    /// If the smallest singular value is repeated, the minimum norm solution is returned
+    /// from the right singular vectors of the repeated values.
+    /// Returns `NoSolution` error if these singular vectors have no component of `rhs`,
+    /// and `IncompatibleShape` error if the length of `rhs` differs from the number of rows.
+    ///
+    /// ```rust
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a = array![[1.0], [2.0], [3.0]];
+    /// let b = array![2.0, 4.0, 6.0];
+    /// let (x, s) = a.total_least_squares(&b).unwrap();
+    /// assert!((x[0] - 2.0).abs() < 1e-9);
+    /// assert!(s < 1e-9);
+    /// ```
+    fn total_least_squares(&self, rhs: &ArrayBase<D, Ix1>) -> Result<(Array1<E>, E::Real)>;
+}
+
+impl<E, D1, D2> TotalLeastSquares<D2, E> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn total_least_squares(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<(Array1<E>, E::Real)> {
+        let (n, m) = self.dim();
+        if rhs.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut c = Array2::zeros((n, m + 1));
+        c.slice_mut(s![.., ..m]).assign(self);
+        c.column_mut(m).assign(rhs);
+        let (_, s, vt) = c.svd(false, true)?;
+        let vt = vt.unwrap();
+
+        // singular values are zero beyond the number of rows
+        let sigma = |i: usize| if i < s.len() { s[i] } else { E::Real::zero() };
+        let smin = sigma(m);
+        let smax = sigma(0);
+        let tol = E::real(std::cmp::max(n, m + 1)) * E::Real::epsilon() * smax;
+        let mut k = m;
+        while k > 0 && sigma(k - 1) - smin <= tol {
+            k -= 1;
+        }
+
+        // The right singular vectors of the smallest singular value are the rows `k..`
+        // of `vt` conjugated. Their combination `z` with the largest last component
+        // `z[m]` gives the minimum norm solution `x = -z[..m] / z[m]`.
+        let v2: Array2<E> = conjugate(&vt.slice(s![k.., ..]));
+        let w = v2.row(m).mapv(|x| x.conj());
+        let z = v2.dot(&w);
+        let zm = z[m];
+        if zm.abs() <= E::Real::epsilon() {
+            return Err(LinalgError::NoSolution);
+        }
+        let x = z.slice(s![..m]).mapv(|z| -z / zm);
+        Ok((x, smin))
+    }
+}
+
+/// Solve non-negative least squares `min ||b - Ax||` s.t. `x >= 0` for a real matrix
+pub trait NonNegativeLeastSquares<D, E>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+{
+    /// Solve by the Lawson-Hanson active set method, where the unconstrained
+    /// sub-problems on the passive set are solved by `least_squares`
+    ///
+    /// Returns the solution and the residual norm `||b - Ax||`.
+    /// Returns `NotConverged` error if the active set does not settle in `3 * m` iterations
+    /// for `m` columns, and `IncompatibleShape` error if the length of `rhs` differs from
+    /// the number of rows.
+    ///
+    /// ```rust
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a = array![[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+    /// let b = array![2.0, -1.0, 1.0];
+    /// let (x, _) = a.nnls(&b).unwrap();
+    /// assert!(x.abs_diff_eq(&array![1.5, 0.0], 1e-9));
+    /// ```
+    fn nnls(&self, rhs: &ArrayBase<D, Ix1>) -> Result<(Array1<E>, E)>;
+}
+
+impl<E, D1, D2> NonNegativeLeastSquares<D2, E> for ArrayBase<D1, Ix2>
+where
+    E: Scalar<Real = E> + Lapack + Float,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn nnls(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<(Array1<E>, E)> {
+        let (n, m) = self.dim();
+        if rhs.len() != n {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let tol = E::real(10 * std::cmp::max(n, m)) * E::epsilon() * self.opnorm_one()?;
+        let max_iter = 3 * m;
+
+        let mut x = Array1::<E>::zeros(m);
+        let mut passive = vec![false; m];
+        // solve the unconstrained problem on the passive set
+        let solve_passive = |passive: &[bool]| -> Result<Array1<E>> {
+            let idx: Vec<usize> = (0..m).filter(|&j| passive[j]).collect();
+            let mut z = Array1::zeros(m);
+            if idx.is_empty() {
+                return Ok(z);
+            }
+            let sol = self.select(Axis(1), &idx).least_squares(rhs)?.solution;
+            for (&j, &s) in idx.iter().zip(sol.iter()) {
+                z[j] = s;
+            }
+            Ok(z)
+        };
+
+        let mut iter = 0;
+        loop {
+            // gradient of `-||b - Ax||^2 / 2`
+            let w = self.t().dot(&(rhs - &self.dot(&x)));
+            let next = (0..m)
+                .filter(|&j| !passive[j] && w[j] > tol)
+                .max_by(|&i, &j| w[i].partial_cmp(&w[j]).unwrap());
+            let j = match next {
+                Some(j) => j,
+                None => break,
+            };
+            passive[j] = true;
+
+            loop {
+                iter += 1;
+                if iter > max_iter {
+                    return Err(LinalgError::NotConverged {
+                        iterations: max_iter,
+                    });
+                }
+                let z = solve_passive(&passive)?;
+                if (0..m).all(|j| !passive[j] || z[j] > E::zero()) {
+                    x = z;
+                    break;
+                }
+                // move toward `z` until a passive variable hits zero
+                let alpha = (0..m)
+                    .filter(|&j| passive[j] && z[j] <= E::zero())
+                    .map(|j| {
+                        // `x[j] >= 0 >= z[j]`, and the step is zero if both are zero
+                        if x[j] > z[j] {
+                            x[j] / (x[j] - z[j])
+                        } else {
+                            E::zero()
+                        }
+                    })
+                    .fold(E::infinity(), Float::min);
+                azip!((x in &mut x, &z in &z) *x += alpha * (z - *x));
+                for j in 0..m {
+                    if passive[j] && x[j] <= tol {
+                        passive[j] = false;
+                        x[j] = E::zero();
+                    }
+                }
+            }
+        }
+        let residual = (rhs - &self.dot(&x)).norm_l2();
+        Ok((x, residual))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{error::LinalgError, *};
@@ -9,6 +9,7 @@
 //!     - [Cholesky/LU decomposition](cholesky/index.html)
 //!     - [Eigenvalue decomposition](eig/index.html)
 //!     - [Eigenvalue decomposition for Hermite matrices](eigh/index.html)
+//!     - [Generalized Schur (QZ) decomposition](qz/index.html)
 //!     - [**S**ingular **V**alue **D**ecomposition](svd/index.html)
 //! - Solution of linear systems:
 //!    - [General matrices](solve/index.html)
@@ -16,6 +17,9 @@
 //!    - [Hermitian/real symmetric matrices](solveh/index.html)
 //!    - [Tridiagonal matrices](tridiagonal/index.html)
 //! - [Inverse matrix computation](solve/trait.Inverse.html)
+//! - [Determinant](solve/trait.Determinant.html)
+//! - [Matrix multiplication with accumulation](blas/index.html)
+//! - [Givens rotations](givens/index.html) and [Householder reflectors](reflector/index.html)
 //!
 //! Naming Convention
 //! -----------------------
@@ -35,8 +39,11 @@
 //!  Utilities
 //!  -----------
 //!  - [Assertions for array](index.html#macros)
+//!  - [Assertions for downstream tests](testing/index.html) (`testing` feature)
 //!  - [Random matrix generators](generate/index.html)
+//!  - [Conversions from/to other crates](interop/index.html)
 //!  - [Scalar trait](types/trait.Scalar.html)
+//!  - [Tuning parameters of LAPACK](tuning/index.html)
 
 #![allow(
     clippy::module_inception,
@@ -49,14 +56,22 @@
 extern crate ndarray;
 
 pub mod assert;
+pub mod auto_solve;
+pub mod balance;
+pub mod blas;
 pub mod cholesky;
+pub mod control;
 pub mod convert;
+pub mod covariance;
 pub mod diagonal;
 pub mod eig;
 pub mod eigh;
 pub mod error;
+pub mod expm;
 pub mod generate;
+pub mod givens;
 pub mod inner;
+pub mod interop;
 pub mod krylov;
 pub mod layout;
 pub mod least_squares;
@@ -64,23 +79,41 @@ pub mod lobpcg;
 pub mod norm;
 pub mod operator;
 pub mod opnorm;
+pub mod pca;
+pub mod permutation;
+pub mod preconditioner;
+pub mod properties;
 pub mod qr;
+pub mod qz;
+pub mod reflector;
+pub mod signm;
 pub mod solve;
 pub mod solveh;
+pub mod stochastic;
 pub mod svd;
 pub mod svddc;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod trace;
 pub mod triangular;
 pub mod tridiagonal;
+pub mod tuning;
 pub mod types;
 
 pub use crate::assert::*;
+pub use crate::auto_solve::*;
+pub use crate::balance::*;
+pub use crate::blas::*;
 pub use crate::cholesky::*;
+pub use crate::control::*;
 pub use crate::convert::*;
+pub use crate::covariance::*;
 pub use crate::diagonal::*;
 pub use crate::eig::*;
 pub use crate::eigh::*;
+pub use crate::expm::*;
 pub use crate::generate::*;
+pub use crate::givens::*;
 pub use crate::inner::*;
 pub use crate::layout::*;
 pub use crate::least_squares::*;
@@ -88,12 +121,21 @@ pub use crate::lobpcg::{TruncatedEig, TruncatedOrder, TruncatedSvd};
 pub use crate::norm::*;
 pub use crate::operator::*;
 pub use crate::opnorm::*;
+pub use crate::pca::*;
+pub use crate::permutation::*;
+pub use crate::preconditioner::*;
+pub use crate::properties::*;
 pub use crate::qr::*;
+pub use crate::qz::*;
+pub use crate::reflector::*;
+pub use crate::signm::*;
 pub use crate::solve::*;
 pub use crate::solveh::*;
+pub use crate::stochastic::*;
 pub use crate::svd::*;
 pub use crate::svddc::*;
 pub use crate::trace::*;
 pub use crate::triangular::*;
 pub use crate::tridiagonal::*;
+pub use crate::tuning::*;
 pub use crate::types::*;
@@ -339,7 +339,10 @@ pub fn lobpcg<
         let result = p_ap
             .as_ref()
             .ok_or(LinalgError::Lapack(
-                lax::error::Error::LapackComputationalFailure { return_code: 1 },
+                lax::error::Error::LapackComputationalFailure {
+                    routine: "sygv",
+                    return_code: 1,
+                },
             ))
             .and_then(|(active_p, active_ap)| {
                 let xap = x.t().dot(active_ap);
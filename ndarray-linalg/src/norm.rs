@@ -20,6 +20,13 @@ pub trait Norm {
     fn norm_l2(&self) -> Self::Output;
     /// maximum norm
     fn norm_max(&self) -> Self::Output;
+    /// L-p norm `(sum |x|^p)^(1/p)` for positive `p`, which may be fractional or infinity
+    ///
+    /// Matrices and higher dimensional arrays are treated as vectors,
+    /// i.e. this is the entrywise norm. For induced norms of matrices, see `OperationNorm::opnorm_p`.
+    ///
+    /// Panics if `p` is not positive.
+    fn norm_p(&self, p: f64) -> Self::Output;
 }
 
 impl<A, S, D> Norm for ArrayBase<S, D>
@@ -45,6 +52,23 @@ where
             }
         })
     }
+    fn norm_p(&self, p: f64) -> Self::Output {
+        assert!(p > 0.0, "p of L-p norm must be positive: {}", p);
+        if p == 1.0 {
+            return self.norm_l1();
+        }
+        if p == 2.0 {
+            return self.norm_l2();
+        }
+        if p.is_infinite() {
+            return self.norm_max();
+        }
+        let p = A::real(p);
+        self.iter()
+            .map(|x| x.abs().powf(p))
+            .sum::<A::Real>()
+            .powf(A::real(1.0) / p)
+    }
 }
 
 pub enum NormalizeAxis {
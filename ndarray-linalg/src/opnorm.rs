@@ -5,6 +5,7 @@ use ndarray::*;
 
 use crate::error::*;
 use crate::layout::*;
+use crate::svd::*;
 use crate::types::*;
 
 pub use lax::NormType;
@@ -32,6 +33,27 @@ pub trait OperationNorm {
     fn opnorm_fro(&self) -> Result<Self::Output> {
         self.opnorm(NormType::Frobenius)
     }
+
+    /// the operator norm induced by the vector L-p norm
+    ///
+    /// Only `p = 1` and `p = inf` are supported by default,
+    /// and dense matrices also support `p = 2` (the largest singular value).
+    /// Returns `UnsupportedNorm` error for other `p`.
+    /// For the entrywise L-p norm, see `Norm::norm_p`.
+    fn opnorm_p(&self, p: f64) -> Result<Self::Output> {
+        opnorm_one_or_inf(self, p)
+    }
+}
+
+/// The default of [OperationNorm::opnorm_p] supporting only `p = 1` and `p = inf`
+fn opnorm_one_or_inf<T: OperationNorm + ?Sized>(a: &T, p: f64) -> Result<T::Output> {
+    if p == 1.0 {
+        a.opnorm_one()
+    } else if p == f64::INFINITY {
+        a.opnorm_inf()
+    } else {
+        Err(LinalgError::UnsupportedNorm { p })
+    }
 }
 
 impl<A, S> OperationNorm for ArrayBase<S, Ix2>
@@ -46,6 +68,18 @@ where
         let a = self.as_allocated()?;
         Ok(A::opnorm(t, l, a))
     }
+
+    fn opnorm_p(&self, p: f64) -> Result<Self::Output> {
+        if p == 2.0 {
+            if self.is_empty() {
+                return Ok(A::real(0.0));
+            }
+            let (_, s, _) = self.svd(false, false)?;
+            Ok(s[0])
+        } else {
+            opnorm_one_or_inf(self, p)
+        }
+    }
 }
 
 impl<A> OperationNorm for Tridiagonal<A>
@@ -0,0 +1,156 @@
+//! Incremental principal component analysis
+//!
+//! - [Principal component analysis - Wikipedia](https://en.wikipedia.org/wiki/Principal_component_analysis)
+
+use ndarray::*;
+
+use crate::eigh::*;
+use crate::error::*;
+use crate::inner::outer;
+use crate::least_squares::LeastSquaresAccumulator;
+use crate::types::*;
+use crate::UPLO;
+
+/// Principal component analysis of data fed in batches
+///
+/// Samples are the rows of the batches fed by [`update`](#method.update).
+/// Only the mean and the covariance matrix are kept in memory
+/// through a [LeastSquaresAccumulator], and thus the memory usage does not depend on the number of samples.
+/// The samples are shifted by the mean of the first batch before accumulated
+/// to avoid the cancellation in the covariance of data far from the origin.
+///
+/// The principal components are the eigenvectors of the covariance matrix,
+/// which is diagonalized by `eigh` when [`components`](#method.components) or
+/// [`explained_variance`](#method.explained_variance) is called.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // samples on the line `y = 2x`
+/// let x = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0], [4.0, 8.0]];
+/// let mut pca = IncrementalPca::new(2, 1);
+/// pca.update(&x.slice(s![..2, ..])).unwrap();
+/// pca.update(&x.slice(s![2.., ..])).unwrap();
+/// assert!(pca.mean().abs_diff_eq(&array![2.5, 5.0], 1e-9));
+/// let v = pca.components().unwrap();
+/// let v = v.column(0);
+/// assert!((v[1] / v[0] - 2.0).abs() < 1e-9);
+/// assert!((pca.explained_variance().unwrap()[0] - 25.0 / 3.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IncrementalPca<A: Scalar> {
+    /// Number of principal components to keep
+    k: usize,
+    /// Offset subtracted from the samples, the mean of the first batch
+    shift: Option<Array1<A>>,
+    /// `X^H X` and `X^H 1` of the shifted samples `X`
+    acc: LeastSquaresAccumulator<A>,
+}
+
+impl<A> IncrementalPca<A>
+where
+    A: Scalar + Lapack,
+{
+    /// Create an empty analysis keeping `k` principal components of the samples with `n_features` features
+    ///
+    /// Panics if `k` is larger than `n_features`.
+    pub fn new(n_features: usize, k: usize) -> Self {
+        assert!(
+            k <= n_features,
+            "number of components must not exceed the number of features"
+        );
+        IncrementalPca {
+            k,
+            shift: None,
+            acc: LeastSquaresAccumulator::new(n_features),
+        }
+    }
+
+    /// Feed a batch of samples as rows
+    ///
+    /// Returns `IncompatibleShape` error if the number of columns differs from the number of features.
+    pub fn update<S>(&mut self, batch: &ArrayBase<S, Ix2>) -> Result<()>
+    where
+        S: Data<Elem = A>,
+    {
+        if batch.ncols() != self.acc.rhs().len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        if batch.nrows() == 0 {
+            return Ok(());
+        }
+        let shift = self
+            .shift
+            .get_or_insert_with(|| batch.mean_axis(Axis(0)).unwrap());
+        let x = batch - &shift.view().insert_axis(Axis(0));
+        let ones = Array1::from_elem(x.nrows(), A::one());
+        self.acc.update(&x, &ones)
+    }
+
+    /// Number of samples fed so far
+    pub fn n_samples(&self) -> usize {
+        self.acc.rows()
+    }
+
+    /// Mean of the samples fed so far, zero if no samples are fed
+    pub fn mean(&self) -> Array1<A> {
+        let n = self.acc.rhs().len();
+        match &self.shift {
+            Some(shift) => {
+                let rows = A::from_real(A::real(self.n_samples() as f64));
+                Zip::from(shift)
+                    .and(self.acc.rhs())
+                    .map_collect(|&s, &r| s + r.conj() / rows)
+            }
+            None => Array1::zeros(n),
+        }
+    }
+
+    /// Unbiased covariance matrix `(X - μ)^H (X - μ) / (N - 1)` of the samples `X` as rows
+    ///
+    /// Returns `IncompatibleShape` error if less than two samples are fed.
+    pub fn covariance(&self) -> Result<Array2<A>> {
+        let rows = self.n_samples();
+        if rows < 2 {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let d = self.acc.rhs();
+        let (rows, dof) = (A::real(rows as f64), A::real(rows as f64 - 1.0));
+        let ddh = outer(d, d);
+        Ok(Zip::from(self.acc.gram())
+            .and(&ddh)
+            .map_collect(|&g, &dd| (g - dd.div_real(rows)).div_real(dof)))
+    }
+
+    /// `k` principal axes as columns, in descending order of the explained variance
+    pub fn components(&self) -> Result<Array2<A>> {
+        Ok(self.eigenpairs()?.1)
+    }
+
+    /// Variance of the samples along each principal axis, i.e. the `k` largest eigenvalues of the covariance matrix
+    pub fn explained_variance(&self) -> Result<Array1<A::Real>> {
+        Ok(self.eigenpairs()?.0)
+    }
+
+    /// Project the samples `x` as rows, centered by the mean, onto the principal axes
+    pub fn transform<S>(&self, x: &ArrayBase<S, Ix2>) -> Result<Array2<A>>
+    where
+        S: Data<Elem = A>,
+    {
+        if x.ncols() != self.acc.rhs().len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let v = self.components()?;
+        let x = x - &self.mean().insert_axis(Axis(0));
+        Ok(x.dot(&v))
+    }
+
+    fn eigenpairs(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let (w, v) = self.covariance()?.eigh_into(UPLO::Upper)?.sort_descending();
+        Ok((
+            w.slice(s![..self.k]).to_owned(),
+            v.slice(s![.., ..self.k]).to_owned(),
+        ))
+    }
+}
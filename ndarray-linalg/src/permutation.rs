@@ -0,0 +1,146 @@
+//! Permutation of rows and columns
+//!
+//! A permutation is represented by the indices `perm` of `0..n`, where the `i`-th row (column)
+//! of the permuted matrix is the `perm[i]`-th row (column) of the original matrix,
+//! i.e. the same as `select(Axis(0), &perm)`.
+//! LAPACK returns the pivots of e.g. LU decomposition as row interchanges instead,
+//! which are converted by [pivot_to_permutation].
+
+use ndarray::*;
+
+use crate::error::*;
+
+/// Permute rows or columns of a matrix in place
+///
+/// The permutation is validated, and `IncompatibleShape` error is returned if its length
+/// differs from the number of rows (columns), or `InvalidPermutation` error if it is not a bijection.
+/// Apply the permutation to a vector `v` as rows of `v.view_mut().insert_axis(Axis(1))`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let mut a = array![[1, 2], [3, 4], [5, 6]];
+/// let perm = array![2, 0, 1];
+/// a.permute_rows(&perm).unwrap();
+/// assert_eq!(a, array![[5, 6], [1, 2], [3, 4]]);
+/// a.inverse_permute_rows(&perm).unwrap();
+/// assert_eq!(a, array![[1, 2], [3, 4], [5, 6]]);
+/// ```
+pub trait Permute {
+    /// Replace the `i`-th row by the `perm[i]`-th row
+    fn permute_rows(&mut self, perm: &Array1<usize>) -> Result<()>;
+    /// Replace the `perm[i]`-th row by the `i`-th row, i.e. undo [`permute_rows`](#tymethod.permute_rows)
+    fn inverse_permute_rows(&mut self, perm: &Array1<usize>) -> Result<()>;
+    /// Replace the `i`-th column by the `perm[i]`-th column
+    fn permute_cols(&mut self, perm: &Array1<usize>) -> Result<()>;
+    /// Replace the `perm[i]`-th column by the `i`-th column, i.e. undo [`permute_cols`](#tymethod.permute_cols)
+    fn inverse_permute_cols(&mut self, perm: &Array1<usize>) -> Result<()>;
+}
+
+impl<A, S> Permute for ArrayBase<S, Ix2>
+where
+    S: DataMut<Elem = A>,
+{
+    fn permute_rows(&mut self, perm: &Array1<usize>) -> Result<()> {
+        permute_axis(self, Axis(0), perm, false)
+    }
+
+    fn inverse_permute_rows(&mut self, perm: &Array1<usize>) -> Result<()> {
+        permute_axis(self, Axis(0), perm, true)
+    }
+
+    fn permute_cols(&mut self, perm: &Array1<usize>) -> Result<()> {
+        permute_axis(self, Axis(1), perm, false)
+    }
+
+    fn inverse_permute_cols(&mut self, perm: &Array1<usize>) -> Result<()> {
+        permute_axis(self, Axis(1), perm, true)
+    }
+}
+
+/// Inverse of the permutation `perm`
+///
+/// Returns `InvalidPermutation` error if `perm` is not a permutation of `0..perm.len()`.
+pub fn inverse_permutation(perm: &Array1<usize>) -> Result<Array1<usize>> {
+    let n = perm.len();
+    let mut inv = Array1::from_elem(n, n);
+    for (i, &p) in perm.iter().enumerate() {
+        if p >= n || inv[p] != n {
+            return Err(LinalgError::InvalidPermutation { n });
+        }
+        inv[p] = i;
+    }
+    Ok(inv)
+}
+
+/// Convert the pivots of LAPACK into a permutation
+///
+/// LAPACK returns the 1-based row interchanges `ipiv`, where the `i`-th row
+/// has been interchanged with the `ipiv[i]`-th row in the order of `i`.
+/// For the LU decomposition `A = P L U`, the rows of `A` permuted by the result are `L U`.
+/// `n` is the number of rows, which may be larger than the number of pivots.
+///
+/// Returns `InvalidPermutation` error if a pivot is not in `1..=n`.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // swap 0 <-> 2, then 1 <-> 2
+/// let perm = pivot_to_permutation(&[3, 3, 3], 3).unwrap();
+/// assert_eq!(perm, array![2, 0, 1]);
+/// ```
+pub fn pivot_to_permutation(ipiv: &[i32], n: usize) -> Result<Array1<usize>> {
+    let mut perm: Array1<usize> = (0..n).collect();
+    for (i, &p) in ipiv.iter().enumerate() {
+        if p < 1 || p as usize > n || i >= n {
+            return Err(LinalgError::InvalidPermutation { n });
+        }
+        perm.swap(i, p as usize - 1);
+    }
+    Ok(perm)
+}
+
+fn permute_axis<A, S>(
+    a: &mut ArrayBase<S, Ix2>,
+    axis: Axis,
+    perm: &Array1<usize>,
+    inverse: bool,
+) -> Result<()>
+where
+    S: DataMut<Elem = A>,
+{
+    if perm.len() != a.len_of(axis) {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let inv = inverse_permutation(perm)?;
+    // `permute_rows` with the inverse is `inverse_permute_rows`
+    let perm = if inverse { &inv } else { perm };
+    let mut done = vec![false; perm.len()];
+    // Follow each cycle `i -> perm[i] -> ...` by swaps
+    for i in 0..perm.len() {
+        let mut j = i;
+        while !done[j] {
+            done[j] = true;
+            let k = perm[j];
+            if k == i {
+                break;
+            }
+            swap_lanes(a, axis, j, k);
+            j = k;
+        }
+    }
+    Ok(())
+}
+
+fn swap_lanes<A, S>(a: &mut ArrayBase<S, Ix2>, axis: Axis, i: usize, j: usize)
+where
+    S: DataMut<Elem = A>,
+{
+    let (lo, hi) = if i < j { (i, j) } else { (j, i) };
+    let (mut first, mut second) = a.view_mut().split_at(axis, hi);
+    Zip::from(first.index_axis_mut(axis, lo))
+        .and(second.index_axis_mut(axis, 0))
+        .for_each(std::mem::swap);
+}
@@ -0,0 +1,158 @@
+//! Preconditioners for the iterative solvers
+//!
+//! Each constructor returns a closure `m_inv(r) = M^{-1} r` for a matrix `M` approximating `A`,
+//! which is passed to e.g. [cg_preconditioned](crate::krylov::cg_preconditioned)
+//! or [gmres_preconditioned](crate::krylov::gmres_preconditioned).
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Jacobi (diagonal) preconditioner `M = diag(A)`
+///
+/// The zero diagonal elements are replaced by one, i.e. such components are not preconditioned.
+/// Returns `NotSquare` error if `a` is not square.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// let a: Array2<f64> = array![[4., 1.], [1., 100.]];
+/// let b = array![1., 2.];
+/// let m_inv = jacobi_preconditioner(&a).unwrap();
+/// let result = cg_preconditioned(a.view(), &b, m_inv, 1e-12, 10);
+/// assert!(result.converged);
+/// assert!(a.dot(&result.solution).abs_diff_eq(&b, 1e-10));
+/// ```
+pub fn jacobi_preconditioner<A, S>(
+    a: &ArrayBase<S, Ix2>,
+) -> Result<impl Fn(&Array1<A>) -> Array1<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    let d_inv = a
+        .diag()
+        .mapv(|d| if d.is_zero() { A::one() } else { A::one() / d });
+    Ok(move |r: &Array1<A>| r * &d_inv)
+}
+
+/// Incomplete Cholesky factorization with zero fill-in, IC(0)
+///
+/// This computes the lower triangular `L` such that `L L^H` agrees with `A` on the sparsity pattern,
+/// by the Cholesky decomposition dropping the fill-in outside the pattern.
+/// The pattern is the non-zero elements of `a`, or the `true` elements of `mask` if given,
+/// and only the lower triangle of `a` and `mask` is referenced.
+/// If the pattern is full, this is the exact Cholesky decomposition.
+///
+/// Returns `NotSquare` error if `a` is not square, `IncompatibleShape` error if the shape of `mask` differs,
+/// and `NotPositiveDefinite` error if a non-positive pivot appears, which may happen
+/// even for a positive definite `A`, e.g. if `A` is not diagonally dominant.
+pub fn ichol0<A, S>(a: &ArrayBase<S, Ix2>, mask: Option<&Array2<bool>>) -> Result<Array2<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    let n = a.nrows();
+    if let Some(mask) = mask {
+        if mask.dim() != a.dim() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+    }
+    let pattern = |i: usize, j: usize| match mask {
+        Some(mask) => mask[(i, j)] || i == j,
+        None => !a[(i, j)].is_zero() || i == j,
+    };
+    let mut l = Array2::<A>::zeros((n, n));
+    for j in 0..n {
+        for i in j..n {
+            if pattern(i, j) {
+                l[(i, j)] = a[(i, j)];
+            }
+        }
+    }
+    for k in 0..n {
+        let pivot = l[(k, k)].re();
+        if pivot.is_nan() || pivot <= A::Real::zero() || !l[(k, k)].im().is_zero() {
+            return Err(LinalgError::NotPositiveDefinite);
+        }
+        let d = Float::sqrt(pivot);
+        l[(k, k)] = A::from_real(d);
+        for i in (k + 1)..n {
+            l[(i, k)] = l[(i, k)].div_real(d);
+        }
+        for j in (k + 1)..n {
+            let ljk = l[(j, k)].conj();
+            if ljk.is_zero() {
+                continue;
+            }
+            for i in j..n {
+                if pattern(i, j) {
+                    let lik = l[(i, k)];
+                    l[(i, j)] -= lik * ljk;
+                }
+            }
+        }
+    }
+    Ok(l)
+}
+
+/// IC(0) preconditioner `M = L L^H` by [ichol0]
+///
+/// The closure applies `M^{-1} = L^{-H} L^{-1}` by the forward and back substitutions.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::{krylov::*, *};
+///
+/// // 1D Laplacian, whose IC(0) is exact since the Cholesky factor has no fill-in
+/// let n = 5;
+/// let a = Array2::from_shape_fn((n, n), |(i, j)| match (i as i32 - j as i32).abs() {
+///     0 => 2.,
+///     1 => -1.,
+///     _ => 0.,
+/// });
+/// let b = Array1::ones(n);
+/// let m_inv = ichol0_preconditioner(&a, None).unwrap();
+/// let result = cg_preconditioned(a.view(), &b, m_inv, 1e-12, 10);
+/// assert!(result.converged);
+/// assert_eq!(result.residual_norms.len(), 2);
+/// ```
+pub fn ichol0_preconditioner<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    mask: Option<&Array2<bool>>,
+) -> Result<impl Fn(&Array1<A>) -> Array1<A>>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let l = ichol0(a, mask)?;
+    Ok(move |r: &Array1<A>| {
+        let n = r.len();
+        // L y = r
+        let mut y = r.clone();
+        for i in 0..n {
+            for j in 0..i {
+                let lij = l[(i, j)];
+                let yj = y[j];
+                y[i] -= lij * yj;
+            }
+            y[i] /= l[(i, i)];
+        }
+        // L^H z = y
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let lji = l[(j, i)].conj();
+                let yj = y[j];
+                y[i] -= lji * yj;
+            }
+            y[i] /= l[(i, i)];
+        }
+        y
+    })
+}
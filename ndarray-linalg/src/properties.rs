@@ -0,0 +1,120 @@
+//! Structural properties of matrices
+//!
+//! These are `O(n^2)` scans to choose a cheaper solver before solving,
+//! e.g. [triangular](crate::triangular) or [tridiagonal](crate::tridiagonal) ones.
+
+use ndarray::*;
+
+use crate::types::*;
+
+pub use lax::UPLO;
+
+/// Structural predicates of a matrix
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]];
+/// assert!(a.is_diagonally_dominant());
+/// assert_eq!(a.is_banded(), Some((1, 1)));
+/// assert!(a.is_triangular().is_none());
+///
+/// let u = array![[1.0, 2.0], [0.0, 3.0]];
+/// assert!(matches!(u.is_triangular(), Some(UPLO::Upper)));
+/// ```
+pub trait MatrixProperties {
+    /// Strict diagonal dominance by rows, i.e. `|a_ii| > sum_{j != i} |a_ij|` for all `i`
+    ///
+    /// Such a matrix is non-singular, and LU decomposition is stable without pivoting.
+    /// Returns `false` for a non-square matrix.
+    fn is_diagonally_dominant(&self) -> bool;
+
+    /// The lower and upper bandwidths `(kl, ku)`, i.e. `a_ij = 0` if `i > j + kl` or `j > i + ku`
+    ///
+    /// Returns `None` if the matrix is dense, i.e. no band of zeros exists in either corner.
+    /// An empty matrix has the bandwidths `(0, 0)`, as a diagonal matrix does.
+    fn is_banded(&self) -> Option<(usize, usize)>;
+
+    /// Exact Hermiticity (symmetry for real matrices), i.e. `a_ij == conj(a_ji)` for all `i, j`
+    ///
+    /// Returns `false` for a non-square matrix.
+    fn is_hermitian(&self) -> bool;
+
+    /// The triangle of a square triangular matrix
+    ///
+    /// A diagonal matrix is reported as `UPLO::Upper`.
+    /// Returns `None` if the matrix is not square or not triangular.
+    fn is_triangular(&self) -> Option<UPLO>;
+}
+
+impl<A, S> MatrixProperties for ArrayBase<S, Ix2>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    fn is_diagonally_dominant(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        self.outer_iter().enumerate().all(|(i, row)| {
+            let off: A::Real = row
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, a)| a.abs())
+                .sum();
+            row[i].abs() > off
+        })
+    }
+
+    fn is_banded(&self) -> Option<(usize, usize)> {
+        let (n, m) = self.dim();
+        let (kl, ku) = bandwidth(self);
+        if n > 1 && m > 1 && kl == n - 1 && ku == m - 1 {
+            None
+        } else {
+            Some((kl, ku))
+        }
+    }
+
+    fn is_hermitian(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        self.indexed_iter()
+            .all(|((i, j), a)| i < j || *a == self[(j, i)].conj())
+    }
+
+    fn is_triangular(&self) -> Option<UPLO> {
+        if !self.is_square() {
+            return None;
+        }
+        match bandwidth(self) {
+            (0, _) => Some(UPLO::Upper),
+            (_, 0) => Some(UPLO::Lower),
+            _ => None,
+        }
+    }
+}
+
+/// The lower and upper bandwidths of the non-zero elements
+fn bandwidth<A, S>(a: &ArrayBase<S, Ix2>) -> (usize, usize)
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let mut kl = 0;
+    let mut ku = 0;
+    for ((i, j), a) in a.indexed_iter() {
+        if a.is_zero() {
+            continue;
+        }
+        if i > j {
+            kl = std::cmp::max(kl, i - j);
+        } else {
+            ku = std::cmp::max(ku, j - i);
+        }
+    }
+    (kl, ku)
+}
@@ -7,7 +7,9 @@ use num_traits::Zero;
 
 use crate::convert::*;
 use crate::error::*;
+use crate::krylov::{Orthogonalizer, MGS};
 use crate::layout::*;
+use crate::norm::Norm;
 use crate::triangular::*;
 use crate::types::*;
 
@@ -128,6 +130,61 @@ where
     }
 }
 
+/// Orthonormal basis of the column space of a matrix
+pub trait Orthonormalize {
+    type Elem: Scalar;
+    /// Orthonormalize the columns by the modified Gram-Schmidt procedure with reorthogonalization
+    ///
+    /// A column is dropped if its residual norm against the previous columns is less than
+    /// `rtol` times the largest column norm, and thus less columns than the input are returned
+    /// for a rank-deficient matrix. The columns are processed in order,
+    /// i.e. this is the `Q` of the thin QR decomposition if no column is dropped.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// let a = array![[1.0, 2.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 1.0]];
+    /// let q = a.orthonormalize(1e-9);
+    /// assert_eq!(q.ncols(), 2);
+    /// assert_close_l2!(&q.t().dot(&q), &Array2::eye(2), 1e-9);
+    /// ```
+    fn orthonormalize(&self, rtol: <Self::Elem as Scalar>::Real) -> Array2<Self::Elem>;
+}
+
+impl<A, S> Orthonormalize for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn orthonormalize(&self, rtol: A::Real) -> Array2<A> {
+        let n = self.nrows();
+        let scale = self
+            .axis_iter(Axis(1))
+            .map(|c| c.norm_l2())
+            .fold(A::Real::zero(), |x, y| if y > x { y } else { x });
+        if scale.is_zero() {
+            return Array2::zeros((n, 0));
+        }
+        let mut mgs = MGS::new(n, rtol * scale);
+        for c in self.axis_iter(Axis(1)) {
+            if mgs.is_full() {
+                break;
+            }
+            let mut c = c.to_owned();
+            // The second pass removes the components left by the cancellation in the first one
+            mgs.decompose(&mut c);
+            mgs.div_append(&mut c);
+        }
+        if mgs.is_empty() {
+            return Array2::zeros((n, 0));
+        }
+        mgs.get_q()
+    }
+}
+
 fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy,
@@ -0,0 +1,96 @@
+//! Generalized Schur (QZ) decomposition
+//!
+//! - [Schur decomposition - Wikipedia](https://en.wikipedia.org/wiki/Schur_decomposition#Generalized_Schur_decomposition)
+//!
+//! For square matrices `A` and `B`, this computes the unitary (orthogonal) `Q` and `Z` with
+//! `A = Q S Z^H` and `B = Q T Z^H`, where `S` and `T` are upper triangular.
+//! For real matrices, `S` is quasi-upper triangular with 2x2 blocks of complex conjugate
+//! generalized eigenvalues to keep all the factors real.
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![[1., 2.], [3., 4.]];
+//! let b: Array2<f64> = array![[2., 0.], [1., 1.]];
+//! let qz = qz(&a, &b, QzSort::LeftHalfPlane).unwrap();
+//! let zh = qz.z.t();
+//! assert!(qz.q.dot(&qz.s).dot(&zh).abs_diff_eq(&a, 1e-9));
+//! assert!(qz.q.dot(&qz.t).dot(&zh).abs_diff_eq(&b, 1e-9));
+//! // The eigenvalue with negative real part comes first
+//! assert_eq!(qz.sdim, 1);
+//! assert!(qz.eigenvalues()[0].re < 0.0);
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+pub use lax::QzSort;
+
+/// Result of [qz]
+#[derive(Debug, Clone)]
+pub struct QzResult<A: Scalar> {
+    /// The left Schur vectors `Q`
+    pub q: Array2<A>,
+    /// (Quasi-)upper triangular `S = Q^H A Z`
+    pub s: Array2<A>,
+    /// Upper triangular `T = Q^H B Z`
+    pub t: Array2<A>,
+    /// The right Schur vectors `Z`
+    pub z: Array2<A>,
+    /// Numerators `α` of the generalized eigenvalues `λ = α / β` in the order of the diagonal
+    pub alpha: Array1<A::Complex>,
+    /// Denominators `β` of the generalized eigenvalues, where `β = 0` means an infinite eigenvalue
+    pub beta: Array1<A>,
+    /// The number of the leading eigenvalues selected by [QzSort], or zero for [QzSort::None]
+    ///
+    /// The first `sdim` columns of `Z` span the corresponding right deflating subspace.
+    pub sdim: usize,
+}
+
+impl<A: Scalar> QzResult<A> {
+    /// The generalized eigenvalues `α / β`, which are infinite or NaN if `β = 0`
+    pub fn eigenvalues(&self) -> Array1<A::Complex> {
+        Zip::from(&self.alpha)
+            .and(&self.beta)
+            .map_collect(|&alpha, &beta| alpha / beta.as_c())
+    }
+}
+
+/// Compute the generalized Schur decomposition `A = Q S Z^H`, `B = Q T Z^H` by `*gges`
+///
+/// The eigenvalues specified by `sort` are moved to the leading part of the diagonal,
+/// e.g. `QzSort::LeftHalfPlane` for the stable eigenvalues of a descriptor system `B dx/dt = A x`.
+/// Returns `IncompatibleShape` error if `A` and `B` are not square matrices of the same size.
+pub fn qz<A, Sa, Sb>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    sort: QzSort,
+) -> Result<QzResult<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    if a.dim() != b.dim() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let mut s = fortran(a);
+    let mut t = fortran(b);
+    let l = s.layout()?;
+    let out = A::qz(l, sort, s.as_allocated_mut()?, t.as_allocated_mut()?)?;
+    Ok(QzResult {
+        q: into_matrix(l, out.q)?,
+        s,
+        t,
+        z: into_matrix(l, out.z)?,
+        alpha: Array1::from(out.alpha),
+        beta: Array1::from(out.beta),
+        sdim: out.sdim,
+    })
+}
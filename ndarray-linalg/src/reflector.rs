@@ -0,0 +1,77 @@
+//! Elementary (Householder) reflectors
+//!
+//! A reflector is represented as `H = I - tau v v^H` with `v[0] = 1`,
+//! the same packed representation as LAPACK uses e.g. in QR decomposition.
+//! These are building blocks for custom reductions, while
+//! [krylov::householder](../krylov/householder/index.html) implements an online QR decomposition.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let mut a: Array2<f64> = array![[3.0, 1.0], [4.0, 2.0]];
+//! // eliminate a[(1, 0)]
+//! let (v, tau, beta) = householder_reflector(&a.column(0));
+//! apply_householder(&mut a, Side::Left, &v, tau).unwrap();
+//! assert!((a[(0, 0)] - beta).abs() < 1e-9);
+//! assert!(a[(1, 0)].abs() < 1e-9);
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+pub use lax::Side;
+
+/// Generate an elementary reflector `H = I - tau v v^H` such that `H^H x = beta e_1` using LAPACK `*larfg`
+///
+/// Returns `(v, tau, beta)` where `v[0] = 1` and `beta` is real.
+/// For real `x`, `H` is symmetric and thus `H x = beta e_1`.
+/// For complex `x`, apply the reflector with `tau.conj()` to compute `H^H x`.
+///
+/// Panics if `x` is empty.
+pub fn householder_reflector<A, S>(x: &ArrayBase<S, Ix1>) -> (Array1<A>, A, A::Real)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    assert!(!x.is_empty(), "Householder reflector of an empty vector");
+    let mut v = x.to_owned();
+    let (tau, beta) = A::reflector(v.as_slice_mut().unwrap());
+    (v, tau, beta)
+}
+
+/// Apply an elementary reflector `H = I - tau v v^H` to `c` in place using LAPACK `*larfx`,
+/// i.e. `C = H C` for `Side::Left` or `C = C H` for `Side::Right`
+///
+/// Returns `IncompatibleShape` error if the length of `v` does not match `c`,
+/// and `InvalidStride` error if `c` is not contiguous.
+pub fn apply_householder<A, Sc, Sv>(
+    c: &mut ArrayBase<Sc, Ix2>,
+    side: Side,
+    v: &ArrayBase<Sv, Ix1>,
+    tau: A,
+) -> Result<()>
+where
+    A: Scalar + Lapack,
+    Sc: DataMut<Elem = A>,
+    Sv: Data<Elem = A>,
+{
+    let n = match side {
+        Side::Left => c.nrows(),
+        Side::Right => c.ncols(),
+    };
+    if v.len() != n {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if c.is_empty() {
+        return Ok(());
+    }
+    let l = c.layout()?;
+    A::apply_reflector(side, &v.to_vec(), tau, l, c.as_allocated_mut()?);
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! Matrix sign function and spectral projectors
+//!
+//! - [Matrix sign function - Wikipedia](https://en.wikipedia.org/wiki/Matrix_sign_function)
+//!
+//! For a matrix `A` without eigenvalues on the imaginary axis,
+//! `sign(A)` has the same eigenvectors as `A` with the eigenvalues `±1`
+//! according to the sign of the real parts of the eigenvalues of `A`.
+//! `(I - sign(A)) / 2` and `(I + sign(A)) / 2` are the projectors
+//! onto the invariant subspaces of the eigenvalues in the left and right half-planes.
+
+use ndarray::*;
+use num_traits::{Float, One, ToPrimitive};
+
+use crate::eig::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::opnorm::*;
+use crate::solve::*;
+use crate::types::*;
+
+/// Open half-plane of the complex plane divided by the imaginary axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HalfPlane {
+    /// `Re λ < 0`, e.g. the stable eigenvalues of a continuous-time system
+    Left,
+    /// `Re λ > 0`
+    Right,
+}
+
+/// Maximum number of the Newton iterations
+const MAX_ITER: usize = 100;
+
+/// Matrix sign function `sign(A)`
+///
+/// This uses the Newton iteration `X <- (μX + (μX)^{-1}) / 2`
+/// with the scaling `μ = (|X^{-1}|_1 / |X|_1)^{1/2}`,
+/// see N. J. Higham, Functions of Matrices (SIAM, 2008), Chapter 5.
+/// Returns `EigenvalueOnImaginaryAxis` error if an eigenvalue `λ` of `A`
+/// satisfies `|Re λ| <= nε |A|_1`, where `sign(A)` is not defined,
+/// `NotConverged` error if the iteration does not converge,
+/// and `NotSquare` error if `A` is not square.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[-1., 3.], [0., 2.]];
+/// let s = a.signm().unwrap();
+/// assert!(s.abs_diff_eq(&array![[-1., 2.], [0., 1.]], 1e-12));
+/// ```
+pub trait Signm {
+    type Output;
+    fn signm(&self) -> Result<Self::Output>;
+}
+
+impl<A, S> Signm for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn signm(&self) -> Result<Array2<A>> {
+        self.ensure_square()?;
+        let n = self.nrows();
+        if n == 0 {
+            return Ok(Array2::zeros((0, 0)));
+        }
+        let norm = self.opnorm_one()?;
+        let eps = A::real(n.to_f64().unwrap()) * A::Real::epsilon();
+        if self
+            .eigvals()?
+            .iter()
+            .any(|lambda| Float::abs(lambda.re()) <= eps * norm)
+        {
+            return Err(LinalgError::EigenvalueOnImaginaryAxis);
+        }
+
+        let half = A::real(0.5);
+        let tol = Float::sqrt(A::Real::epsilon());
+        let mut x = self.to_owned();
+        let mut scaling = true;
+        for _ in 0..MAX_ITER {
+            let x_inv = x.inv()?;
+            let mu = if scaling {
+                Float::sqrt(x_inv.opnorm_one()? / x.opnorm_one()?)
+            } else {
+                A::Real::one()
+            };
+            let next = Zip::from(&x)
+                .and(&x_inv)
+                .map_collect(|&x, &y| (x.mul_real(mu) + y.div_real(mu)).mul_real(half));
+            let diff = (&next - &x).opnorm_one()?;
+            let next_norm = next.opnorm_one()?;
+            x = next;
+            if !scaling {
+                return Ok(x);
+            }
+            if diff <= tol * next_norm {
+                // One more unscaled step reaches the machine precision
+                // by the quadratic convergence
+                scaling = false;
+            }
+        }
+        Err(LinalgError::NotConverged {
+            iterations: MAX_ITER,
+        })
+    }
+}
+
+/// Spectral projector onto the invariant subspace of the eigenvalues in a half-plane
+///
+/// The projector is `P = (I - sign(A)) / 2` for [HalfPlane::Left]
+/// and `P = (I + sign(A)) / 2` for [HalfPlane::Right],
+/// which satisfies `P^2 = P` and `AP = PA`.
+/// The range of `P` is the invariant subspace,
+/// whose orthonormal basis is given e.g. by the QR decomposition of `P`.
+/// Returns the same errors as [Signm::signm].
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[-1., 3.], [0., 2.]];
+/// let p = a.spectral_projector(HalfPlane::Left).unwrap();
+/// assert!(p.dot(&p).abs_diff_eq(&p, 1e-12));
+/// // range of `P` is the eigenvector `(1, 0)` of the eigenvalue `-1`
+/// assert!(p.abs_diff_eq(&array![[1., -1.], [0., 0.]], 1e-12));
+/// ```
+pub trait SpectralProjector {
+    type Output;
+    fn spectral_projector(&self, region: HalfPlane) -> Result<Self::Output>;
+}
+
+impl<A, S> SpectralProjector for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn spectral_projector(&self, region: HalfPlane) -> Result<Array2<A>> {
+        let mut p = self.signm()?;
+        let half = A::real(0.5);
+        let sign = match region {
+            HalfPlane::Left => -half,
+            HalfPlane::Right => half,
+        };
+        p.mapv_inplace(|s| s.mul_real(sign));
+        for i in 0..p.nrows() {
+            p[(i, i)] += A::from_real(half);
+        }
+        Ok(p)
+    }
+}
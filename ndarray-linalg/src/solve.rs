@@ -45,17 +45,32 @@
 //!
 //! # }
 //! ```
+//!
+//! `SolveFactored` solves the first system and returns the factorization
+//! at once:
+//!
+//! ```
+//! use ndarray::prelude::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = random((3, 3));
+//! let b: Array1<f64> = random(3);
+//! let (x, f) = a.solve_factored(&b).unwrap();
+//! let det = f.det().unwrap(); // reuse the factorization
+//! ```
 
 use ndarray::*;
-use num_traits::{Float, Zero};
+use num_traits::{Float, One, ToPrimitive, Zero};
+use std::sync::OnceLock;
 
 use crate::convert::*;
 use crate::error::*;
 use crate::layout::*;
+use crate::norm::Norm;
 use crate::opnorm::OperationNorm;
 use crate::types::*;
 
-pub use lax::{Pivot, Transpose};
+pub use lax::{Pivot, RefineErrorBounds, Transpose};
 
 /// An interface for solving systems of linear equations.
 ///
@@ -74,6 +89,10 @@ pub use lax::{Pivot, Transpose};
 /// If you plan to solve many equations with the same `A` matrix but different
 /// `b` vectors, it's faster to factor the `A` matrix once using the
 /// `Factorize` trait, and then solve using the `LUFactorized` struct.
+///
+/// `A` and `b` can be views into larger arrays. A non-contiguous `b`, e.g. a
+/// column of a C-contiguous matrix, is copied into a contiguous buffer, and
+/// `*_inplace` methods write the solution back into the view.
 pub trait Solve<A: Scalar> {
     /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
@@ -115,6 +134,30 @@ pub trait Solve<A: Scalar> {
         b: &'a mut ArrayBase<S, Ix1>,
     ) -> Result<&'a mut ArrayBase<S, Ix1>>;
 
+    /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
+    /// is the argument, and `x` is written into `out`.
+    ///
+    /// This does not allocate for `LUFactorized` and contiguous `out`,
+    /// e.g. to solve with pooled buffers in a loop.
+    /// Returns `IncompatibleShape` error if the lengths of `b` and `out` differ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_into_buffer<S: Data<Elem = A>, So: DataMut<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+        out: &mut ArrayBase<So, Ix1>,
+    ) -> Result<()> {
+        if out.len() != b.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        out.assign(b);
+        self.solve_inplace(out)?;
+        Ok(())
+    }
+
     /// Solves a system of linear equations `A^T * x = b` where `A` is `self`, `b`
     /// is the argument, and `x` is the successful result.
     ///
@@ -195,13 +238,36 @@ pub trait Solve<A: Scalar> {
 }
 
 /// Represents the LU factorization of a matrix `A` as `A = P*L*U`.
+///
+/// The reciprocal condition number is estimated at the first call of `rcond` or `cond`,
+/// and cached for the later calls.
 #[derive(Clone)]
-pub struct LUFactorized<S: Data + RawDataClone> {
+pub struct LUFactorized<S: Data + RawDataClone>
+where
+    S::Elem: Scalar,
+{
     /// The factors `L` and `U`; the unit diagonal elements of `L` are not
     /// stored.
     a: ArrayBase<S, Ix2>,
     /// The pivot indices that define the permutation matrix `P`.
     ipiv: Pivot,
+    /// The 1-norm of the original matrix `A` required by `*gecon`
+    a_opnorm_one: <S::Elem as Scalar>::Real,
+    /// Cache of the reciprocal condition number
+    rcond: OnceLock<<S::Elem as Scalar>::Real>,
+}
+
+impl<A, S> LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Estimates the condition number `1 / rcond` of `A` in 1-norm
+    ///
+    /// This shares the cache with `rcond`, i.e. only the first call runs `*gecon`.
+    pub fn cond(&self) -> Result<A::Real> {
+        Ok(self.rcond()?.recip())
+    }
 }
 
 impl<A, S> Solve<A> for LUFactorized<S>
@@ -221,13 +287,16 @@ where
             self.a.len_of(Axis(1)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solve(
-            self.a.square_layout()?,
-            Transpose::No,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        with_contiguous_mut(rhs, |rhs| {
+            A::solve(
+                self.a.square_layout()?,
+                Transpose::No,
+                self.a.as_allocated()?,
+                &self.ipiv,
+                rhs,
+            )?;
+            Ok(())
+        })?;
         Ok(rhs)
     }
     fn solve_t_inplace<'a, Sb>(
@@ -242,13 +311,16 @@ where
             self.a.len_of(Axis(0)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solve(
-            self.a.square_layout()?,
-            Transpose::Transpose,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        with_contiguous_mut(rhs, |rhs| {
+            A::solve(
+                self.a.square_layout()?,
+                Transpose::Transpose,
+                self.a.as_allocated()?,
+                &self.ipiv,
+                rhs,
+            )?;
+            Ok(())
+        })?;
         Ok(rhs)
     }
     fn solve_h_inplace<'a, Sb>(
@@ -263,13 +335,16 @@ where
             self.a.len_of(Axis(0)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solve(
-            self.a.square_layout()?,
-            Transpose::Hermite,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        with_contiguous_mut(rhs, |rhs| {
+            A::solve(
+                self.a.square_layout()?,
+                Transpose::Hermite,
+                self.a.as_allocated()?,
+                &self.ipiv,
+                rhs,
+            )?;
+            Ok(())
+        })?;
         Ok(rhs)
     }
 }
@@ -311,15 +386,437 @@ where
     }
 }
 
+/// Solve systems of linear equations `A * X = B` for the multiple right-hand sides `B`
+///
+/// `A` and `B` may have the different layouts, e.g. a row-major `A` with a column-major `B`.
+/// The layout of `A` is handled by LAPACK without copy,
+/// while a row-major `B` is transposed into a temporary buffer internally,
+/// and the solution `X` always has the same layout as `B`.
+/// A non-contiguous `B` is copied into a contiguous buffer as in [Solve].
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[3., 2., -1.], [2., -2., 4.], [-2., 1., -2.]];
+/// // column-major `B`
+/// let b: Array2<f64> = array![[1., -2., 0.], [3., 4., -3.]].reversed_axes();
+/// let x = a.solve_matrix(&b).unwrap();
+/// assert!(a.dot(&x).abs_diff_eq(&b, 1e-9));
+/// assert!(!x.is_standard_layout());
+/// ```
+pub trait SolveMatrix<A: Scalar> {
+    /// Solves `A * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of columns of `A`.
+    fn solve_matrix<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>> {
+        let mut b = replicate(b);
+        self.solve_matrix_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves `A * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of columns of `A`.
+    fn solve_matrix_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_matrix_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves `A * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of columns of `A`.
+    fn solve_matrix_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix2>,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>>;
+
+    /// Solves `A^T * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of rows of `A`.
+    fn solve_t_matrix<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>> {
+        let mut b = replicate(b);
+        self.solve_t_matrix_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves `A^T * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of rows of `A`.
+    fn solve_t_matrix_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_t_matrix_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves `A^T * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of rows of `A`.
+    fn solve_t_matrix_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix2>,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>>;
+
+    /// Solves `A^H * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of rows of `A`.
+    fn solve_h_matrix<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array2<A>> {
+        let mut b = replicate(b);
+        self.solve_h_matrix_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves `A^H * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of rows of `A`.
+    fn solve_h_matrix_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_h_matrix_inplace(&mut b)?;
+        Ok(b)
+    }
+    /// Solves `A^H * X = B` where `A` is `self`, `B` is the argument, and `X` is the successful result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of rows of `B` is not the equal to the number of rows of `A`.
+    fn solve_h_matrix_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, Ix2>,
+    ) -> Result<&'a mut ArrayBase<S, Ix2>>;
+}
+
+impl<A, S> LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Solve `op(A) X = B` in place, copying `B` if it is not contiguous
+    fn solve_nrhs<'a, Sb>(
+        &self,
+        t: Transpose,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.a.nrows(),
+            "The number of rows of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        let l = self.a.square_layout()?;
+        let a = self.a.as_allocated()?;
+        if let Ok(b_layout) = rhs.layout() {
+            A::solve_nrhs(l, t, a, &self.ipiv, b_layout, rhs.as_allocated_mut()?)?;
+        } else {
+            let mut b = rhs.to_owned();
+            A::solve_nrhs(l, t, a, &self.ipiv, b.layout()?, b.as_allocated_mut()?)?;
+            rhs.assign(&b);
+        }
+        Ok(rhs)
+    }
+}
+
+impl<A, S> SolveMatrix<A> for LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_matrix_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_nrhs(Transpose::No, rhs)
+    }
+    fn solve_t_matrix_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_nrhs(Transpose::Transpose, rhs)
+    }
+    fn solve_h_matrix_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_nrhs(Transpose::Hermite, rhs)
+    }
+}
+
+impl<A, S> SolveMatrix<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_matrix_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize()?;
+        f.solve_matrix_inplace(rhs)
+    }
+    fn solve_t_matrix_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize()?;
+        f.solve_t_matrix_inplace(rhs)
+    }
+    fn solve_h_matrix_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize()?;
+        f.solve_h_matrix_inplace(rhs)
+    }
+}
+
+/// Solve a real system of linear equations `A * x = b` with a complex right-hand side `b`
+///
+/// The real and imaginary parts of `b` are solved separately against the real LU factorization,
+/// which is cheaper than promoting `A` to a complex matrix.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[3., 2., -1.], [2., -2., 4.], [-2., 1., -2.]];
+/// let b = array![c64::new(1., 2.), c64::new(-2., 0.), c64::new(0., 1.)];
+/// let x = a.solve_complex(&b).unwrap();
+/// let ac = a.mapv(c64::from);
+/// assert!(ac.dot(&x).abs_diff_eq(&b, 1e-9));
+/// ```
+pub trait SolveComplex<A: Scalar> {
+    /// Solves `A * x = b` for the complex `x`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_complex<S: Data<Elem = A::Complex>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<Array1<A::Complex>>;
+}
+
+impl<A, S> SolveComplex<A> for LUFactorized<S>
+where
+    A: Scalar<Real = A> + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_complex<Sb: Data<Elem = A::Complex>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<Array1<A::Complex>> {
+        let re = self.solve_into(b.mapv(|b| b.re()))?;
+        let im = self.solve_into(b.mapv(|b| b.im()))?;
+        Ok(Zip::from(&re)
+            .and(&im)
+            .map_collect(|&re, &im| A::complex(re, im)))
+    }
+}
+
+impl<A, S> SolveComplex<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar<Real = A> + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_complex<Sb: Data<Elem = A::Complex>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+    ) -> Result<Array1<A::Complex>> {
+        let f = self.factorize()?;
+        f.solve_complex(b)
+    }
+}
+
+/// Solve a complex system of linear equations `A * x = b` whose solution is known to be real
+///
+/// Only the real part of the solution is returned, after checking that
+/// the discarded imaginary part is negligible, i.e. `|Im x| <= rtol |x|` in the 2-norm.
+/// Returns `NotReal` error otherwise rather than dropping a significant imaginary part silently.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[c64::new(1., 1.), c64::new(0., 2.)], [c64::new(2., 0.), c64::new(1., -1.)]];
+/// // `b = A (1, -1)^T`
+/// let b = array![c64::new(1., -1.), c64::new(1., 1.)];
+/// let x = a.solve_real_part(&b, 1e-9).unwrap();
+/// assert!(x.abs_diff_eq(&array![1., -1.], 1e-9));
+/// ```
+pub trait SolveRealPart<A: Scalar> {
+    /// Solves `A * x = b` for the real `x`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_real_part<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+        rtol: A::Real,
+    ) -> Result<Array1<A::Real>>;
+}
+
+impl<A, S> SolveRealPart<A> for LUFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_real_part<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        rtol: A::Real,
+    ) -> Result<Array1<A::Real>> {
+        let x = self.solve(b)?;
+        let norm = x.norm_l2();
+        let im_norm = x.mapv(|x| A::from_real(x.im())).norm_l2();
+        if im_norm.is_nan() || im_norm > rtol * norm {
+            return Err(LinalgError::NotReal {
+                ratio: (im_norm / norm).to_f64().unwrap(),
+            });
+        }
+        Ok(x.map(|x| x.re()))
+    }
+}
+
+impl<A, S> SolveRealPart<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solve_real_part<Sb: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<Sb, Ix1>,
+        rtol: A::Real,
+    ) -> Result<Array1<A::Real>> {
+        let f = self.factorize()?;
+        f.solve_real_part(b, rtol)
+    }
+}
+
+/// Solve a system of linear equations with the iterative refinement in extended precision
+///
+/// The solution by the LU decomposition is refined by LAPACK `*gerfsx`,
+/// which computes the residual in extended precision if LAPACK is linked with XBLAS,
+/// and falls back to the refinement in the working precision otherwise.
+/// The achieved error bounds are returned with the solution.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[3., 2., -1.], [2., -2., 4.], [-2., 1., -2.]];
+/// let b: Array1<f64> = array![1., -2., 0.];
+/// let (x, bounds) = a.solve_xrefine(&b).unwrap();
+/// assert!(x.abs_diff_eq(&array![1., -2., -2.], 1e-9));
+/// assert!(bounds.normwise_trusted && bounds.normwise < 1e-9);
+/// ```
+pub trait SolveXRefine<A: Scalar> {
+    /// Solves `A * x = b` with the iterative refinement
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_xrefine<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<(Array1<A>, RefineErrorBounds<A::Real>)>;
+}
+
+impl<A, Si> SolveXRefine<A> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn solve_xrefine<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<(Array1<A>, RefineErrorBounds<A::Real>)> {
+        self.ensure_square()?;
+        assert_eq!(b.len(), self.ncols());
+        // LAPACK requires `A` and its LU factor in the same layout, and both are copied by `replicate`
+        let a: Array2<A> = replicate(self);
+        let f = a.factorize()?;
+        let mut x = f.solve(b)?;
+        if x.is_empty() {
+            let zero = A::Real::zero();
+            let bounds = RefineErrorBounds {
+                rcond: A::Real::one(),
+                backward_error: zero,
+                normwise: zero,
+                componentwise: zero,
+                normwise_trusted: true,
+                componentwise_trusted: true,
+            };
+            return Ok((x, bounds));
+        }
+        let b = b.to_vec();
+        let bounds = A::refine(
+            a.layout()?,
+            a.as_allocated()?,
+            f.a.as_allocated()?,
+            &f.ipiv,
+            &b,
+            x.as_slice_mut().unwrap(),
+        )?;
+        Ok((x, bounds))
+    }
+}
+
 /// An interface for computing LU factorizations of matrix refs.
-pub trait Factorize<S: Data + RawDataClone> {
+pub trait Factorize<S: Data + RawDataClone>
+where
+    S::Elem: Scalar,
+{
     /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
     /// matrix.
     fn factorize(&self) -> Result<LUFactorized<S>>;
 }
 
 /// An interface for computing LU factorizations of matrices.
-pub trait FactorizeInto<S: Data + RawDataClone> {
+pub trait FactorizeInto<S: Data + RawDataClone>
+where
+    S::Elem: Scalar,
+{
     /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
     /// matrix.
     fn factorize_into(self) -> Result<LUFactorized<S>>;
@@ -331,8 +828,14 @@ where
     S: DataMut<Elem = A> + RawDataClone,
 {
     fn factorize_into(mut self) -> Result<LUFactorized<S>> {
+        let a_opnorm_one = self.opnorm_one()?;
         let ipiv = A::lu(self.layout()?, self.as_allocated_mut()?)?;
-        Ok(LUFactorized { a: self, ipiv })
+        Ok(LUFactorized {
+            a: self,
+            ipiv,
+            a_opnorm_one,
+            rcond: OnceLock::new(),
+        })
     }
 }
 
@@ -343,11 +846,102 @@ where
 {
     fn factorize(&self) -> Result<LUFactorized<OwnedRepr<A>>> {
         let mut a: Array2<A> = replicate(self);
+        let a_opnorm_one = a.opnorm_one()?;
         let ipiv = A::lu(a.layout()?, a.as_allocated_mut()?)?;
-        Ok(LUFactorized { a, ipiv })
+        Ok(LUFactorized {
+            a,
+            ipiv,
+            a_opnorm_one,
+            rcond: OnceLock::new(),
+        })
     }
 }
 
+/// An interface for solving a system of linear equations while keeping the
+/// LU factorization for reuse.
+pub trait SolveFactored<A: Scalar> {
+    /// Solves a system of linear equations `A * x = b` where `A` is `self`, `b`
+    /// is the argument, and returns `x` together with the LU factorization of
+    /// `A`.
+    ///
+    /// The factorization can be used for subsequent solves or the determinant
+    /// without factorizing `A` again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not the equal to the number of columns
+    /// of `A`.
+    fn solve_factored<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<(Array1<A>, LUFactorized<OwnedRepr<A>>)>;
+}
+
+impl<A, Si> SolveFactored<A> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn solve_factored<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<(Array1<A>, LUFactorized<OwnedRepr<A>>)> {
+        let f = self.factorize()?;
+        let x = f.solve(b)?;
+        Ok((x, f))
+    }
+}
+
+/// Solve `(A ⊗ B) x = c` without forming the Kronecker product
+///
+/// Since `(A ⊗ B) vec(X) = vec(A X B^T)` for the row-major `vec` of `X`,
+/// `c` is reshaped into the matrix `C`, and `X = A^{-1} C B^{-T}` is computed by
+/// the LU decompositions of the small factors `A` and `B`.
+///
+/// Returns `NotSquare` error if `A` or `B` is not square,
+/// and `IncompatibleShape` error if the length of `c` is not the product of their sizes.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray::linalg::kron;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2., 1.], [1., 3.]];
+/// let b: Array2<f64> = array![[4., 1., 0.], [1., 4., 1.], [0., 1., 4.]];
+/// let c: Array1<f64> = array![1., 2., 3., 4., 5., 6.];
+/// let x = kron_solve(&a, &b, &c).unwrap();
+/// assert!(kron(&a, &b).dot(&x).abs_diff_eq(&c, 1e-9));
+/// ```
+pub fn kron_solve<A, Sa, Sb, Sc>(
+    a: &ArrayBase<Sa, Ix2>,
+    b: &ArrayBase<Sb, Ix2>,
+    c: &ArrayBase<Sc, Ix1>,
+) -> Result<Array1<A>>
+where
+    A: Scalar + Lapack,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+    Sc: Data<Elem = A>,
+{
+    a.ensure_square()?;
+    b.ensure_square()?;
+    let (na, nb) = (a.nrows(), b.nrows());
+    if c.len() != na * nb {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    let fa = a.factorize()?;
+    let fb = b.factorize()?;
+    let mut x = Array::from_iter(c.iter().cloned()).into_shape((na, nb))?;
+    // `A^{-1} C` by columns, and then `(A^{-1} C) B^{-T}` by rows
+    for mut col in x.axis_iter_mut(Axis(1)) {
+        fa.solve_inplace(&mut col)?;
+    }
+    for mut row in x.axis_iter_mut(Axis(0)) {
+        fb.solve_inplace(&mut row)?;
+    }
+    Ok(x.into_shape(na * nb)?)
+}
+
 /// An interface for inverting matrix refs.
 pub trait Inverse {
     type Output;
@@ -397,6 +991,8 @@ where
         let f = LUFactorized {
             a,
             ipiv: self.ipiv.clone(),
+            a_opnorm_one: self.a_opnorm_one,
+            rcond: self.rcond.clone(),
         };
         f.inv_into()
     }
@@ -410,6 +1006,7 @@ where
     type Output = Self;
 
     fn inv_into(self) -> Result<Self::Output> {
+        self.ensure_square()?;
         let f = self.factorize_into()?;
         f.inv_into()
     }
@@ -423,12 +1020,29 @@ where
     type Output = Array2<A>;
 
     fn inv(&self) -> Result<Self::Output> {
+        self.ensure_square()?;
         let f = self.factorize()?;
         f.inv_into()
     }
 }
 
 /// An interface for calculating determinants of matrix refs.
+///
+/// This is implemented for square matrices using the LU factorization, and
+/// also for `LUFactorized` to reuse an existing factorization.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]];
+/// assert!((a.det().unwrap() - 49.).abs() < 1e-9);
+///
+/// // `(sign, ln|det|)` avoids overflow for large matrices
+/// let (sign, ln_det) = a.sln_det().unwrap();
+/// assert_eq!(sign, 1.);
+/// assert!((ln_det - 49f64.ln()).abs() < 1e-9);
+/// ```
 pub trait Determinant<A: Scalar> {
     /// Computes the determinant of the matrix.
     fn det(&self) -> Result<A> {
@@ -530,6 +1144,29 @@ where
     }
 }
 
+/// `sln_det` of an empty or 1x1 matrix without the LU decomposition
+///
+/// The determinant of the empty matrix is one as the empty product.
+fn sln_det_small<A, S>(a: &ArrayBase<S, Ix2>) -> Option<(A, A::Real)>
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    match a.nrows() {
+        0 => Some((A::one(), A::Real::zero())),
+        1 => {
+            let x = a[(0, 0)];
+            let abs = x.abs();
+            if abs.is_zero() {
+                Some((A::zero(), A::Real::neg_infinity()))
+            } else {
+                Some((x.div_real(abs), Float::ln(abs)))
+            }
+        }
+        _ => None,
+    }
+}
+
 impl<A, S> Determinant<A> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -537,6 +1174,9 @@ where
 {
     fn sln_det(&self) -> Result<(A, A::Real)> {
         self.ensure_square()?;
+        if let Some(d) = sln_det_small(self) {
+            return Ok(d);
+        }
         match self.factorize() {
             Ok(fac) => fac.sln_det(),
             Err(LinalgError::Lapack(e))
@@ -557,6 +1197,9 @@ where
 {
     fn sln_det_into(self) -> Result<(A, A::Real)> {
         self.ensure_square()?;
+        if let Some(d) = sln_det_small(&self) {
+            return Ok(d);
+        }
         match self.factorize_into() {
             Ok(fac) => fac.sln_det_into(),
             Err(LinalgError::Lapack(e))
@@ -570,6 +1213,68 @@ where
     }
 }
 
+/// Sign of the determinant of a real matrix
+///
+/// The sign is given by the parity of the LU pivots and the signs of the diagonal of `U`
+/// without the product of the diagonal, which may overflow,
+/// e.g. to check the orientation of an orthogonal matrix or a geometric predicate.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// // reflection
+/// let a: Array2<f64> = array![[0., 1.], [1., 0.]];
+/// assert_eq!(a.det_sign().unwrap(), -1);
+/// let a: Array2<f64> = array![[1., 2.], [2., 4.]];
+/// assert_eq!(a.det_sign().unwrap(), 0);
+/// ```
+pub trait DeterminantSign {
+    /// Returns `1`, `-1`, or `0` for a singular matrix
+    fn det_sign(&self) -> Result<i8>;
+}
+
+impl<A, S> DeterminantSign for LUFactorized<S>
+where
+    A: Scalar<Real = A> + Lapack + Float,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn det_sign(&self) -> Result<i8> {
+        self.a.ensure_square()?;
+        let mut negative = self
+            .ipiv
+            .iter()
+            .enumerate()
+            .filter(|&(i, &pivot)| pivot != i as i32 + 1)
+            .count();
+        for &u in self.a.diag() {
+            if u.is_zero() {
+                return Ok(0);
+            }
+            if u < A::zero() {
+                negative += 1;
+            }
+        }
+        Ok(if negative % 2 == 0 { 1 } else { -1 })
+    }
+}
+
+impl<A, S> DeterminantSign for ArrayBase<S, Ix2>
+where
+    A: Scalar<Real = A> + Lapack + Float,
+    S: Data<Elem = A>,
+{
+    fn det_sign(&self) -> Result<i8> {
+        self.ensure_square()?;
+        match self.factorize() {
+            Ok(fac) => fac.det_sign(),
+            // The determinant is zero.
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => Ok(0),
+            Err(err) => Err(err),
+        }
+    }
+}
+
 /// An interface for *estimating* the reciprocal condition number of matrix refs.
 pub trait ReciprocalConditionNum<A: Scalar> {
     /// *Estimates* the reciprocal of the condition number of the matrix in
@@ -604,11 +1309,11 @@ where
     S: Data<Elem = A> + RawDataClone,
 {
     fn rcond(&self) -> Result<A::Real> {
-        Ok(A::rcond(
-            self.a.layout()?,
-            self.a.as_allocated()?,
-            self.a.opnorm_one()?,
-        )?)
+        if let Some(&rcond) = self.rcond.get() {
+            return Ok(rcond);
+        }
+        let rcond = A::rcond(self.a.layout()?, self.a.as_allocated()?, self.a_opnorm_one)?;
+        Ok(*self.rcond.get_or_init(|| rcond))
     }
 }
 
@@ -1,7 +1,9 @@
 //! Solve Hermitian (or real symmetric) linear problems and invert Hermitian
 //! (or real symmetric) matrices
 //!
-//! **Note that only the upper triangular portion of the matrix is used.**
+//! **Note that only the upper triangular portion of the matrix is used**
+//! unless the triangle is specified by `FactorizeH::factorizeh_uplo`
+//! or `FactorizeHInto::factorizeh_uplo_into`.
 //!
 //! # Examples
 //!
@@ -53,6 +55,7 @@ use ndarray::*;
 use num_traits::{Float, One, Zero};
 
 use crate::convert::*;
+use crate::eigh::EigValsh;
 use crate::error::*;
 use crate::layout::*;
 use crate::types::*;
@@ -135,13 +138,16 @@ where
             self.a.len_of(Axis(1)),
             "The length of `rhs` must be compatible with the shape of the factored matrix.",
         );
-        A::solveh(
-            self.a.square_layout()?,
-            UPLO::Upper,
-            self.a.as_allocated()?,
-            &self.ipiv,
-            rhs.as_slice_mut().unwrap(),
-        )?;
+        with_contiguous_mut(rhs, |rhs| {
+            A::solveh(
+                self.a.square_layout()?,
+                UPLO::Upper,
+                self.a.as_allocated()?,
+                &self.ipiv,
+                rhs,
+            )?;
+            Ok(())
+        })?;
         Ok(rhs)
     }
 }
@@ -167,16 +173,28 @@ where
 /// real symmetric) matrix refs.
 pub trait FactorizeH<S: Data> {
     /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
-    /// symmetric) matrix.
-    fn factorizeh(&self) -> Result<BKFactorized<S>>;
+    /// symmetric) matrix using its upper triangle.
+    fn factorizeh(&self) -> Result<BKFactorized<S>> {
+        self.factorizeh_uplo(UPLO::Upper)
+    }
+
+    /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
+    /// symmetric) matrix using only the triangle specified by `uplo`.
+    fn factorizeh_uplo(&self, uplo: UPLO) -> Result<BKFactorized<S>>;
 }
 
 /// An interface for computing the Bunch–Kaufman factorization of Hermitian (or
 /// real symmetric) matrices.
-pub trait FactorizeHInto<S: Data> {
+pub trait FactorizeHInto<S: Data>: Sized {
     /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
-    /// symmetric) matrix.
-    fn factorizeh_into(self) -> Result<BKFactorized<S>>;
+    /// symmetric) matrix using its upper triangle.
+    fn factorizeh_into(self) -> Result<BKFactorized<S>> {
+        self.factorizeh_uplo_into(UPLO::Upper)
+    }
+
+    /// Computes the Bunch–Kaufman factorization of a Hermitian (or real
+    /// symmetric) matrix using only the triangle specified by `uplo`.
+    fn factorizeh_uplo_into(self, uplo: UPLO) -> Result<BKFactorized<S>>;
 }
 
 impl<A, S> FactorizeHInto<S> for ArrayBase<S, Ix2>
@@ -184,8 +202,13 @@ where
     A: Scalar + Lapack,
     S: DataMut<Elem = A>,
 {
-    fn factorizeh_into(mut self) -> Result<BKFactorized<S>> {
-        let ipiv = A::bk(self.square_layout()?, UPLO::Upper, self.as_allocated_mut()?)?;
+    fn factorizeh_uplo_into(mut self, uplo: UPLO) -> Result<BKFactorized<S>> {
+        let layout = self.square_layout()?;
+        // The factorization always keeps the upper triangle
+        if let UPLO::Lower = uplo {
+            triangular_fill_hermitian(&mut self, UPLO::Lower);
+        }
+        let ipiv = A::bk(layout, UPLO::Upper, self.as_allocated_mut()?)?;
         Ok(BKFactorized { a: self, ipiv })
     }
 }
@@ -195,10 +218,9 @@ where
     A: Scalar + Lapack,
     Si: Data<Elem = A>,
 {
-    fn factorizeh(&self) -> Result<BKFactorized<OwnedRepr<A>>> {
-        let mut a: Array2<A> = replicate(self);
-        let ipiv = A::bk(a.square_layout()?, UPLO::Upper, a.as_allocated_mut()?)?;
-        Ok(BKFactorized { a, ipiv })
+    fn factorizeh_uplo(&self, uplo: UPLO) -> Result<BKFactorized<OwnedRepr<A>>> {
+        let a: Array2<A> = replicate(self);
+        a.factorizeh_uplo_into(uplo)
     }
 }
 
@@ -332,7 +354,6 @@ where
     S: Data<Elem = A>,
     A: Scalar + Lapack,
 {
-    let layout = a.layout().unwrap();
     let mut sign = A::Real::one();
     let mut ln_det = A::Real::zero();
     let mut ipiv_enum = ipiv_iter.enumerate();
@@ -356,15 +377,9 @@ where
             debug_assert_eq!(lower_diag.im(), Zero::zero());
 
             // Off-diagonal elements, can be complex.
-            let off_diag = match layout {
-                MatrixLayout::C { .. } => match uplo {
-                    UPLO::Upper => unsafe { a.uget((k + 1, k)) },
-                    UPLO::Lower => unsafe { a.uget((k, k + 1)) },
-                },
-                MatrixLayout::F { .. } => match uplo {
-                    UPLO::Upper => unsafe { a.uget((k, k + 1)) },
-                    UPLO::Lower => unsafe { a.uget((k + 1, k)) },
-                },
+            let off_diag = match uplo {
+                UPLO::Upper => unsafe { a.uget((k, k + 1)) },
+                UPLO::Lower => unsafe { a.uget((k + 1, k)) },
             };
 
             // Determinant of 2x2 block.
@@ -379,6 +394,62 @@ where
     (sign, ln_det)
 }
 
+/// Returns the inertia `(positive, negative, zero)` of the block diagonal
+/// factor `D`.
+fn bk_inertia<P, S, A>(uplo: UPLO, ipiv_iter: P, a: &ArrayBase<S, Ix2>) -> (usize, usize, usize)
+where
+    P: Iterator<Item = i32>,
+    S: Data<Elem = A>,
+    A: Scalar + Lapack,
+{
+    let (mut positive, mut negative, mut zero) = (0, 0, 0);
+    let mut count = |val: A::Real| {
+        if val > A::Real::zero() {
+            positive += 1;
+        } else if val < A::Real::zero() {
+            negative += 1;
+        } else {
+            zero += 1;
+        }
+    };
+    let mut ipiv_enum = ipiv_iter.enumerate();
+    while let Some((k, ipiv_k)) = ipiv_enum.next() {
+        debug_assert!(k < a.nrows() && k < a.ncols());
+        if ipiv_k > 0 {
+            // 1x1 block at k, must be real.
+            count(unsafe { a.uget((k, k)) }.re());
+        } else {
+            // 2x2 block at k..k+2.
+            let upper_diag = unsafe { a.uget((k, k)) }.re();
+            let lower_diag = unsafe { a.uget((k + 1, k + 1)) }.re();
+            let off_diag = match uplo {
+                UPLO::Upper => unsafe { a.uget((k, k + 1)) },
+                UPLO::Lower => unsafe { a.uget((k + 1, k)) },
+            };
+
+            // The eigenvalues of a 2x2 Hermitian block have opposite signs
+            // iff its determinant is negative. Otherwise their common sign is
+            // the sign of the trace.
+            let block_det = upper_diag * lower_diag - off_diag.square();
+            let block_trace = upper_diag + lower_diag;
+            if block_det < A::Real::zero() {
+                count(A::Real::one());
+                count(-A::Real::one());
+            } else if block_det > A::Real::zero() {
+                count(block_trace);
+                count(block_trace);
+            } else {
+                count(A::Real::zero());
+                count(block_trace);
+            }
+
+            // Skip the k+1 ipiv value.
+            ipiv_enum.next();
+        }
+    }
+    (positive, negative, zero)
+}
+
 impl<A, S> BKFactorized<S>
 where
     A: Scalar + Lapack,
@@ -431,6 +502,17 @@ where
     pub fn sln_deth_into(self) -> (A::Real, A::Real) {
         bk_sln_det(UPLO::Upper, self.ipiv.into_iter(), &self.a)
     }
+
+    /// Computes the inertia `(positive, negative, zero)` of the factorized
+    /// Hermitian (or real symmetric) matrix, i.e. the number of positive,
+    /// negative, and zero eigenvalues.
+    ///
+    /// By Sylvester's law of inertia, `A` and the block diagonal matrix `D` of
+    /// the factorization have the same inertia, so this only inspects the
+    /// 1x1 and 2x2 diagonal blocks of `D` and does not compute eigenvalues.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        bk_inertia(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
+    }
 }
 
 impl<A, S> DeterminantH for ArrayBase<S, Ix2>
@@ -484,3 +566,43 @@ where
         }
     }
 }
+
+/// An interface for computing the inertia of Hermitian (or real symmetric)
+/// matrix refs.
+pub trait InertiaH {
+    /// Computes the inertia `(positive, negative, zero)` of the Hermitian (or
+    /// real symmetric) matrix, i.e. the number of positive, negative, and zero
+    /// eigenvalues.
+    ///
+    /// This uses the Bunch-Kaufman factorization and Sylvester's law of
+    /// inertia, which is much cheaper than a full eigenvalue decomposition.
+    /// If the block diagonal factor turns out to be exactly singular, LAPACK
+    /// reports a failure without the pivots, and the eigenvalues are counted
+    /// instead, treating those below `n * epsilon * max(|eigenvalue|)` as
+    /// zero.
+    fn inertia(&self) -> Result<(usize, usize, usize)>;
+}
+
+impl<A, S> InertiaH for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn inertia(&self) -> Result<(usize, usize, usize)> {
+        match self.factorizeh() {
+            Ok(fac) => Ok(fac.inertia()),
+            Err(LinalgError::Lapack(lax::error::Error::LapackComputationalFailure { .. })) => {
+                // `D` is exactly singular.
+                let eigs = self.eigvalsh(UPLO::Upper)?;
+                let max = eigs.iter().fold(A::Real::zero(), |max, &val| {
+                    Float::max(max, Float::abs(val))
+                });
+                let tol = A::real(eigs.len()) * A::Real::epsilon() * max;
+                let positive = eigs.iter().filter(|&&val| val > tol).count();
+                let negative = eigs.iter().filter(|&&val| val < -tol).count();
+                Ok((positive, negative, eigs.len() - positive - negative))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Stochastic estimators of spectral quantities of large matrices
+//!
+//! These estimators access the matrix only through a closure applying it (or its inverse) to a vector,
+//! and average the quadratic forms `z^H f(A) z` of random probe vectors `z` with `E[z z^H] = I`,
+//! whose expectation is `tr f(A)`.
+//!
+//! - M. F. Hutchinson, Commun. Stat. Simul. Comput. 19, 433 (1990)
+//! - S. Ubaru, J. Chen and Y. Saad, SIAM J. Matrix Anal. Appl. 38, 1075 (2017)
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+use rand::Rng;
+
+use crate::eigh::eigh_tridiagonal;
+use crate::error::*;
+use crate::inner::InnerProduct;
+use crate::krylov::lanczos::lanczos_tridiagonal;
+use crate::norm::Norm;
+use crate::types::*;
+
+/// Rademacher probe vector whose elements are `±1` with equal probability
+fn rademacher<A: Scalar, R: Rng>(n: usize, rng: &mut R) -> Array1<A> {
+    Array1::from_shape_fn(n, |_| {
+        if rng.gen::<bool>() {
+            A::one()
+        } else {
+            -A::one()
+        }
+    })
+}
+
+/// Mean and its standard error of the samples
+fn mean_std_error<A: Scalar>(samples: &[A]) -> (A, A::Real) {
+    let k = samples.len();
+    let mean = samples
+        .iter()
+        .fold(A::zero(), |s, &x| s + x)
+        .div_real(A::real(k as f64));
+    if k < 2 {
+        return (mean, A::Real::infinity());
+    }
+    let var = samples
+        .iter()
+        .fold(A::Real::zero(), |s, &x| s + (x - mean).square())
+        / A::real((k - 1) as f64);
+    (mean, Float::sqrt(var / A::real(k as f64)))
+}
+
+/// Estimate `tr(A^{-1})` of an `n x n` matrix by Hutchinson's estimator
+///
+/// `matvec_solve(z)` should return `A^{-1} z`, e.g. by a factorized matrix or an iterative solver,
+/// and `A^{-1}` is never formed. This averages `z^H A^{-1} z` over `num_probes` Rademacher vectors `z`,
+/// and returns the estimate and its standard error, which decreases as `1 / sqrt(num_probes)`.
+/// The standard error is infinite for a single probe.
+///
+/// Panics if `num_probes` is zero.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = Array2::from_diag(&array![1., 2., 4., 8.]);
+/// let f = a.factorize().unwrap();
+/// let mut rng = rand::thread_rng();
+/// let (tr, _) = trace_inv_estimate(|z| f.solve(z).unwrap(), 4, 10, &mut rng);
+/// // Rademacher probes are exact for a diagonal matrix
+/// assert!((tr - 1.875).abs() < 1e-12);
+/// ```
+pub fn trace_inv_estimate<A, R>(
+    matvec_solve: impl Fn(&Array1<A>) -> Array1<A>,
+    n: usize,
+    num_probes: usize,
+    rng: &mut R,
+) -> (A, A::Real)
+where
+    A: Scalar,
+    R: Rng,
+{
+    assert!(num_probes > 0, "number of probe vectors must be positive");
+    let samples: Vec<A> = (0..num_probes)
+        .map(|_| {
+            let z = rademacher(n, rng);
+            z.inner(&matvec_solve(&z))
+        })
+        .collect();
+    mean_std_error(&samples)
+}
+
+/// Estimate `log det A` of an `n x n` Hermitian positive definite matrix by stochastic Lanczos quadrature
+///
+/// `matvec(x)` should return `A x`, and `A` is never factorized.
+/// For each of `num_probes` Rademacher vectors `z`, `lanczos_steps` steps of the Lanczos iteration
+/// starting from `z` give the tridiagonal matrix `T`, whose eigenvalues `θ_k` and the squared first elements
+/// `τ_k` of its eigenvectors by [eigh_tridiagonal] are the nodes and weights of the Gauss quadrature
+/// `z^H log(A) z ≈ |z|^2 Σ_k τ_k log θ_k`.
+/// Returns the average, i.e. the estimate of `tr log A = log det A`, and its standard error.
+/// The standard error is infinite for a single probe.
+///
+/// The iteration stops earlier if the Krylov subspace becomes invariant,
+/// and the result is NaN if `A` is not positive definite.
+/// Panics if `num_probes` or `lanczos_steps` is zero.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a: Array2<f64> = array![[2., 0.], [0., 3.]];
+/// let mut rng = rand::thread_rng();
+/// let (logdet, _) = logdet_estimate(|x| a.dot(x), 2, 10, 2, &mut rng).unwrap();
+/// // Lanczos of `n` steps makes the quadrature exact, and every Rademacher probe gives
+/// // `z^T log(A) z = log det A` for a diagonal `A`
+/// assert!((logdet - 6.0_f64.ln()).abs() < 1e-9);
+/// ```
+pub fn logdet_estimate<A, R>(
+    matvec: impl Fn(&Array1<A>) -> Array1<A>,
+    n: usize,
+    num_probes: usize,
+    lanczos_steps: usize,
+    rng: &mut R,
+) -> Result<(A::Real, A::Real)>
+where
+    A: Scalar + Lapack,
+    A::Real: Lapack,
+    R: Rng,
+{
+    assert!(num_probes > 0, "number of probe vectors must be positive");
+    assert!(
+        lanczos_steps > 0,
+        "number of Lanczos steps must be positive"
+    );
+    let mut samples = Vec::with_capacity(num_probes);
+    for _ in 0..num_probes {
+        let z: Array1<A> = rademacher(n, rng);
+        let znorm = z.norm_l2();
+        let (alpha, beta, _) = lanczos_tridiagonal(&matvec, &z, lanczos_steps, |_, _| Ok(false))?;
+        let m = alpha.len();
+        let (theta, s) =
+            eigh_tridiagonal(&Array1::from(alpha), &Array1::from(beta[..m - 1].to_vec()))?;
+        let quadrature = theta
+            .iter()
+            .zip(s.row(0))
+            .fold(A::Real::zero(), |acc, (&t, &s)| acc + s * s * Float::ln(t));
+        samples.push(quadrature * znorm * znorm);
+    }
+    Ok(mean_std_error(&samples))
+}
@@ -2,8 +2,9 @@
 //!
 //! [Wikipedia article on SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition)
 
-use crate::{convert::*, error::*, layout::*, types::*};
+use crate::{convert::*, error::*, generate::conjugate, layout::*, types::*};
 use ndarray::*;
+use num_traits::{Float, Zero};
 
 /// singular-value decomposition of matrix reference
 pub trait SVD {
@@ -104,3 +105,215 @@ where
         Ok((u, s, vt))
     }
 }
+
+/// Optimal low-rank approximation using SVD
+///
+/// This computes the best rank-`k` approximation in both 2-norm and Frobenius norm
+/// (the Eckart-Young theorem) by truncating the SVD to the `k` largest singular values.
+pub trait LowRankApprox {
+    type Output;
+    type Real;
+    /// Returns the rank-`k` approximation and its error in 2-norm, i.e. the `k+1`-th singular value.
+    ///
+    /// The matrix itself and zero error are returned if `k` is not less than the size of the matrix.
+    fn low_rank_approx(&self, k: usize) -> Result<(Self::Output, Self::Real)>;
+}
+
+impl<A, S> LowRankApprox for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type Real = A::Real;
+
+    fn low_rank_approx(&self, k: usize) -> Result<(Self::Output, Self::Real)> {
+        let (u, s, vt) = self.svd(true, true)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let k = std::cmp::min(k, s.len());
+        let err = if k < s.len() { s[k] } else { A::Real::zero() };
+        let mut us = u.slice(s![.., ..k]).to_owned();
+        for (mut col, &s) in us.axis_iter_mut(Axis(1)).zip(s.iter()) {
+            col.map_inplace(|x| *x = x.mul_real(s));
+        }
+        Ok((us.dot(&vt.slice(s![..k, ..])), err))
+    }
+}
+
+/// Rank, null space, column space and condition number derived from one SVD
+///
+/// This is created by [SVDAnalyze::svd_analysis], and each subspace is sliced
+/// from the stored singular vectors on request.
+#[derive(Debug, Clone)]
+pub struct SvdAnalysis<A: Scalar> {
+    u: Array2<A>,
+    s: Array1<A::Real>,
+    vt: Array2<A>,
+    rank: usize,
+}
+
+impl<A: Scalar> SvdAnalysis<A> {
+    /// Numerical rank, i.e. the number of singular values larger than the threshold
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Singular values in descending order
+    pub fn singular_values(&self) -> ArrayView1<'_, A::Real> {
+        self.s.view()
+    }
+
+    /// Orthonormal basis of the null space `{x | Ax = 0}` as columns
+    pub fn null_space(&self) -> Array2<A> {
+        self.vt.slice(s![self.rank.., ..]).t().mapv(|x| x.conj())
+    }
+
+    /// Orthonormal basis of the column space (range) as columns
+    pub fn col_space(&self) -> ArrayView2<'_, A> {
+        self.u.slice(s![.., ..self.rank])
+    }
+
+    /// Condition number in 2-norm, see [condition_from_singular_values]
+    pub fn condition_number(&self) -> A::Real {
+        condition_from_singular_values(&self.s)
+    }
+}
+
+/// Analyze a matrix by SVD
+pub trait SVDAnalyze {
+    type Elem: Scalar;
+    /// Compute SVD once and count the singular values larger than `rcond` times the largest one.
+    ///
+    /// If `rcond` is `None`, the machine epsilon times the larger dimension of the matrix is used.
+    fn svd_analysis(
+        &self,
+        rcond: Option<<Self::Elem as Scalar>::Real>,
+    ) -> Result<SvdAnalysis<Self::Elem>>;
+}
+
+impl<A, S> SVDAnalyze for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn svd_analysis(&self, rcond: Option<A::Real>) -> Result<SvdAnalysis<A>> {
+        let (n, m) = self.dim();
+        let (u, s, vt) = self.svd(true, true)?;
+        let rcond =
+            rcond.unwrap_or_else(|| A::Real::epsilon() * A::real(std::cmp::max(n, m) as f64));
+        let tol = s.fold(A::Real::zero(), |m, &s| Float::max(m, s)) * rcond;
+        let rank = s.iter().filter(|&&s| s > tol).count();
+        Ok(SvdAnalysis {
+            u: u.unwrap(),
+            s,
+            vt: vt.unwrap(),
+            rank,
+        })
+    }
+}
+
+/// Non-trivial solution of the homogeneous system `A x = 0` created by [SolveHomogeneous]
+#[derive(Debug, Clone)]
+pub struct HomogeneousSolution<A: Scalar> {
+    /// Unit vector `x` minimizing `|A x|`, i.e. the right singular vector of the smallest singular value
+    pub solution: Array1<A>,
+    /// Smallest singular value, which is the residual `|A x|`
+    pub residual: A::Real,
+    /// Orthonormal basis of the right singular vectors of the smallest singular value as columns,
+    /// including `solution`, whose number of columns is the multiplicity of the value
+    pub basis: Array2<A>,
+}
+
+impl<A: Scalar> HomogeneousSolution<A> {
+    /// Whether the smallest singular value is simple, i.e. the solution is unique up to a phase
+    pub fn is_unique(&self) -> bool {
+        self.basis.ncols() == 1
+    }
+}
+
+/// Solve the homogeneous system `A x = 0` for the unit vector `x` in the least squares sense
+pub trait SolveHomogeneous {
+    type Elem: Scalar;
+    /// Find the unit vector `x` minimizing `|A x|` by SVD,
+    /// e.g. the direct linear transformation in computer vision.
+    ///
+    /// The smallest singular value is not unique if another singular value is within
+    /// `ε max(n, m)` times the largest one, or the matrix has fewer rows than columns.
+    /// Then any unit vector in `basis` is a solution, and `solution` is the last one of them.
+    /// Returns `IncompatibleShape` error if the matrix has no columns.
+    ///
+    /// ```
+    /// use ndarray::*;
+    /// use ndarray_linalg::*;
+    ///
+    /// // points on the line `x - 2y + 3 = 0`
+    /// let a: Array2<f64> = array![[1., 2., 1.], [3., 3., 1.], [-1., 1., 1.]];
+    /// let h = a.solve_homogeneous().unwrap();
+    /// assert!(h.is_unique());
+    /// assert!(h.residual < 1e-12);
+    /// let line = &h.solution / h.solution[0];
+    /// assert!(line.abs_diff_eq(&array![1., -2., 3.], 1e-9));
+    /// ```
+    fn solve_homogeneous(&self) -> Result<HomogeneousSolution<Self::Elem>>;
+}
+
+impl<A, S> SolveHomogeneous for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn solve_homogeneous(&self) -> Result<HomogeneousSolution<A>> {
+        let (n, m) = self.dim();
+        if m == 0 {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let (s, vt) = if n == 0 {
+            (Array1::zeros(0), Array2::eye(m))
+        } else {
+            let (_, s, vt) = self.svd(false, true)?;
+            (s, vt.unwrap())
+        };
+
+        // singular values are zero beyond the number of rows
+        let sigma = |i: usize| if i < s.len() { s[i] } else { A::Real::zero() };
+        let smin = sigma(m - 1);
+        let tol = A::real(std::cmp::max(n, m)) * A::Real::epsilon() * sigma(0);
+        let mut k = m - 1;
+        while k > 0 && sigma(k - 1) - smin <= tol {
+            k -= 1;
+        }
+        let basis: Array2<A> = conjugate(&vt.slice(s![k.., ..]));
+        Ok(HomogeneousSolution {
+            solution: basis.column(m - 1 - k).to_owned(),
+            residual: smin,
+            basis,
+        })
+    }
+}
+
+/// Condition number in 2-norm from the singular values of a matrix
+///
+/// This computes `sigma_max / sigma_min` from the singular values
+/// already computed by e.g. `SVD::svd` or `LeastSquaresSvd::least_squares`,
+/// to avoid decomposing the matrix again.
+/// Returns infinity if the smallest singular value is zero,
+/// and one for the empty matrix.
+pub fn condition_from_singular_values<R, S>(sv: &ArrayBase<S, Ix1>) -> R
+where
+    R: Scalar<Real = R> + Float,
+    S: Data<Elem = R>,
+{
+    if sv.is_empty() {
+        return R::real(1.0);
+    }
+    let max = sv.fold(R::real(0.0), |m, &s| Float::max(m, s));
+    let min = sv.fold(<R as Float>::infinity(), |m, &s| Float::min(m, s));
+    if min == R::real(0.0) {
+        return <R as Float>::infinity();
+    }
+    max / min
+}
@@ -0,0 +1,141 @@
+//! Assertions for downstream tests
+//!
+//! This module is enabled by the `testing` feature. Unlike the `assert_*!` macros,
+//! the failure message reports where the arrays differ most.
+//! [compare_matrices] returns the same diagnostics as a report instead of panicking.
+
+use ndarray::*;
+use num_traits::{Float, Zero};
+use std::fmt::Debug;
+
+use super::error::*;
+use super::types::*;
+
+/// check two arrays are close element-wise in the absolute tolerance
+///
+/// On failure, this prints the maximum absolute difference and its index.
+///
+/// # Panics
+///
+/// Panics if the shapes differ, or the absolute difference of some elements
+/// (or NaN) exceeds `atol`.
+pub fn assert_matrix_abs_diff_eq<A, S1, S2, D>(
+    test: &ArrayBase<S1, D>,
+    truth: &ArrayBase<S2, D>,
+    atol: A::Real,
+) where
+    A: Scalar,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    D::Pattern: PartialEq + Debug,
+{
+    assert_eq!(test.dim(), truth.dim(), "Shapes of arrays differ");
+    // (index, deviation, actual, expected) of the largest deviation, and NaN sticks
+    let mut worst: Option<(D::Pattern, A::Real, A, A)> = None;
+    for ((index, &t), &e) in test.indexed_iter().zip(truth.iter()) {
+        let dev = (t - e).abs();
+        let larger = match &worst {
+            None => true,
+            Some((_, max, _, _)) => !Float::is_nan(*max) && (Float::is_nan(dev) || dev > *max),
+        };
+        if larger {
+            worst = Some((index, dev, t, e));
+        }
+    }
+    if let Some((index, dev, t, e)) = worst {
+        if Float::is_nan(dev) || dev > atol {
+            eprintln!("==== Assetion Failed ====");
+            eprintln!("Expected:\n{}", truth);
+            eprintln!("Actual:\n{}", test);
+            panic!(
+                "Too large absolute deviation at {:?}: {} > {} (expected = {}, actual = {})",
+                index, dev, atol, e, t
+            );
+        }
+    }
+}
+
+/// Element-wise comparison of two arrays reported by [compare_matrices]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixComparison<R, I> {
+    /// Maximum of `|a - b|`, NaN if some element is NaN
+    pub max_abs_diff: R,
+    /// Index of `max_abs_diff`, `None` for empty arrays
+    pub max_abs_index: Option<I>,
+    /// Maximum of `|a - b| / |b|`, infinite if `a != b` where `b` is zero
+    pub max_rel_diff: R,
+    /// Index of `max_rel_diff`, `None` for empty arrays
+    pub max_rel_index: Option<I>,
+    /// Whether `|a - b| <= atol + rtol |b|` holds for all the elements
+    pub passed: bool,
+}
+
+/// Compare two arrays element-wise without panicking
+///
+/// The arrays pass if `|a - b| <= atol + rtol |b|` for all the elements,
+/// i.e. the same criterion as `numpy.allclose`, and NaN fails.
+/// The maximum absolute and relative differences are reported with their indices
+/// for diagnostics whichever the result is.
+///
+/// Returns `IncompatibleShape` error if the shapes differ.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::testing::*;
+///
+/// let a: Array2<f64> = array![[1.0, 2.0], [3.0, 4.0]];
+/// let b = array![[1.0, 2.0], [3.1, 4.0]];
+/// let report = compare_matrices(&a, &b, 1e-3, 0.0).unwrap();
+/// assert!(!report.passed);
+/// assert_eq!(report.max_abs_index, Some((1, 0)));
+/// assert!((report.max_abs_diff - 0.1).abs() < 1e-12);
+/// ```
+pub fn compare_matrices<A, S1, S2, D>(
+    a: &ArrayBase<S1, D>,
+    b: &ArrayBase<S2, D>,
+    rtol: A::Real,
+    atol: A::Real,
+) -> Result<MatrixComparison<A::Real, D::Pattern>>
+where
+    A: Scalar,
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+{
+    if a.shape() != b.shape() {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    // NaN sticks as the maximum
+    let larger = |dev: A::Real, max: &Option<(D::Pattern, A::Real)>| match max {
+        None => true,
+        Some((_, max)) => !Float::is_nan(*max) && (Float::is_nan(dev) || dev > *max),
+    };
+    let mut abs_worst = None;
+    let mut rel_worst = None;
+    let mut passed = true;
+    for ((index, &x), &y) in a.indexed_iter().zip(b.iter()) {
+        let dev = (x - y).abs();
+        let scale = y.abs();
+        let rel = if dev.is_zero() { dev } else { dev / scale };
+        if Float::is_nan(dev) || dev > atol + rtol * scale {
+            passed = false;
+        }
+        if larger(dev, &abs_worst) {
+            abs_worst = Some((index.clone(), dev));
+        }
+        if larger(rel, &rel_worst) {
+            rel_worst = Some((index, rel));
+        }
+    }
+    let zero = A::Real::zero();
+    let (max_abs_index, max_abs_diff) = abs_worst.map_or((None, zero), |(i, d)| (Some(i), d));
+    let (max_rel_index, max_rel_diff) = rel_worst.map_or((None, zero), |(i, d)| (Some(i), d));
+    Ok(MatrixComparison {
+        max_abs_diff,
+        max_abs_index,
+        max_rel_diff,
+        max_rel_index,
+        passed,
+    })
+}
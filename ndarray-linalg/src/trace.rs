@@ -3,11 +3,23 @@
 use ndarray::*;
 use std::iter::Sum;
 
+use super::eig::*;
 use super::error::*;
+use super::layout::*;
 use super::types::*;
 
+/// Trace of a square matrix, i.e. the sum of its diagonal elements
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[1.0, 2.0], [3.0, 4.0]];
+/// assert_eq!(a.trace().unwrap(), 5.0);
+/// ```
 pub trait Trace {
     type Output;
+    /// Returns `NotSquare` error if the matrix is not square
     fn trace(&self) -> Result<Self::Output>;
 }
 
@@ -29,3 +41,40 @@ where
         Ok((0..n as usize).map(|i| self[(i, i)]).sum())
     }
 }
+
+/// Trace of the `k`-th power of a square matrix without forming `A^k`
+///
+/// This is computed as the sum of the `k`-th powers of the eigenvalues by one [EigVals] call.
+/// The result is complex also for a real matrix, whose imaginary part is a rounding error.
+///
+/// ```
+/// use ndarray::*;
+/// use ndarray_linalg::*;
+///
+/// let a = array![[1.0, 2.0], [3.0, 4.0]];
+/// let t = a.trace_power(2).unwrap();
+/// assert!((t.re - a.dot(&a).trace().unwrap()).abs() < 1e-9);
+/// ```
+pub trait TracePower {
+    type Output;
+    /// Returns `NotSquare` error if the matrix is not square.
+    /// `trace_power(0)` is the dimension of the matrix.
+    fn trace_power(&self, k: u32) -> Result<Self::Output>;
+}
+
+impl<A, S> TracePower for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = A::Complex;
+
+    fn trace_power(&self, k: u32) -> Result<Self::Output> {
+        self.ensure_square()?;
+        if k == 0 {
+            return Ok(A::complex(self.nrows(), 0));
+        }
+        let e = self.eigvals()?;
+        Ok(e.iter().map(|e| e.powi(k as i32)).sum())
+    }
+}
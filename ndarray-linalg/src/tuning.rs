@@ -0,0 +1,24 @@
+//! Tuning parameters of the installed LAPACK
+//!
+//! These are useful to choose e.g. the panel width of blocked algorithms
+//! matching the LAPACK implementation, instead of guessing.
+
+use crate::error::*;
+use crate::types::*;
+
+/// Optimal block size recommended by LAPACK (`ilaenv`) for `routine` and the problem size `m` x `n`
+///
+/// `routine` is the name of the LAPACK routine without the type prefix, e.g. `"GEQRF"`;
+/// the prefix is determined by the element type `A`.
+/// If the routine does not use blocking, or is unknown to the implementation, this returns 1.
+///
+/// ```no_run
+/// use ndarray_linalg::*;
+///
+/// let nb = optimal_block_size::<f64>("GETRF", 1000, 1000).unwrap();
+/// assert!(nb >= 1);
+/// ```
+pub fn optimal_block_size<A: Scalar + Lapack>(routine: &str, m: usize, n: usize) -> Result<usize> {
+    let nb = A::optimal_block_size(routine, m as i32, n as i32)?;
+    Ok(nb as usize)
+}
@@ -0,0 +1,74 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn check<A: Scalar + Lapack>(a: &Array2<A>, kind: SolverKind) {
+    let n = a.nrows();
+    let x: Array1<A> = random(n);
+    let b = a.dot(&x);
+    assert_eq!(a.solver_kind(), kind);
+    let (y, used) = a.auto_solve(&b).unwrap();
+    assert_eq!(used, kind);
+    assert_close_l2!(&y, &x, A::real(1e-7));
+}
+
+macro_rules! impl_auto_solve {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let n = 5;
+            let a: Array2<$scalar> = random_regular(n);
+            check(
+                &a.clone().into_triangular(UPLO::Upper),
+                SolverKind::UpperTriangular,
+            );
+            check(
+                &a.clone().into_triangular(UPLO::Lower),
+                SolverKind::LowerTriangular,
+            );
+
+            let mut t: Array2<$scalar> = random((n, n));
+            for ((i, j), t) in t.indexed_iter_mut() {
+                if i > j + 1 || j > i + 1 {
+                    *t = <$scalar>::from_real(<$scalar>::real(0.0));
+                } else if i == j {
+                    *t += <$scalar>::from_real(<$scalar>::real(4.0));
+                }
+            }
+            check(&t, SolverKind::Tridiagonal);
+
+            let h: Array2<$scalar> = random_hpd(n);
+            check(&h, SolverKind::Cholesky);
+
+            check(&a, SolverKind::LU);
+        }
+    };
+}
+
+impl_auto_solve!(auto_solve_real, f64);
+impl_auto_solve!(auto_solve_complex, c64);
+
+#[test]
+fn auto_solve_indefinite() {
+    // Hermitian with positive diagonal but indefinite
+    let a = array![[1.0, 2.0, 0.5], [2.0, 1.0, 0.5], [0.5, 0.5, 1.0]];
+    let x = array![1.0, -1.0, 2.0];
+    assert_eq!(a.solver_kind(), SolverKind::Cholesky);
+    let (y, used) = a.auto_solve(&a.dot(&x)).unwrap();
+    assert_eq!(used, SolverKind::BunchKaufman);
+    assert_close_l2!(&y, &x, 1e-9);
+
+    let b = array![[-1.0, 2.0, 0.5], [2.0, 1.0, 0.5], [0.5, 0.5, 1.0]];
+    assert_eq!(b.solver_kind(), SolverKind::BunchKaufman);
+    let (y, _) = b.auto_solve(&b.dot(&x)).unwrap();
+    assert_close_l2!(&y, &x, 1e-9);
+}
+
+#[test]
+fn properties_hermitian() {
+    let a: Array2<c64> = random_hermite(4);
+    assert!(a.is_hermitian());
+    let mut b = a.clone();
+    b[(0, 1)] = b[(1, 0)];
+    assert!(!b.is_hermitian());
+    assert!(!Array2::<f64>::ones((2, 3)).is_hermitian());
+}
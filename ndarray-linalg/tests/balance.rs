@@ -0,0 +1,47 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn badly_scaled<A: Scalar>(a: &Array2<A>) -> Array2<A> {
+    let n = a.nrows();
+    // D A D^-1 with D = diag(1, 2^10, 2^20, ...)
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        a[(i, j)].mul_real(A::real(2.0f64.powi(10 * i as i32 - 10 * j as i32)))
+    })
+}
+
+macro_rules! impl_balance {
+    ($name:ident, $scalar:ty, $f:expr) => {
+        #[test]
+        fn $name() {
+            let n = 4;
+            let a: Array2<$scalar> = badly_scaled(&random((n, n)));
+            let a = if $f {
+                let mut af = Array2::zeros((n, n).f());
+                af.assign(&a);
+                af
+            } else {
+                a
+            };
+            let (b, d) = a.balance().unwrap();
+            // B = D^-1 A D
+            let truth = Array2::from_shape_fn((n, n), |(i, j)| a[(i, j)].mul_real(d[j] / d[i]));
+            assert_close_l2!(&b, &truth, 1e-12);
+            // better scaled
+            assert!(b.opnorm_one().unwrap() < a.opnorm_one().unwrap());
+        }
+    };
+}
+
+impl_balance!(balance_real, f64, false);
+impl_balance!(balance_real_t, f64, true);
+impl_balance!(balance_complex, c64, false);
+impl_balance!(balance_complex_t, c64, true);
+
+#[test]
+fn balance_nonsquare() {
+    let a: Array2<f64> = random((3, 2));
+    assert!(matches!(
+        a.balance(),
+        Err(error::LinalgError::NotSquare { rows: 3, cols: 2 })
+    ));
+}
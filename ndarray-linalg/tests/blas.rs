@@ -0,0 +1,433 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_gemm<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, c: &Array2<T>, rtol: T::Real) {
+    let alpha = T::from_real(T::real(1.5));
+    let beta = T::from_real(T::real(-0.5));
+    let expected = a.dot(b).mapv(|x| x * alpha) + c.mapv(|x| x * beta);
+    let mut c = c.clone();
+    gemm(alpha, a, b, beta, &mut c).unwrap();
+    assert_close_l2!(&c, &expected, rtol);
+}
+
+macro_rules! impl_gemm {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gemm_ $scalar>]() {
+                let a: Array2<$scalar> = random((3, 4));
+                let b: Array2<$scalar> = random((4, 2));
+                let c: Array2<$scalar> = random((3, 2));
+                test_gemm(&a, &b, &c, $rtol);
+            }
+
+            #[test]
+            fn [<gemm_ $scalar _mixed_layout>]() {
+                let a: Array2<$scalar> = random((3, 4).f());
+                let b: Array2<$scalar> = random((4, 2));
+                let c: Array2<$scalar> = random((3, 2).f());
+                test_gemm(&a, &b, &c, $rtol);
+                let a: Array2<$scalar> = random((3, 4));
+                let b: Array2<$scalar> = random((4, 2).f());
+                let c: Array2<$scalar> = random((3, 2));
+                test_gemm(&a, &b, &c, $rtol);
+            }
+
+            #[test]
+            fn [<gemm_ $scalar _transpose>]() {
+                let a: Array2<$scalar> = random((4, 3));
+                let b: Array2<$scalar> = random((4, 2));
+                let c: Array2<$scalar> = random((3, 2));
+                test_gemm(&a.t().to_owned(), &b, &c, $rtol);
+
+                let alpha = <$scalar>::from_real(<$scalar>::real(1.5));
+                let beta = <$scalar>::from_real(<$scalar>::real(-0.5));
+                let expected = a.t().dot(&b).mapv(|x| x * alpha) + c.mapv(|x| x * beta);
+                let mut c = c.clone();
+                gemm(alpha, &a.t(), &b, beta, &mut c).unwrap();
+                assert_close_l2!(&c, &expected, $rtol);
+            }
+        }
+    };
+}
+
+impl_gemm!(f64, 1e-9);
+impl_gemm!(f32, 1e-5);
+impl_gemm!(c64, 1e-9);
+impl_gemm!(c32, 1e-5);
+
+#[test]
+fn gemm_shape_mismatch() {
+    let a: Array2<f64> = random((3, 4));
+    let b: Array2<f64> = random((3, 2));
+    let mut c: Array2<f64> = random((3, 2));
+    assert!(gemm(1.0, &a, &b, 0.0, &mut c).is_err());
+    let b: Array2<f64> = random((4, 2));
+    let mut c: Array2<f64> = random((2, 3));
+    assert!(gemm(1.0, &a, &b, 0.0, &mut c).is_err());
+}
+
+#[test]
+fn gemm_non_contiguous() {
+    let a: Array2<f64> = random((3, 8));
+    let b: Array2<f64> = random((4, 2));
+    let mut c: Array2<f64> = random((3, 2));
+    assert!(gemm(1.0, &a.slice(s![.., ..;2]), &b, 0.0, &mut c).is_err());
+}
+
+#[test]
+fn gemm_empty() {
+    let a: Array2<f64> = Array2::zeros((3, 0));
+    let b: Array2<f64> = Array2::zeros((0, 2));
+    let mut c: Array2<f64> = Array2::ones((3, 2));
+    gemm(1.0, &a, &b, 2.0, &mut c).unwrap();
+    assert_eq!(c, Array2::from_elem((3, 2), 2.0));
+    gemm(1.0, &a, &b, 0.0, &mut c).unwrap();
+    assert_eq!(c, Array2::zeros((3, 2)));
+}
+
+fn test_syrk<T: Scalar + Lapack>(a: &Array2<T>, c: &Array2<T>, uplo: UPLO, rtol: T::Real) {
+    let alpha = T::real(1.5);
+    let beta = T::real(-0.5);
+    let ah: Array2<T> = conjugate(a);
+    let full = a.dot(&ah).mapv(|x| x * T::from_real(alpha)) + c.mapv(|x| x * T::from_real(beta));
+    let mut result = c.clone();
+    syrk(alpha, a, beta, &mut result, uplo).unwrap();
+    // other triangle is untouched
+    let mut expected = c.clone();
+    for ((i, j), x) in expected.indexed_iter_mut() {
+        match uplo {
+            UPLO::Upper if i <= j => *x = full[(i, j)],
+            UPLO::Lower if i >= j => *x = full[(i, j)],
+            _ => {}
+        }
+    }
+    assert_close_l2!(&result, &expected, rtol);
+}
+
+macro_rules! impl_syrk {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<syrk_ $scalar>]() {
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let a: Array2<$scalar> = random((3, 5));
+                    let c: Array2<$scalar> = random_hermite(3);
+                    test_syrk(&a, &c, uplo, $rtol);
+
+                    let a: Array2<$scalar> = random((3, 5).f());
+                    test_syrk(&a, &c, uplo, $rtol);
+
+                    let c: Array2<$scalar> = replicate(&c.t()).reversed_axes();
+                    test_syrk(&a, &c, uplo, $rtol);
+
+                    let a: Array2<$scalar> = random((3, 5));
+                    test_syrk(&a, &c, uplo, $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_syrk!(f64, 1e-9);
+impl_syrk!(f32, 1e-5);
+impl_syrk!(c64, 1e-9);
+impl_syrk!(c32, 1e-5);
+
+#[test]
+fn syrk_shape_mismatch() {
+    let a: Array2<f64> = random((3, 4));
+    let mut c: Array2<f64> = random((3, 2));
+    assert!(syrk(1.0, &a, 0.0, &mut c, UPLO::Upper).is_err());
+    let mut c: Array2<f64> = random((4, 4));
+    assert!(syrk(1.0, &a, 0.0, &mut c, UPLO::Upper).is_err());
+}
+
+fn test_tri_dot<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, side: Side, rtol: T::Real) {
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        for &trans in &[Transpose::No, Transpose::Transpose, Transpose::Hermite] {
+            for &diag in &[Diag::NonUnit, Diag::Unit] {
+                let mut tri = a.clone();
+                for ((i, j), x) in tri.indexed_iter_mut() {
+                    let outside = match uplo {
+                        UPLO::Upper => i > j,
+                        UPLO::Lower => i < j,
+                    };
+                    if outside {
+                        *x = T::zero();
+                    }
+                    if i == j && matches!(diag, Diag::Unit) {
+                        *x = T::one();
+                    }
+                }
+                let op: Array2<T> = match trans {
+                    Transpose::No => tri,
+                    Transpose::Transpose => tri.t().to_owned(),
+                    Transpose::Hermite => conjugate(&tri),
+                };
+                let expected = match side {
+                    Side::Left => op.dot(b),
+                    Side::Right => b.dot(&op),
+                };
+                let mut result = b.clone();
+                a.tri_dot(&mut result, side, uplo, trans, diag).unwrap();
+                assert_close_l2!(&result, &expected, rtol);
+            }
+        }
+    }
+}
+
+macro_rules! impl_tri_dot {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<tri_dot_ $scalar>]() {
+                let a: Array2<$scalar> = random((3, 3));
+                let b: Array2<$scalar> = random((3, 4));
+                test_tri_dot(&a, &b, Side::Left, $rtol);
+                let b: Array2<$scalar> = random((4, 3));
+                test_tri_dot(&a, &b, Side::Right, $rtol);
+            }
+
+            #[test]
+            fn [<tri_dot_ $scalar _mixed_layout>]() {
+                let a: Array2<$scalar> = random((3, 3).f());
+                let b: Array2<$scalar> = random((3, 4));
+                test_tri_dot(&a, &b, Side::Left, $rtol);
+                let b: Array2<$scalar> = random((4, 3));
+                test_tri_dot(&a, &b, Side::Right, $rtol);
+                let b: Array2<$scalar> = random((3, 4).f());
+                test_tri_dot(&a, &b, Side::Left, $rtol);
+            }
+        }
+    };
+}
+
+impl_tri_dot!(f64, 1e-9);
+impl_tri_dot!(f32, 1e-5);
+impl_tri_dot!(c64, 1e-9);
+impl_tri_dot!(c32, 1e-5);
+
+#[test]
+fn tri_dot_shape_mismatch() {
+    let a: Array2<f64> = random((3, 3));
+    let mut b: Array2<f64> = random((4, 3));
+    assert!(a
+        .tri_dot(
+            &mut b,
+            Side::Left,
+            UPLO::Upper,
+            Transpose::No,
+            Diag::NonUnit
+        )
+        .is_err());
+    let a: Array2<f64> = random((3, 4));
+    assert!(a
+        .tri_dot(
+            &mut b,
+            Side::Right,
+            UPLO::Upper,
+            Transpose::No,
+            Diag::NonUnit
+        )
+        .is_err());
+}
+
+#[test]
+fn iamax() {
+    let v: Array1<f64> = array![1.0, -5.0, 3.0, 5.0];
+    assert_eq!(v.iamax(), Some(1));
+    assert_eq!(v.slice(s![..;-1]).iamax(), Some(0));
+    assert_eq!(v.slice(s![2..;1]).iamax(), Some(1));
+    assert_eq!(Array1::<f64>::zeros(0).iamax(), None);
+    // |re| + |im| is used as BLAS does
+    let c: Array1<c64> = array![c64::new(3.0, 3.0), c64::new(0.0, 5.0)];
+    assert_eq!(c.iamax(), Some(0));
+}
+
+#[test]
+fn iamax_axis() {
+    let a: Array2<f64> = array![[1.0, -7.0, 0.0], [-4.0, 2.0, 1.0]];
+    assert_eq!(a.iamax_axis(Axis(0)).unwrap(), array![1, 0, 1]);
+    assert_eq!(a.iamax_axis(Axis(1)).unwrap(), array![1, 0]);
+    // strided columns of a row-major matrix
+    assert_eq!(a.column(1).iamax(), Some(0));
+    // the first of equal magnitudes is selected, as BLAS does
+    let b: Array2<c64> = array![
+        [c64::new(1.0, 0.0), c64::new(0.0, 0.0)],
+        [c64::new(0.0, -1.0), c64::new(0.0, 0.0)]
+    ];
+    assert_eq!(b.column(0).iamax(), Some(0));
+    assert_eq!(b.column(1).iamax(), Some(0));
+    assert_eq!(a.t().iamax_axis(Axis(1)).unwrap(), array![1, 0, 1]);
+    assert!(Array2::<f64>::zeros((0, 3)).iamax_axis(Axis(0)).is_none());
+}
+
+fn outer<T: Scalar>(x: &Array1<T>, y: &Array1<T>) -> Array2<T> {
+    Array2::from_shape_fn((x.len(), y.len()), |(i, j)| x[i] * y[j])
+}
+
+macro_rules! impl_ger {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<ger_ $scalar>]() {
+                let alpha: $scalar = random::<$scalar, OwnedRepr<_>, _, _>(1)[0];
+                let x: Array1<$scalar> = random(3);
+                let y: Array1<$scalar> = random(4);
+                let yc = y.mapv(|v| v.conj());
+                let cs: Vec<Array2<$scalar>> = vec![random((3, 4)), random((3, 4).f())];
+                for a in cs {
+                    let mut c = a.clone();
+                    ger(alpha, &x, &y, &mut c).unwrap();
+                    let expected: Array2<$scalar> = &a + &outer(&x, &y).mapv(|v| v * alpha);
+                    assert_close_l2!(&c, &expected, $rtol);
+                    let mut c = a.clone();
+                    gerc(alpha, &x, &y, &mut c).unwrap();
+                    let expected: Array2<$scalar> = &a + &outer(&x, &yc).mapv(|v| v * alpha);
+                    assert_close_l2!(&c, &expected, $rtol);
+                }
+                // strided and reversed vectors
+                let big: Array2<$scalar> = random((4, 5));
+                let (xs, ys) = (big.slice(s![..3, 0]), big.slice(s![0, 1..;-1]));
+                let mut c = Array2::zeros((3, 4));
+                ger(alpha, &xs, &ys, &mut c).unwrap();
+                let expected: Array2<$scalar> = outer(&xs.to_owned(), &ys.to_owned()).mapv(|v| v * alpha);
+                assert_close_l2!(&c, &expected, $rtol);
+            }
+        }
+    };
+}
+
+impl_ger!(f64, 1e-9);
+impl_ger!(f32, 1e-4);
+impl_ger!(c64, 1e-9);
+impl_ger!(c32, 1e-4);
+
+#[test]
+fn ger_shape_mismatch() {
+    let x: Array1<f64> = random(3);
+    let y: Array1<f64> = random(4);
+    let mut a: Array2<f64> = random((4, 3));
+    assert!(ger(1.0, &x, &y, &mut a).is_err());
+}
+
+macro_rules! impl_axpy {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<axpy_scal_ $scalar>]() {
+                let alpha: $scalar = random::<$scalar, OwnedRepr<_>, _, _>(1)[0];
+                let x: Array1<$scalar> = random(4);
+                let y: Array1<$scalar> = random(4);
+                let mut z = y.clone();
+                axpy(alpha, &x, &mut z).unwrap();
+                let expected: Array1<$scalar> = &y + &x.mapv(|v| v * alpha);
+                assert_close_l2!(&z, &expected, $rtol);
+                scal(alpha, &mut z);
+                let expected = expected.mapv(|v| v * alpha);
+                assert_close_l2!(&z, &expected, $rtol);
+
+                // strided and reversed views are updated in place
+                let mut a: Array2<$scalar> = random((4, 3));
+                let a0 = a.clone();
+                axpy(alpha, &x.slice(s![..;-1]), &mut a.column_mut(1)).unwrap();
+                let expected: Array1<$scalar> = &a0.column(1) + &x.slice(s![..;-1]).mapv(|v| v * alpha);
+                assert_close_l2!(&a.column(1), &expected, $rtol);
+                scal(alpha, &mut a.slice_mut(s![..;-1, 2]));
+                let expected: Array1<$scalar> = a0.column(2).mapv(|v| v * alpha);
+                assert_close_l2!(&a.column(2), &expected, $rtol);
+                assert_eq!(a.column(0), a0.column(0));
+            }
+        }
+    };
+}
+
+impl_axpy!(f64, 1e-9);
+impl_axpy!(f32, 1e-4);
+impl_axpy!(c64, 1e-9);
+impl_axpy!(c32, 1e-4);
+
+#[test]
+fn axpy_columns_of_same_matrix() {
+    let mut a: Array2<f64> = random((4, 3));
+    let a0 = a.clone();
+    let (x, mut y) = a.multi_slice_mut((s![.., 0], s![.., 2]));
+    axpy(2.0, &x, &mut y).unwrap();
+    let expected: Array1<f64> = &a0.column(2) + &a0.column(0).mapv(|v| 2.0 * v);
+    assert_close_l2!(&a.column(2), &expected, 1e-9);
+    assert_eq!(a.slice(s![.., ..2]), a0.slice(s![.., ..2]));
+}
+
+#[test]
+fn axpy_shape_mismatch() {
+    let x: Array1<f64> = random(3);
+    let mut y: Array1<f64> = random(4);
+    assert!(axpy(1.0, &x, &mut y).is_err());
+}
+
+macro_rules! impl_blas_dot {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gemv_ $scalar>]() {
+                let a: Array2<$scalar> = random((3, 4));
+                let x: Array1<$scalar> = random(4);
+                let y: Array1<$scalar> = random(3);
+                let alpha = <$scalar>::from_real(<$scalar>::real(1.5));
+                let beta = <$scalar>::from_real(<$scalar>::real(-0.5));
+                let expected = a.dot(&x).mapv(|v| v * alpha) + y.mapv(|v| v * beta);
+                let mut y1 = y.clone();
+                gemv(alpha, &a, &x, beta, &mut y1).unwrap();
+                assert_close_l2!(&y1, &expected, $rtol);
+
+                // F layout, and a strided vector
+                let af = a.t().to_owned().reversed_axes();
+                let mut yy: Array2<$scalar> = Array2::zeros((3, 2));
+                yy.column_mut(1).assign(&y);
+                gemv(alpha, &af, &x, beta, &mut yy.column_mut(1)).unwrap();
+                assert_close_l2!(&yy.column(1).to_owned(), &expected, $rtol);
+            }
+
+            #[test]
+            fn [<blas_dot_ $scalar>]() {
+                let a: Array2<$scalar> = random((3, 4));
+                let b: Array2<$scalar> = random((4, 2).f());
+                let x: Array1<$scalar> = random(4);
+                let y: Array1<$scalar> = random(4);
+                let z: Array1<$scalar> = random(3);
+
+                assert_rclose!(x.blas_dot(&y).unwrap(), x.dot(&y), $rtol);
+                let xh = x.mapv(|v| v.conj());
+                assert_rclose!(dotc(&x, &y).unwrap(), xh.dot(&y), $rtol);
+                assert_rclose!(dotu(&x, &y).unwrap(), x.dot(&y), $rtol);
+                assert_close_l2!(&a.blas_dot(&x).unwrap(), &a.dot(&x), $rtol);
+                assert_close_l2!(&z.blas_dot(&a).unwrap(), &z.dot(&a), $rtol);
+                assert_close_l2!(&a.blas_dot(&b).unwrap(), &a.dot(&b), $rtol);
+
+                // non-contiguous operands are copied
+                let s = a.slice(s![.., ..2]);
+                assert_close_l2!(&s.blas_dot(&b.slice(s![..2, ..])).unwrap(), &s.dot(&b.slice(s![..2, ..])), $rtol);
+                assert_rclose!(x.slice(s![..;2]).blas_dot(&y.slice(s![..;-2])).unwrap(), x.slice(s![..;2]).dot(&y.slice(s![..;-2])), $rtol);
+            }
+        }
+    };
+}
+
+impl_blas_dot!(f64, 1e-9);
+impl_blas_dot!(f32, 1e-5);
+impl_blas_dot!(c64, 1e-9);
+impl_blas_dot!(c32, 1e-5);
+
+#[test]
+fn blas_dot_shape_mismatch() {
+    let a: Array2<f64> = Array2::zeros((3, 4));
+    let x: Array1<f64> = Array1::zeros(3);
+    assert!(a.blas_dot(&x).is_err());
+    assert!(x.blas_dot(&Array1::<f64>::zeros(4)).is_err());
+    assert!(a.blas_dot(&a).is_err());
+    assert_eq!(
+        Array1::<f64>::zeros(0).blas_dot(&Array1::zeros(0)).unwrap(),
+        0.0
+    );
+}
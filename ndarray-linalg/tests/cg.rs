@@ -0,0 +1,61 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test_cg<T: Scalar + Lapack>(rtol: T::Real) {
+    let n = 10;
+    let a: Array2<T> = random_hpd(n);
+    let x: Array1<T> = random(n);
+    let b = a.dot(&x);
+    let result = cg(a.view(), &b, rtol, 1000);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, T::real(1e4) * rtol);
+    assert_close_l2!(&a.dot(&result.solution), &b, T::real(10.0) * rtol);
+}
+
+#[test]
+fn cg_real() {
+    test_cg::<f64>(1e-10);
+}
+
+#[test]
+fn cg_complex() {
+    test_cg::<c64>(1e-10);
+}
+
+#[test]
+fn cg_preconditioned_scaled() {
+    // Badly scaled HPD matrix whose diagonal is a good preconditioner
+    let n = 30;
+    let s = Array1::from_shape_fn(n, |i| 10f64.powi(i as i32 % 6));
+    let c: Array2<f64> = random_hpd(n);
+    let mut a = c.mapv(|x| 0.01 * x);
+    for i in 0..n {
+        a[(i, i)] += n as f64;
+    }
+    let a = Array2::from_shape_fn((n, n), |(i, j)| s[i].sqrt() * a[(i, j)] * s[j].sqrt());
+    let b: Array1<f64> = random(n);
+    let d = a.diag().to_owned();
+
+    let plain = cg(a.view(), &b, 1e-10, 1000);
+    let pre = cg_preconditioned(a.view(), &b, |r| r / &d, 1e-10, 1000);
+    assert!(pre.converged);
+    assert_close_l2!(&a.dot(&pre.solution), &b, 1e-9);
+    assert!(pre.residual_norms.len() < plain.residual_norms.len());
+}
+
+#[test]
+fn cg_not_converged() {
+    let a: Array2<f64> = random_hpd(20);
+    let b: Array1<f64> = random(20);
+    let result = cg(a.view(), &b, 1e-14, 2);
+    assert!(!result.converged);
+    assert_eq!(result.residual_norms.len(), 3);
+}
+
+#[test]
+fn cg_zero_rhs() {
+    let a: Array2<f64> = random_hpd(4);
+    let result = cg(a.view(), &Array1::zeros(4), 1e-10, 10);
+    assert!(result.converged);
+    assert_eq!(result.solution, Array1::zeros(4));
+}
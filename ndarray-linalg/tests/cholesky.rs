@@ -226,3 +226,138 @@ cholesky_solve!(f64, 1e-9);
 cholesky_solve!(f32, 1e-3);
 cholesky_solve!(c64, 1e-9);
 cholesky_solve!(c32, 1e-3);
+
+/// Hermitian positive definite matrix `A = L * L^H` with known `L`.
+/// `A` is not symmetric, so treating it as `L * L^T` would give a wrong factor.
+#[test]
+fn cholesky_hermitian_fixed() {
+    let i = c64::new(0., 1.);
+    let a_orig: Array2<c64> = array![
+        [c64::new(4., 0.), c64::new(2., -2.)],
+        [c64::new(2., 2.), c64::new(3., 0.)]
+    ];
+    let lower: Array2<c64> = array![
+        [c64::new(2., 0.), c64::new(0., 0.)],
+        [c64::new(1., 1.), c64::new(1., 0.)]
+    ];
+    let upper = lower.t().mapv(|elem| elem.conj());
+    let x = array![c64::new(1., 0.), i];
+    let b = array![c64::new(6., 2.), c64::new(2., 5.)];
+
+    // standard (C) layout, and Fortran layout
+    for a in &[a_orig.clone(), replicate(&a_orig.t()).reversed_axes()] {
+        assert_close_l2!(&a.cholesky(UPLO::Lower).unwrap(), &lower, 1e-9);
+        assert_close_l2!(&a.cholesky(UPLO::Upper).unwrap(), &upper, 1e-9);
+        assert_rclose!(a.detc().unwrap(), 4.0, 1e-9);
+        assert_close_l2!(&a.solvec(&b).unwrap(), &x, 1e-9);
+        assert_close_l2!(
+            &a.factorizec(UPLO::Upper).unwrap().solvec(&b).unwrap(),
+            &x,
+            1e-9
+        );
+        assert_close_l2!(
+            &a.factorizec(UPLO::Lower).unwrap().solvec(&b).unwrap(),
+            &x,
+            1e-9
+        );
+    }
+}
+
+#[test]
+fn cholesky_nonsquare() {
+    let a: Array2<f64> = random((2, 3));
+    assert!(matches!(
+        a.cholesky(UPLO::Upper),
+        Err(error::LinalgError::NotSquare { rows: 2, cols: 3 })
+    ));
+    assert!(matches!(
+        a.factorizec(UPLO::Lower),
+        Err(error::LinalgError::NotSquare { rows: 2, cols: 3 })
+    ));
+    assert!(matches!(
+        a.clone().cholesky_inplace(UPLO::Upper),
+        Err(error::LinalgError::NotSquare { rows: 2, cols: 3 })
+    ));
+}
+
+macro_rules! cholesky_solve_factor {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_solve_lower_upper_ $elem>]() {
+                let a: Array2<$elem> = random_hpd(3);
+                let b: Array1<$elem> = random(3);
+                let lower = a.cholesky(UPLO::Lower).unwrap();
+                let upper = a.cholesky(UPLO::Upper).unwrap();
+                for uplo in &[UPLO::Lower, UPLO::Upper] {
+                    let f = a.factorizec(*uplo).unwrap();
+                    let y = f.solve_lower(&b).unwrap();
+                    assert_close_l2!(&lower.dot(&y), &b, $rtol);
+                    let x = f.solve_upper(&b).unwrap();
+                    assert_close_l2!(&upper.dot(&x), &b, $rtol);
+                    let x = f.solve_upper(&f.solve_lower(&b).unwrap()).unwrap();
+                    assert_close_l2!(&x, &a.solvec(&b).unwrap(), $rtol);
+                }
+            }
+        }
+    };
+}
+cholesky_solve_factor!(f64, 1e-9);
+cholesky_solve_factor!(f32, 1e-3);
+cholesky_solve_factor!(c64, 1e-9);
+cholesky_solve_factor!(c32, 1e-3);
+
+macro_rules! cholesky_rcond {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_rcond_ $elem>]() {
+                let n = 4;
+                let a: Array2<$elem> = random_hpd(n);
+                let truth = 1.0 / (a.opnorm_one().unwrap() * a.invc().unwrap().opnorm_one().unwrap());
+                for uplo in &[UPLO::Lower, UPLO::Upper] {
+                    for a in &[a.clone(), a.t().to_owned()] {
+                        let rcond = a.factorizec(*uplo).unwrap().rcond().unwrap();
+                        // The estimate of `|A^{-1}|_1` never exceeds the true value
+                        assert!(rcond >= truth * (1.0 - $rtol));
+                        assert!(rcond <= 3.0 * truth);
+                    }
+                }
+            }
+        }
+    };
+}
+cholesky_rcond!(f64, 1e-9);
+cholesky_rcond!(f32, 1e-3);
+cholesky_rcond!(c64, 1e-9);
+cholesky_rcond!(c32, 1e-3);
+
+#[test]
+fn cholesky_rcond_triangle() {
+    // Only the `uplo` triangle is referenced, also for the 1-norm
+    let a = array![[1.0, 0.0], [0.0, 100.0]];
+    let mut upper = a.clone();
+    upper[(1, 0)] = f64::NAN;
+    let rcond = upper.factorizec(UPLO::Upper).unwrap().rcond().unwrap();
+    assert!((rcond - 0.01).abs() < 1e-12);
+    let mut lower = a;
+    lower[(0, 1)] = f64::NAN;
+    let rcond = lower.factorizec_into(UPLO::Lower).unwrap().rcond().unwrap();
+    assert!((rcond - 0.01).abs() < 1e-12);
+}
+
+#[test]
+fn cholesky_rcond_struct_literal() {
+    // The factorization can be built from a factor computed elsewhere
+    let a = array![[4.0, 2.0], [2.0, 5.0]];
+    let f = CholeskyFactorized {
+        factor: a.cholesky(UPLO::Lower).unwrap(),
+        uplo: UPLO::Lower,
+    };
+    let truth = 1.0 / (a.opnorm_one().unwrap() * a.invc().unwrap().opnorm_one().unwrap());
+    let rcond: f64 = f.rcond().unwrap();
+    assert!(rcond >= truth * (1.0 - 1e-9));
+    assert!(rcond <= 3.0 * truth);
+    let with_norm: f64 = f.rcond_with_norm(a.opnorm_one().unwrap()).unwrap();
+    assert!((with_norm - rcond).abs() < 1e-12);
+}
@@ -0,0 +1,20 @@
+//! Helpers shared by the integration tests
+
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Hermitian matrix `a` whose `uplo` triangle is kept and the other is broken,
+/// in the same layout as `a`
+pub fn half_filled(a: &Array2<c64>, uplo: UPLO) -> Array2<c64> {
+    let mut b = a.clone();
+    let n = a.nrows();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            match uplo {
+                UPLO::Upper => b[(j, i)] = c64::new(100.0, 100.0),
+                UPLO::Lower => b[(i, j)] = c64::new(100.0, 100.0),
+            }
+        }
+    }
+    b
+}
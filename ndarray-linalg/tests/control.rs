@@ -0,0 +1,75 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Random stable matrix, whose eigenvalues are shifted to the left half-plane
+fn random_stable<A: Scalar + Lapack>(n: usize) -> Array2<A> {
+    let a: Array2<A> = random((n, n));
+    let shift = A::from_real(A::real(n as f64));
+    a - &Array2::from_diag(&Array1::from_elem(n, shift))
+}
+
+fn hermite<A: Scalar>(a: &Array2<A>) -> Array2<A> {
+    a.t().mapv(|x| x.conj())
+}
+
+macro_rules! impl_control {
+    ($name:ident, $scalar:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            let n = 5;
+            let a: Array2<$scalar> = random_stable(n);
+            let b: Array2<$scalar> = random((n, 2));
+            let c: Array2<$scalar> = random((3, n));
+
+            let q: Array2<$scalar> = random((n, n));
+            let x = solve_lyapunov(&a, &q).unwrap();
+            assert_close_l2!(&(a.dot(&x) + x.dot(&hermite(&a))), &q, $rtol);
+            // C layout and F layout give the same solution
+            let mut af: Array2<$scalar> = Array2::zeros(a.dim().f());
+            af.assign(&a);
+            assert_close_l2!(&solve_lyapunov(&af, &q).unwrap(), &x, $rtol);
+
+            let w = controllability_gramian(&a, &b).unwrap();
+            let res = a.dot(&w) + w.dot(&hermite(&a));
+            assert_close_l2!(&res, &-b.dot(&hermite(&b)), $rtol);
+            assert_eq!(w, hermite(&w));
+            assert!(w
+                .eigvalsh(UPLO::Upper)
+                .unwrap()
+                .iter()
+                .all(|&e| e >= -$rtol));
+
+            let w = observability_gramian(&a, &c).unwrap();
+            let res = hermite(&a).dot(&w) + w.dot(&a);
+            assert_close_l2!(&res, &-hermite(&c).dot(&c), $rtol);
+            assert_eq!(w, hermite(&w));
+        }
+    };
+}
+
+impl_control!(control_f64, f64, 1e-9);
+impl_control!(control_c64, c64, 1e-9);
+impl_control!(control_f32, f32, 1e-3);
+
+#[test]
+fn gramian_not_stable() {
+    let a: Array2<f64> = array![[1., 0.], [0., -2.]];
+    let b: Array2<f64> = array![[1.], [1.]];
+    assert!(matches!(
+        controllability_gramian(&a, &b),
+        Err(error::LinalgError::NotStable)
+    ));
+    // eigenvalues on the imaginary axis, for which the Lyapunov equation is singular
+    for a in [array![[0., 1.], [-1., 0.]], array![[0., 0.], [0., -2.]]] {
+        assert!(matches!(
+            controllability_gramian(&a, &b),
+            Err(error::LinalgError::NotStable)
+        ));
+        assert!(matches!(
+            observability_gramian(&a, &b.t()),
+            Err(error::LinalgError::NotStable)
+        ));
+    }
+    assert!(controllability_gramian(&a, &Array2::zeros((3, 1))).is_err());
+    assert!(observability_gramian(&a, &Array2::zeros((1, 3))).is_err());
+}
@@ -8,3 +8,26 @@ fn generalize() {
     let a: Array3<f64> = convert::generalize(a);
     assert_eq!(a, ans);
 }
+
+#[test]
+fn adjoint() {
+    let a: Array2<c64> = random((3, 2));
+    let ah = a.adjoint();
+    assert_eq!(ah.dim(), (2, 3));
+    for ((i, j), &x) in ah.indexed_iter() {
+        assert_eq!(x, a[(j, i)].conj());
+    }
+    assert_eq!(a.view().adjoint(), ah);
+    assert_eq!(a.slice(s![..;2, ..]).adjoint(), ah.slice(s![.., ..;2]));
+
+    let b = a.clone().adjoint_into();
+    assert_eq!(b, ah);
+    assert!(b.t().is_standard_layout());
+    let mut c = a.clone();
+    assert_eq!(c.view_mut().adjoint_into(), ah);
+
+    // real matrix is just transposed
+    let a: Array2<f64> = random((2, 4).f());
+    assert_eq!(a.adjoint(), a.t());
+    assert_eq!(a.clone().adjoint_into(), a.t());
+}
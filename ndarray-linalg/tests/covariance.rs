@@ -0,0 +1,106 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! impl_covariance {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cov_to_corr_ $scalar>]() {
+                let n = 5;
+                let cov: Array2<$scalar> = random_hpd(n);
+                let corr = cov.cov_to_corr().unwrap();
+                for i in 0..n {
+                    assert_eq!(corr[(i, i)], <$scalar>::from_real(1.0));
+                    for j in 0..n {
+                        let s = (cov[(i, i)].re() * cov[(j, j)].re()).sqrt();
+                        assert_rclose!(corr[(i, j)].abs(), cov[(i, j)].abs() / s, $rtol);
+                        assert!(corr[(i, j)].abs() <= 1.0 + $rtol);
+                    }
+                }
+            }
+
+            #[test]
+            fn [<whitening_ $scalar>]() {
+                let n = 5;
+                let cov: Array2<$scalar> = random_hpd(n);
+                let w = cov.whitening(UPLO::Lower).unwrap();
+                assert_close_l2!(&w.correlation, &cov.cov_to_corr().unwrap(), $rtol);
+                let eye = Array2::<$scalar>::eye(n);
+                for m in &[w.zca_cor(), w.pca_cor()] {
+                    assert_close_l2!(&m.dot(&cov).dot(&m.adjoint()), &eye, $rtol);
+                }
+                // ZCA-cor whitening is symmetric in the correlation scale
+                let d = Array2::from_diag(&w.std_dev.mapv(<$scalar>::from_real));
+                let zd = w.zca_cor().dot(&d);
+                assert_close_l2!(&zd, &zd.adjoint(), $rtol);
+            }
+        }
+    };
+}
+
+impl_covariance!(f64, 1e-9);
+impl_covariance!(c64, 1e-9);
+impl_covariance!(f32, 1e-3);
+
+#[test]
+fn cov_to_corr_not_positive() {
+    let cov = array![[1.0, 0.0], [0.0, 0.0]];
+    assert!(matches!(
+        cov.cov_to_corr(),
+        Err(error::LinalgError::NotPositiveDefinite)
+    ));
+    // Positive variances but indefinite
+    let cov = array![[1.0, 2.0], [2.0, 1.0]];
+    assert!(cov.cov_to_corr().is_ok());
+    assert!(matches!(
+        cov.whitening(UPLO::Upper),
+        Err(error::LinalgError::NotPositiveDefinite)
+    ));
+}
+
+macro_rules! impl_nearest_spd {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<nearest_spd_ $scalar>]() {
+                let n = 5;
+                // noisy covariance with negative eigenvalues
+                let q: Array2<$scalar> = random_unitary(n);
+                let d = array![3.0, 1.0, 0.5, -1e-3, -0.2].mapv(<$scalar>::from_real);
+                let a = q.dot(&Array2::from_diag(&d)).dot(&q.adjoint());
+                assert!(a.cholesky(UPLO::Lower).is_err());
+                let b = a.nearest_spd().unwrap();
+                assert!(b.cholesky(UPLO::Lower).is_ok());
+                assert_close_l2!(&b, &b.adjoint(), 1e-12);
+                // nearest one clips the negative eigenvalues of the Hermitian matrix
+                let clipped = a.apply_fn(UPLO::Lower, |e| e.max(0.0)).unwrap();
+                assert_close_l2!(&b, &clipped, 1e-9);
+
+                // non-Hermitian input is symmetrized at first
+                let skew: Array2<$scalar> = random((n, n));
+                let a_ns = &a + &(&skew - &skew.adjoint());
+                assert_close_l2!(&a_ns.nearest_spd().unwrap(), &clipped, 1e-9);
+
+                // positive definite matrix is kept
+                let c: Array2<$scalar> = random_hpd(n);
+                assert_close_l2!(&c.nearest_spd().unwrap(), &c, 1e-9);
+            }
+        }
+    };
+}
+
+impl_nearest_spd!(f64);
+impl_nearest_spd!(c64);
+
+#[test]
+fn nearest_spd_zero() {
+    let a: Array2<f64> = Array2::zeros((3, 3));
+    let b = a.nearest_spd().unwrap();
+    assert!(b.cholesky(UPLO::Lower).is_ok());
+    assert!(b.iter().all(|x| x.abs() < 1e-9));
+    assert!(Array2::<f64>::zeros((2, 3)).nearest_spd().is_err());
+    assert_eq!(
+        Array2::<f64>::zeros((0, 0)).nearest_spd().unwrap().dim(),
+        (0, 0)
+    );
+}
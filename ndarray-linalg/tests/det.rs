@@ -83,6 +83,18 @@ fn det_zero() {
     det_zero!(c32);
 }
 
+#[test]
+fn det_1x1() {
+    let a: Array2<f64> = array![[-2.0]];
+    assert_eq!(a.det().unwrap(), -2.0);
+    assert_eq!(a.sln_det().unwrap(), (-1.0, f64::ln(2.0)));
+    let a: Array2<c64> = array![[c64::new(0.0, 3.0)]];
+    assert_eq!(
+        a.sln_det_into().unwrap(),
+        (c64::new(0.0, 1.0), f64::ln(3.0))
+    );
+}
+
 #[test]
 fn det_zero_nonsquare() {
     macro_rules! det_zero_nonsquare {
@@ -172,3 +184,70 @@ fn det_nonsquare() {
         }
     }
 }
+
+#[test]
+fn det_known() {
+    let a: Array2<f64> = array![[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]];
+    assert_rclose!(a.det().unwrap(), 49., 1e-9);
+    let (sign, ln_det) = a.sln_det().unwrap();
+    assert_eq!(sign, 1.);
+    assert_rclose!(ln_det, Float::ln(49.), 1e-9);
+
+    // odd permutation
+    let p: Array2<f64> = array![[0., 1., 0.], [1., 0., 0.], [0., 0., 1.]];
+    assert_rclose!(p.det().unwrap(), -1., 1e-9);
+    assert_eq!(p.sln_det().unwrap().0, -1.);
+
+    // det(c A) = c^n det(A), and the sign is a phase for complex
+    let i = c64::new(0., 1.);
+    let a: Array2<c64> = a.mapv(|x| i * x);
+    assert_aclose!(a.det().unwrap(), c64::new(0., -49.), 1e-9);
+    let (sign, ln_det) = a.sln_det().unwrap();
+    assert_aclose!(sign, -i, 1e-9);
+    assert_rclose!(ln_det, Float::ln(49.), 1e-9);
+}
+
+#[test]
+fn det_triangular() {
+    macro_rules! det_triangular {
+        ($elem:ty, $rtol:expr) => {
+            let a: Array2<$elem> = random((4, 4));
+            for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                let t = a.clone().into_triangular(uplo);
+                let product = t.diag().iter().fold(<$elem>::one(), |p, &x| p * x);
+                assert_aclose!(t.det().unwrap(), product, $rtol);
+                assert_aclose!(t.t().to_owned().det().unwrap(), product, $rtol);
+            }
+        };
+    }
+    det_triangular!(f64, 1e-9);
+    det_triangular!(f32, 1e-4);
+    det_triangular!(c64, 1e-9);
+    det_triangular!(c32, 1e-4);
+}
+
+#[test]
+fn det_sign() {
+    for _ in 0..10 {
+        let a: Array2<f64> = random((4, 4));
+        let sign = a.det().unwrap().signum() as i8;
+        assert_eq!(a.det_sign().unwrap(), sign);
+        assert_eq!(a.factorize().unwrap().det_sign().unwrap(), sign);
+        // the determinant overflows
+        assert_eq!((&a * 1e200).det_sign().unwrap(), sign);
+        // swapping two rows flips the sign
+        let b = a.select(Axis(0), &[1, 0, 2, 3]);
+        assert_eq!(b.det_sign().unwrap(), -sign);
+    }
+    let q: Array2<f32> = random_unitary(3);
+    assert_eq!(q.det_sign().unwrap() as f32, q.det().unwrap().round());
+}
+
+#[test]
+fn det_sign_singular() {
+    let a: Array2<f64> = array![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]];
+    assert_eq!(a.det_sign().unwrap(), 0);
+    assert_eq!(Array2::<f64>::zeros((2, 2)).det_sign().unwrap(), 0);
+    assert_eq!(Array2::<f64>::zeros((0, 0)).det_sign().unwrap(), 1);
+    assert!(Array2::<f64>::zeros((2, 3)).det_sign().is_err());
+}
@@ -330,3 +330,213 @@ macro_rules! impl_test_complex {
 
 impl_test_complex!(c32);
 impl_test_complex!(c64);
+
+#[test]
+fn eig_nonsquare() {
+    let a: Array2<f64> = random((3, 2));
+    assert!(matches!(
+        a.eig(),
+        Err(error::LinalgError::NotSquare { rows: 3, cols: 2 })
+    ));
+    assert!(matches!(
+        a.eigvals(),
+        Err(error::LinalgError::NotSquare { rows: 3, cols: 2 })
+    ));
+}
+
+macro_rules! impl_test_balanced {
+    ($name:ident, $real:ty, $balance:expr) => {
+        #[test]
+        fn $name() {
+            // Badly scaled similarity transform `D A D^-1` of the test matrix
+            let d = array![1e-3, 1.0, 1e3, 1.0, 1e-2];
+            let a = test_matrix_real::<$real>();
+            let b = Array2::from_shape_fn((5, 5), |(i, j)| a[(i, j)] * d[i] / d[j]);
+            let (e1, vecs) = b.eig_balanced($balance).unwrap();
+            let e2 = b.eigvals_balanced($balance).unwrap();
+            assert_close_l2!(&e1, &e2, 1.0e-5);
+            assert_close_l2!(
+                &sorted_eigvals(e1.view()),
+                &sorted_eigvals(answer_eig_real::<$real>().view()),
+                1.0e-3
+            );
+            test_eig(b.view(), e1.view(), vecs.view());
+
+            let (e1, vecs) = b.t().eig_balanced($balance).unwrap();
+            test_eig(b.t(), e1.view(), vecs.view());
+        }
+    };
+}
+
+impl_test_balanced!(eig_balanced_none, f64, Balance::None);
+impl_test_balanced!(eig_balanced_permute, f64, Balance::Permute);
+impl_test_balanced!(eig_balanced_scale, f64, Balance::Scale);
+impl_test_balanced!(eig_balanced_both, f64, Balance::Both);
+
+#[test]
+fn eig_balanced_complex() {
+    let a = test_matrix_complex::<c64>();
+    for &balance in &[Balance::None, Balance::Both] {
+        let (e, vecs) = a.eig_balanced(balance).unwrap();
+        assert_close_l2!(&e, &answer_eig_complex::<c64>(), 1.0e-3);
+        test_eig(a.view(), e.view(), vecs.view());
+    }
+}
+
+#[test]
+fn eig_expert_normal() {
+    let a = Array2::from_diag(&array![1.0, 2.0, 4.0]);
+    for a in &[a.clone(), a.t().to_owned()] {
+        let (e, vecs, rconde, rcondv) = a.eig_expert().unwrap();
+        test_eig(a.view(), e.view(), vecs.view());
+        // eigenvalues of a normal matrix are perfectly conditioned
+        assert_close_l2!(&rconde, &array![1.0, 1.0, 1.0], 1e-9);
+        // eigenvectors are conditioned by the gap between eigenvalues
+        let gap: Array1<f64> = e
+            .iter()
+            .map(|x| {
+                e.iter()
+                    .filter(|&y| y != x)
+                    .map(|y: &c64| (x - y).norm())
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .collect();
+        assert_close_l2!(&rcondv, &gap, 1e-9);
+    }
+}
+
+#[test]
+fn eig_expert_nonnormal() {
+    let t = 10.0;
+    let a = array![[1.0, t], [0.0, 2.0]];
+    let at = {
+        let mut at = Array2::zeros((2, 2).f());
+        at.assign(&a);
+        at
+    };
+    for a in &[a, at] {
+        let (e, vecs, rconde, _) = a.eig_expert().unwrap();
+        test_eig(a.view(), e.view(), vecs.view());
+        let s = 1.0 / f64::sqrt(1.0 + t * t);
+        assert_close_l2!(&rconde, &array![s, s], 1e-9);
+    }
+}
+
+#[test]
+fn eig_expert_complex() {
+    let a = test_matrix_complex::<c64>();
+    let (e, vecs, rconde, rcondv) = a.eig_expert().unwrap();
+    assert_close_l2!(&e, &answer_eig_complex::<c64>(), 1.0e-3);
+    test_eig(a.view(), e.view(), vecs.view());
+    assert_eq!(rconde.len(), 4);
+    assert_eq!(rcondv.len(), 4);
+    assert!(rconde.iter().all(|&r| r > 0.0 && r <= 1.0));
+}
+
+// Test y_i^H A = e_i y_i^H and y_i^H x_j = δ_ij
+fn test_eig_both<T: Scalar>(
+    a: ArrayView2<'_, T>,
+    eigs: ArrayView1<'_, T::Complex>,
+    left: ArrayView2<'_, T::Complex>,
+    right: ArrayView2<'_, T::Complex>,
+) where
+    T::Complex: Lapack,
+{
+    test_eig(a, eigs, right);
+    let a: Array2<T::Complex> = a.map(|v| v.as_c());
+    let yh = left.t().mapv(|y| y.conj());
+    for (&e, y) in eigs.iter().zip(yh.axis_iter(Axis(0))) {
+        let ya = y.dot(&a);
+        let ey = y.mapv(|val| val * e);
+        assert_close_l2!(&ya, &ey, T::real(1e-3));
+    }
+    assert_close_l2!(&yh.dot(&right), &Array2::eye(eigs.len()), T::real(1e-3));
+}
+
+macro_rules! impl_test_eig_both {
+    ($real:ty, $complex:ty) => {
+        paste::item! {
+            #[test]
+            fn [<$real _eig_both>]() {
+                for a in &[test_matrix_real::<$real>(), test_matrix_real_t::<$real>()] {
+                    let (e, y, x) = a.eig_both().unwrap();
+                    assert_close_l2!(&sorted_eigvals(e.view()), &sorted_eigvals(answer_eig_real::<$real>().view()), 1.0e-3);
+                    test_eig_both(a.view(), e.view(), y.view(), x.view());
+                }
+            }
+
+            #[test]
+            fn [<$complex _eig_both>]() {
+                for a in &[test_matrix_complex::<$complex>(), test_matrix_complex_t::<$complex>()] {
+                    let (e, y, x) = a.eig_both().unwrap();
+                    assert_close_l2!(&sorted_eigvals(e.view()), &sorted_eigvals(answer_eig_complex::<$complex>().view()), 1.0e-3);
+                    test_eig_both(a.view(), e.view(), y.view(), x.view());
+                }
+            }
+        }
+    };
+}
+
+impl_test_eig_both!(f32, c32);
+impl_test_eig_both!(f64, c64);
+
+#[test]
+fn eig_both_condition_number() {
+    // `|y_i|` of the biorthonormal left eigenvectors is `1 / rconde`
+    let t = 10.0;
+    let a = array![[1.0, t], [0.0, 2.0]];
+    let (e, y, _) = a.eig_both().unwrap();
+    let (e_, _, rconde, _) = a.eig_expert().unwrap();
+    assert_close_l2!(&e, &e_, 1e-12);
+    let ynorm = y
+        .axis_iter(Axis(1))
+        .map(|y| y.norm_l2())
+        .collect::<Array1<f64>>();
+    assert_close_l2!(&ynorm, &rconde.mapv(|r| 1.0 / r), 1e-9);
+    assert!(Array2::<f64>::zeros((2, 3)).eig_both().is_err());
+}
+
+#[test]
+fn eig_empty_1x1() {
+    let a: Array2<f64> = Array2::zeros((0, 0));
+    let (e, v) = a.eig().unwrap();
+    assert_eq!(e.len(), 0);
+    assert_eq!(v.dim(), (0, 0));
+    assert_eq!(a.eigvals().unwrap().len(), 0);
+
+    let a: Array2<f64> = array![[-3.0]];
+    let (e, v) = a.eig().unwrap();
+    assert_eq!(e, array![c64::new(-3.0, 0.0)]);
+    assert_eq!(v, array![[c64::new(1.0, 0.0)]]);
+    assert_eq!(a.eigvals().unwrap(), e);
+}
+
+#[test]
+fn eig_defective() {
+    // similarity transform of a Jordan block
+    let j: Array2<f64> = array![[2.0, 1.0, 0.0], [0.0, 2.0, 1.0], [0.0, 0.0, -1.0]];
+    let q: Array2<f64> = random_unitary(3);
+    let a = q.dot(&j).dot(&q.t());
+    let (defective, cond) = a.eig().unwrap().is_defective().unwrap();
+    assert!(defective, "cond = {}", cond);
+
+    let a: Array2<c64> = random_unitary(4);
+    let (defective, cond) = a.eig().unwrap().is_defective().unwrap();
+    assert!(!defective);
+    // the eigenvectors of a normal matrix are orthonormal
+    assert_rclose!(cond, 1.0, 1e-9);
+}
+
+#[test]
+fn eig_near_defective() {
+    // the angle of the eigenvectors is `δ`, and `κ(V) ~ 2 / δ`
+    for &(delta, flagged) in &[(1e-3, false), (1e-9, true)] {
+        let a: Array2<f64> = array![[1.0, 1.0], [0.0, 1.0 + delta]];
+        let (defective, cond) = a.eig().unwrap().is_defective().unwrap();
+        assert_eq!(defective, flagged);
+        assert_rclose!(cond, 2.0 / delta, 1e-3);
+    }
+
+    let e: (Array1<c64>, Array2<c64>) = (Array1::zeros(0), Array2::zeros((0, 0)));
+    assert_eq!(e.is_defective().unwrap(), (false, 1.0));
+}
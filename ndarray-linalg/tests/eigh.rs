@@ -1,6 +1,9 @@
 use ndarray::*;
 use ndarray_linalg::*;
 
+mod common;
+use common::half_filled;
+
 #[should_panic]
 #[test]
 fn eigh_generalized_shape_mismatch() {
@@ -128,3 +131,408 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+#[test]
+fn ssqrt_complex() {
+    let a: Array2<c64> = random_hpd(3);
+    let s = a.ssqrt(UPLO::Upper).unwrap();
+    let sh = s.t().mapv(|x| x.conj());
+    assert_close_l2!(&sh, &s, 1e-7);
+    assert_close_l2!(&s.dot(&s), &a, 1e-7);
+}
+
+macro_rules! impl_apply_fn {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random_hermite(4);
+            // identity function reconstructs the matrix
+            let b = a.apply_fn(UPLO::Upper, |e| e).unwrap();
+            assert_close_l2!(&b, &a, 1e-9);
+            // `f(A)` shares the eigenvectors with `A`, and `A^2` is `A A`
+            let a2 = a.apply_fn(UPLO::Lower, |e| e * e).unwrap();
+            assert_close_l2!(&a2, &a.dot(&a), 1e-9);
+            // `exp(A) exp(-A) = I`
+            let p = a.apply_fn(UPLO::Upper, |e| e.exp()).unwrap();
+            let m = a
+                .clone()
+                .apply_fn_into(UPLO::Upper, |e| (-e).exp())
+                .unwrap();
+            assert_close_l2!(&p.dot(&m), &Array2::eye(4), 1e-9);
+        }
+    };
+}
+
+impl_apply_fn!(apply_fn_f64, f64);
+impl_apply_fn!(apply_fn_c64, c64);
+
+fn tridiagonal_dense<T: Scalar>(d: &Array1<T>, e: &Array1<T>) -> Array2<T> {
+    let n = d.len();
+    let mut a = Array2::from_diag(d);
+    for i in 0..n - 1 {
+        a[(i, i + 1)] = e[i];
+        a[(i + 1, i)] = e[i];
+    }
+    a
+}
+
+#[test]
+fn eigh_tridiagonal_random() {
+    let d: Array1<f64> = random(5);
+    let e: Array1<f64> = random(4);
+    let a = tridiagonal_dense(&d, &e);
+    let (w, v) = eigh_tridiagonal(&d, &e).unwrap();
+    assert_close_l2!(&w, &a.eigvalsh(UPLO::Upper).unwrap(), 1e-9);
+    assert_close_l2!(&a.dot(&v), &(&v * &w), 1e-9);
+    assert_close_l2!(&v.t().dot(&v), &Array2::eye(5), 1e-9);
+    assert_close_l2!(&eigvalsh_tridiagonal(&d, &e).unwrap(), &w, 1e-12);
+}
+
+#[test]
+fn eigh_tridiagonal_f32() {
+    let d: Array1<f32> = random(4);
+    let e: Array1<f32> = random(3);
+    let a = tridiagonal_dense(&d, &e);
+    let (w, v) = eigh_tridiagonal(&d, &e).unwrap();
+    assert_close_l2!(&a.dot(&v), &(&v * &w), 1e-4);
+}
+
+#[test]
+fn eigh_tridiagonal_small() {
+    let (w, v) = eigh_tridiagonal(&array![3.0], &Array1::<f64>::zeros(0)).unwrap();
+    assert_eq!(w, array![3.0]);
+    assert_eq!(v, array![[1.0]]);
+    let w = eigvalsh_tridiagonal(&Array1::<f64>::zeros(0), &Array1::zeros(0)).unwrap();
+    assert!(w.is_empty());
+    assert!(eigh_tridiagonal(&array![1.0, 2.0], &array![1.0, 2.0]).is_err());
+}
+
+#[test]
+fn eigh_sort_descending() {
+    let a: Array2<c64> = random_hermite(4);
+    let (w, v) = a.eigh(UPLO::Upper).unwrap();
+    let (ws, vs) = (w.clone(), v.clone()).sort_descending();
+    for i in 0..4 {
+        assert_eq!(ws[i], w[3 - i]);
+        assert_eq!(vs.column(i), v.column(3 - i));
+    }
+    // pairs are still consistent
+    let ws_c = ws.mapv(c64::from_real);
+    assert_close_l2!(&a.dot(&vs), &(&vs * &ws_c), 1e-9);
+}
+
+#[test]
+fn eigh_sort_descending_unordered() {
+    let w = array![1.0, 3.0, 2.0];
+    let v: Array2<f64> = random((3, 3));
+    let (ws, vs) = (w, v.clone()).sort_descending();
+    assert_eq!(ws, array![3.0, 2.0, 1.0]);
+    assert_eq!(vs.column(0), v.column(1));
+    assert_eq!(vs.column(1), v.column(2));
+    assert_eq!(vs.column(2), v.column(0));
+}
+
+macro_rules! impl_eigh_layout {
+    ($name:ident, $uplo:expr, $t:expr) => {
+        #[test]
+        fn $name() {
+            let a: Array2<c64> = random_hermite(4);
+            let mut b = half_filled(&a, $uplo);
+            if $t {
+                let mut f = Array2::zeros((4, 4).f());
+                f.assign(&b);
+                b = f;
+            }
+            let (e, v) = b.eigh($uplo).unwrap();
+            let ec = e.mapv(c64::from_real);
+            assert_close_l2!(&a.dot(&v), &(&v * &ec), 1e-9);
+            assert_close_l2!(&b.eigvalsh($uplo).unwrap(), &e, 1e-9);
+        }
+    };
+}
+
+impl_eigh_layout!(eigh_half_filled_c_upper, UPLO::Upper, false);
+impl_eigh_layout!(eigh_half_filled_c_lower, UPLO::Lower, false);
+impl_eigh_layout!(eigh_half_filled_f_upper, UPLO::Upper, true);
+impl_eigh_layout!(eigh_half_filled_f_lower, UPLO::Lower, true);
+
+#[test]
+fn eigh_strided() {
+    let a: Array2<c64> = random_hermite(4);
+    let mut big = Array2::<c64>::zeros((8, 8));
+    big.slice_mut(s![..;2, ..;2]).assign(&a);
+    let (e, _) = a.eigh(UPLO::Upper).unwrap();
+
+    let mut view = big.slice_mut(s![..;2, ..;2]);
+    assert_close_l2!(&view.eigvalsh(UPLO::Upper).unwrap(), &e, 1e-9);
+    let (ev, v) = view.eigh_inplace(UPLO::Upper).unwrap();
+    assert_close_l2!(&ev, &e, 1e-9);
+    let ec = ev.mapv(c64::from_real);
+    assert_close_l2!(&a.dot(v), &(&*v * &ec), 1e-9);
+    // other elements are untouched
+    assert_eq!(big[(1, 1)], c64::new(0.0, 0.0));
+}
+
+#[test]
+fn eigh_generalized_mixed_layout() {
+    let a: Array2<c64> = random_hermite(3);
+    let b: Array2<c64> = random_hpd(3);
+    let bf = {
+        let mut bf = Array2::zeros((3, 3).f());
+        bf.assign(&half_filled(&b, UPLO::Upper));
+        bf
+    };
+    let (e, (_, v)) = (half_filled(&a, UPLO::Upper), bf)
+        .eigh(UPLO::Upper)
+        .unwrap();
+    let ec = e.mapv(c64::from_real);
+    assert_close_l2!(&a.dot(&v), &(&b.dot(&v) * &ec), 1e-9);
+}
+
+macro_rules! impl_eigh_generalized_normalization {
+    ($name:ident, $scalar:ty, $uplo:expr, $f:expr) => {
+        #[test]
+        fn $name() {
+            let n = 4;
+            let a: Array2<$scalar> = random_hermite(n);
+            let b: Array2<$scalar> = random_hpd(n);
+            let (a, b) = if $f {
+                let mut af = Array2::zeros((n, n).f());
+                let mut bf = Array2::zeros((n, n).f());
+                af.assign(&a);
+                bf.assign(&b);
+                (af, bf)
+            } else {
+                (a, b)
+            };
+            let (e, (v, _)) = (a.clone(), b.clone()).eigh($uplo).unwrap();
+            let vh: Array2<$scalar> = conjugate(&v);
+            // `B`-orthonormal eigenvectors in the original coordinates
+            assert_close_l2!(&vh.dot(&b).dot(&v), &Array2::eye(n), 1e-9);
+            // which diagonalize `A` as well
+            let d = Array2::from_diag(&e.mapv(<$scalar>::from_real));
+            assert_close_l2!(&vh.dot(&a).dot(&v), &d, 1e-9);
+        }
+    };
+}
+
+impl_eigh_generalized_normalization!(eigh_generalized_normalization, f64, UPLO::Upper, false);
+impl_eigh_generalized_normalization!(eigh_generalized_normalization_t, f64, UPLO::Lower, true);
+impl_eigh_generalized_normalization!(
+    eigh_generalized_normalization_complex,
+    c64,
+    UPLO::Lower,
+    false
+);
+impl_eigh_generalized_normalization!(
+    eigh_generalized_normalization_complex_t,
+    c64,
+    UPLO::Upper,
+    true
+);
+
+#[test]
+fn eigh_gap() {
+    let a: Array2<f64> = Array2::from_diag(&array![3.0, 1.0, 1.0 + 1e-13, 5.0, 5.0]);
+    let eig = a.eigh(UPLO::Upper).unwrap();
+    assert!(eig.min_gap().unwrap() < 1e-12);
+    assert_eq!(eig.degenerate(1e-10), vec![true, true, false, true, true]);
+    assert_eq!(eig.degenerate_blocks(1e-10), vec![0..2, 2..3, 3..5]);
+    // descending order gives the same blocks in reverse
+    let eig = eig.sort_descending();
+    assert_eq!(eig.degenerate_blocks(1e-10), vec![0..2, 2..3, 3..5]);
+}
+
+#[test]
+fn eigh_gap_single() {
+    let a: Array2<f64> = array![[2.0]];
+    let eig = a.eigh(UPLO::Upper).unwrap();
+    assert_eq!(eig.min_gap(), None);
+    assert_eq!(eig.degenerate(1.0), vec![false]);
+    assert_eq!(eig.degenerate_blocks(1.0), vec![0..1]);
+}
+
+macro_rules! impl_rank_symmetric {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            // Hermitian matrix of rank 3 with negative eigenvalues
+            let n = 6;
+            let q: Array2<$scalar> = random_unitary(n);
+            let d = array![4.0, -2.0, 0.5, 0.0, 0.0, 0.0].mapv(<$scalar>::from_real);
+            let qh: Array2<$scalar> = conjugate(&q);
+            let a = q.dot(&Array2::from_diag(&d)).dot(&qh);
+            assert_eq!(a.rank_symmetric(UPLO::Upper, None).unwrap(), 3);
+            assert_eq!(a.rank_symmetric(UPLO::Lower, Some(0.2)).unwrap(), 2);
+            assert_eq!(
+                a.rank_symmetric(UPLO::Upper, None).unwrap(),
+                a.svd_analysis(None).unwrap().rank()
+            );
+        }
+    };
+}
+
+impl_rank_symmetric!(rank_symmetric, f64);
+impl_rank_symmetric!(rank_symmetric_complex, c64);
+
+#[test]
+fn rank_symmetric_not_square() {
+    let a: Array2<f64> = Array2::zeros((2, 3));
+    assert!(a.rank_symmetric(UPLO::Upper, None).is_err());
+}
+
+macro_rules! impl_truncated_eigh {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let n = 5;
+            let q: Array2<$scalar> = random_unitary(n);
+            let qh: Array2<$scalar> = conjugate(&q);
+            let d = array![4.0, 2.0, 0.5, 0.01, -0.1];
+            let a = q
+                .dot(&Array2::from_diag(&d.mapv(<$scalar>::from_real)))
+                .dot(&qh);
+            let truth = |k: usize| {
+                let mut dk = d.clone();
+                dk.slice_mut(s![k..]).fill(0.0);
+                q.dot(&Array2::from_diag(&dk.mapv(<$scalar>::from_real)))
+                    .dot(&qh)
+            };
+
+            let (b, kept) = a
+                .truncated_eigh(UPLO::Upper, 0.1, ThresholdMode::Absolute)
+                .unwrap();
+            assert_eq!(kept, 3);
+            assert_close_l2!(&b, &truth(3), 1e-9);
+            let (b, kept) = a
+                .truncated_eigh(UPLO::Lower, 0.3, ThresholdMode::Relative)
+                .unwrap();
+            assert_eq!(kept, 2);
+            assert_close_l2!(&b, &truth(2), 1e-9);
+            // negative eigenvalues are removed with the zero threshold
+            let (b, kept) = a
+                .truncated_eigh(UPLO::Upper, 0.0, ThresholdMode::Absolute)
+                .unwrap();
+            assert_eq!(kept, 4);
+            assert_close_l2!(&b, &truth(4), 1e-9);
+            let (b, kept) = a
+                .truncated_eigh(UPLO::Upper, 10.0, ThresholdMode::Absolute)
+                .unwrap();
+            assert_eq!(kept, 0);
+            assert_eq!(b, Array2::zeros((n, n)));
+        }
+    };
+}
+
+impl_truncated_eigh!(truncated_eigh, f64);
+impl_truncated_eigh!(truncated_eigh_complex, c64);
+
+macro_rules! impl_batched_eigh {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let (k, n) = (4, 3);
+            let a: Array3<$scalar> = Array3::from_shape_fn((k, n, n), |(i, j, l)| {
+                if j <= l {
+                    <$scalar>::from_real((i + j * n + l) as f64) + <$scalar>::from_real(1.0)
+                } else {
+                    <$scalar>::from_real(((i + l * n + j) as f64) + 1.0)
+                }
+            });
+            for uplo in [UPLO::Upper, UPLO::Lower] {
+                let (w, v) = a.batched_eigh(uplo).unwrap();
+                assert_eq!(w.dim(), (k, n));
+                assert_eq!(v.dim(), (k, n, n));
+                for i in 0..k {
+                    let (wi, vi) = a.index_axis(Axis(0), i).eigh(uplo).unwrap();
+                    assert_close_l2!(&w.row(i).to_owned(), &wi, 1e-9);
+                    assert_close_l2!(&v.index_axis(Axis(0), i).to_owned(), &vi, 1e-9);
+                }
+            }
+        }
+    };
+}
+
+impl_batched_eigh!(batched_eigh, f64);
+impl_batched_eigh!(batched_eigh_complex, c64);
+
+#[test]
+fn batched_eigh_empty() {
+    let a: Array3<f64> = Array3::zeros((0, 3, 3));
+    let (w, v) = a.batched_eigh(UPLO::Upper).unwrap();
+    assert_eq!(w.dim(), (0, 3));
+    assert_eq!(v.dim(), (0, 3, 3));
+}
+
+#[test]
+fn batched_eigh_not_square() {
+    let a: Array3<f64> = Array3::zeros((2, 3, 2));
+    assert!(matches!(
+        a.batched_eigh(UPLO::Upper),
+        Err(error::LinalgError::NotSquare { rows: 3, cols: 2 })
+    ));
+}
+
+macro_rules! impl_eigh_small {
+    ($name:ident, $scalar:ty, $n:expr) => {
+        #[test]
+        fn $name() {
+            let check = |a: &Array2<$scalar>| {
+                for uplo in [UPLO::Upper, UPLO::Lower] {
+                    // the closed-form solution agrees with LAPACK `eigvalsh`
+                    let (e, v) = a.eigh(uplo).unwrap();
+                    assert_close_l2!(&e, &a.eigvalsh(uplo).unwrap(), 1e-12);
+                    let ec = e.mapv(<$scalar>::from_real);
+                    assert_close_l2!(&a.dot(&v), &(&v * &ec), 1e-12);
+                    assert_close_l2!(&v.adjoint().dot(&v), &Array2::eye($n), 1e-12);
+                }
+            };
+            let a: Array2<$scalar> = random_hermite($n);
+            check(&a);
+            // nearly degenerate eigenvalues
+            let q: Array2<$scalar> = random_unitary($n);
+            let d = array![1.0, 1.0 + 1e-10, -2.0].slice_move(s![..$n]);
+            let a = q
+                .dot(&Array2::from_diag(&d.mapv(<$scalar>::from_real)))
+                .dot(&q.adjoint());
+            check(&a);
+            check(&Array2::eye($n));
+            check(&Array2::zeros(($n, $n)));
+        }
+    };
+}
+
+impl_eigh_small!(eigh_2x2, f64, 2);
+impl_eigh_small!(eigh_2x2_complex, c64, 2);
+impl_eigh_small!(eigh_3x3, f64, 3);
+impl_eigh_small!(eigh_3x3_complex, c64, 3);
+
+#[test]
+fn eigh_small_nan() {
+    // Non-finite matrices are left to LAPACK without panic
+    let a2 = array![[1.0, f64::NAN], [f64::NAN, 2.0]];
+    let a3 = array![[1.0, f64::NAN, 0.0], [f64::NAN, 2.0, 0.5], [0.0, 0.5, 3.0]];
+    for a in [a2, a3] {
+        for uplo in [UPLO::Upper, UPLO::Lower] {
+            if let Ok((e, _)) = a.eigh(uplo) {
+                assert!(e.iter().any(|e| e.is_nan()));
+            }
+        }
+    }
+}
+
+#[test]
+fn eigh_empty_1x1() {
+    let a: Array2<c64> = Array2::zeros((0, 0));
+    let (e, v) = a.eigh(UPLO::Upper).unwrap();
+    assert_eq!(e.len(), 0);
+    assert_eq!(v.dim(), (0, 0));
+    assert_eq!(a.eigvalsh(UPLO::Upper).unwrap().len(), 0);
+
+    // the imaginary part of the diagonal is ignored
+    let a = array![[c64::new(2.0, 5.0)]];
+    let (e, v) = a.eigh(UPLO::Lower).unwrap();
+    assert_eq!(e, array![2.0]);
+    assert_eq!(v, array![[c64::new(1.0, 0.0)]]);
+    assert_eq!(a.eigvalsh(UPLO::Lower).unwrap(), array![2.0]);
+}
@@ -0,0 +1,143 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+#[test]
+fn expm_rotation() {
+    let t = 0.7_f64;
+    let a = array![[0.0, -t], [t, 0.0]];
+    let truth = array![[t.cos(), -t.sin()], [t.sin(), t.cos()]];
+    assert_close_l2!(&a.expm().unwrap(), &truth, 1e-12);
+}
+
+#[test]
+fn expm_zero() {
+    let a: Array2<f64> = Array2::zeros((3, 3));
+    assert_close_l2!(&a.expm().unwrap(), &Array2::eye(3), 1e-15);
+}
+
+macro_rules! impl_expm {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<expm_diagonal_ $scalar>]() {
+                let d: Array1<$scalar> = random(5);
+                let a = Array2::from_diag(&d);
+                let truth = Array2::from_diag(&d.mapv(|x| x.exp()));
+                assert_close_l2!(&a.expm().unwrap(), &truth, $rtol);
+            }
+
+            #[test]
+            fn [<expm_inverse_ $scalar>]() {
+                // Large norm requires the scaling and squaring
+                let n = 5;
+                let scale = <$scalar>::from_real(3.0);
+                let mut a: Array2<$scalar> = random((n, n));
+                a.mapv_inplace(|x| x * scale);
+                let ma = a.mapv(|x| -x);
+                let e = a.expm().unwrap().dot(&ma.expm().unwrap());
+                assert_close_l2!(&e, &Array2::eye(n), $rtol);
+            }
+
+            #[test]
+            fn [<expm_multiply_ $scalar>]() {
+                let n = 30;
+                let a: Array2<$scalar> = random((n, n));
+                let v: Array1<$scalar> = random(n);
+                let t = <$scalar>::from_real(0.5);
+                let truth = a.mapv(|x| x * t).expm().unwrap().dot(&v);
+                let w = expm_multiply(a.view(), &v, t, $rtol, n).unwrap();
+                assert_close_l2!(&w, &truth, $rtol * 10.0);
+            }
+        }
+    };
+}
+
+impl_expm!(f64, 1e-9);
+impl_expm!(c64, 1e-9);
+
+#[test]
+fn expm_multiply_imaginary_time() {
+    // exp(-iHt) v keeps the norm for a Hermitian H
+    let n = 20;
+    let h: Array2<c64> = random_hermite(n);
+    let v: Array1<c64> = random(n);
+    let t = c64::new(0.0, -1.0);
+    let truth = h.mapv(|x| x * t).expm().unwrap().dot(&v);
+    let w = expm_multiply(h.view(), &v, t, 1e-10, n).unwrap();
+    assert_close_l2!(&w, &truth, 1e-8);
+    assert!((w.norm_l2() - v.norm_l2()).abs() < 1e-8);
+}
+
+#[test]
+fn expm_multiply_invariant_subspace() {
+    let a = Array2::from_diag(&array![1.0, 2.0, 3.0, 4.0]);
+    let v = array![1.0, 1.0, 0.0, 0.0];
+    let w = expm_multiply(a.view(), &v, 1.0, 1e-14, 4).unwrap();
+    assert_close_l2!(&w, &array![1.0_f64.exp(), 2.0_f64.exp(), 0.0, 0.0], 1e-12);
+}
+
+#[test]
+fn expm_multiply_not_converged() {
+    let n = 30;
+    let a: Array2<f64> = random((n, n));
+    let v: Array1<f64> = random(n);
+    assert!(matches!(
+        expm_multiply(a.view(), &v, 1.0, 1e-14, 2),
+        Err(error::LinalgError::NotConverged { .. })
+    ));
+}
+
+macro_rules! impl_trigm {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cosm_sinm_pythagorean_ $scalar>]() {
+                for &scale in &[0.1, 1.0, 2.0] {
+                    let n = 5;
+                    let scale = <$scalar>::from_real(scale);
+                    let mut a: Array2<$scalar> = random((n, n));
+                    a.mapv_inplace(|x| x * scale);
+                    let c = a.cosm().unwrap();
+                    let s = a.sinm().unwrap();
+                    assert_close_l2!(&(c.dot(&c) + s.dot(&s)), &Array2::eye(n), $rtol);
+                    // commute with `A`
+                    assert_close_l2!(&c.dot(&a), &a.dot(&c), $rtol);
+                    // `exp(iA) = cos(A) + i sin(A)`
+                    let ia = a.mapv(|x| x.as_c() * c64::new(0.0, 1.0));
+                    let e = ia.expm().unwrap();
+                    let cs = Zip::from(&c).and(&s).map_collect(|&c, &s| c.as_c() + s.as_c() * c64::new(0.0, 1.0));
+                    assert_close_l2!(&cs, &e, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<cosm_hermitian_ $scalar>]() {
+                let mut a: Array2<$scalar> = random_hermite(4);
+                a.mapv_inplace(|x| x * <$scalar>::from_real(5.0));
+                let truth = a.apply_fn(UPLO::Upper, f64::cos).unwrap();
+                assert_close_l2!(&a.cosm().unwrap(), &truth, $rtol);
+                let truth = a.apply_fn(UPLO::Upper, f64::sin).unwrap();
+                assert_close_l2!(&a.sinm().unwrap(), &truth, $rtol);
+            }
+        }
+    };
+}
+
+impl_trigm!(f64, 1e-9);
+impl_trigm!(c64, 1e-9);
+
+#[test]
+fn cosm_second_order_ode() {
+    // `x(t) = cos(Ω t) x0` for `x'' = -Ω^2 x` with the diagonal `Ω`
+    let t = 2.5;
+    let omega = array![1.0, 3.0];
+    let a = Array2::from_diag(&omega.mapv(|w| w * t));
+    let c = a.cosm().unwrap();
+    assert_close_l2!(&c.diag(), &omega.mapv(|w: f64| (w * t).cos()), 1e-12);
+    assert_close_l2!(
+        &a.sinm().unwrap().diag(),
+        &omega.mapv(|w: f64| (w * t).sin()),
+        1e-12
+    );
+    assert!(Array2::<f64>::zeros((2, 3)).cosm().is_err());
+}
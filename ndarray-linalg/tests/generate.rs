@@ -0,0 +1,225 @@
+use ndarray::*;
+use ndarray_linalg::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+fn sample_covariance<T: Scalar>(x: &Array2<T>, mu: &Array1<T>) -> Array2<T> {
+    let d = x - mu;
+    let dh: Array2<T> = conjugate(&d);
+    dh.dot(&d)
+        .mapv(|v| v / T::from_real(T::real(x.nrows() as f64)))
+}
+
+macro_rules! impl_sample_mvn {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<sample_mvn_ $scalar>]() {
+                let mut rng = StdRng::seed_from_u64(0);
+                let mu: Array1<$scalar> = random(3);
+                let sigma: Array2<$scalar> = random_hpd(3);
+                let n = 200_000;
+                let x = sample_mvn(&mu, &sigma, n, &mut rng).unwrap();
+                assert_eq!(x.dim(), (n, 3));
+                let mean = x.mean_axis(Axis(0)).unwrap();
+                assert_close_l2!(&mean, &mu, 0.05);
+                assert_close_l2!(&sample_covariance(&x, &mu), &sigma, 0.05);
+            }
+        }
+    };
+}
+
+impl_sample_mvn!(f64);
+impl_sample_mvn!(c64);
+
+#[test]
+fn fill_standard_normal() {
+    let mut rng = StdRng::seed_from_u64(0);
+    // odd length uses the first of the last pair
+    let mut x = vec![0.0_f64; 100_001];
+    f64::fill_standard_normal(&mut x, &mut rng);
+    let x = Array1::from(x);
+    assert!(x.mean().unwrap().abs() < 0.01);
+    assert!((x.mapv(|x| x * x).mean().unwrap() - 1.0).abs() < 0.02);
+    assert!(x[100_000] != 0.0);
+
+    let mut z = vec![c64::new(0.0, 0.0); 100_000];
+    c64::fill_standard_normal(&mut z, &mut rng);
+    let z = Array1::from(z);
+    assert!((z.mapv(|z| z.re * z.re).mean().unwrap() - 0.5).abs() < 0.01);
+    assert!((z.mapv(|z| z.im * z.im).mean().unwrap() - 0.5).abs() < 0.01);
+    assert!(z.mapv(|z| z.re * z.im).mean().unwrap().abs() < 0.01);
+}
+
+#[test]
+fn sample_mvn_errors() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mu: Array1<f64> = random(2);
+    let not_pd: Array2<f64> = array![[1.0, 2.0], [2.0, 1.0]];
+    assert!(sample_mvn(&mu, &not_pd, 1, &mut rng).is_err());
+    let sigma: Array2<f64> = random_hpd(3);
+    assert!(sample_mvn(&mu, &sigma, 1, &mut rng).is_err());
+}
+
+#[test]
+fn block_diag_rectangular() {
+    let a: Array2<f64> = random((2, 3));
+    let b: Array2<f64> = random((1, 1));
+    let c: Array2<f64> = random((3, 2));
+    let d = block_diag(&[a.view(), b.view(), c.view()]);
+    assert_eq!(d.dim(), (6, 6));
+    assert_eq!(d.slice(s![0..2, 0..3]), a);
+    assert_eq!(d.slice(s![2..3, 3..4]), b);
+    assert_eq!(d.slice(s![3..6, 4..6]), c);
+    assert_eq!(d.iter().filter(|&&x| x != 0.0).count(), 6 + 1 + 6);
+}
+
+#[test]
+fn block_diag_empty() {
+    let d = block_diag::<f64, OwnedRepr<f64>>(&[]);
+    assert_eq!(d.dim(), (0, 0));
+    // zero-sized blocks still add rows or columns
+    let d = block_diag(&[Array2::<f64>::zeros((0, 2)), Array2::eye(1)]);
+    assert_eq!(d, array![[0.0, 0.0, 1.0]]);
+}
+
+#[test]
+fn hconcat_vconcat() {
+    let a: Array2<f64> = random((2, 3));
+    let b: Array2<f64> = random((2, 1));
+    let h = hconcat(&[a.view(), b.view()]).unwrap();
+    assert_eq!(h.dim(), (2, 4));
+    assert_eq!(h.slice(s![.., ..3]), a);
+    assert_eq!(h.slice(s![.., 3..]), b);
+
+    let c: Array2<f64> = random((1, 3));
+    let v = vconcat(&[a.view(), c.view()]).unwrap();
+    assert_eq!(v.dim(), (3, 3));
+    assert_eq!(v.slice(s![..2, ..]), a);
+    assert_eq!(v.slice(s![2.., ..]), c);
+
+    assert_eq!(hconcat::<f64, OwnedRepr<f64>>(&[]).unwrap().dim(), (0, 0));
+}
+
+#[test]
+fn hconcat_vconcat_mismatch() {
+    let a: Array2<f64> = random((2, 3));
+    let b: Array2<f64> = random((3, 3));
+    assert!(matches!(
+        hconcat(&[a.view(), a.view(), b.view()]),
+        Err(error::LinalgError::IncompatibleBlock {
+            index: 2,
+            expected: 2,
+            actual: 3
+        })
+    ));
+    let c: Array2<f64> = random((2, 2));
+    assert!(matches!(
+        vconcat(&[a.view(), c.view()]),
+        Err(error::LinalgError::IncompatibleBlock {
+            index: 1,
+            expected: 3,
+            actual: 2
+        })
+    ));
+}
+
+#[test]
+fn toeplitz_rectangular() {
+    let c = array![1.0, 2.0];
+    let r = array![9.0, 3.0, 4.0, 5.0];
+    let t = toeplitz(&c, &r);
+    assert_eq!(t, array![[1.0, 3.0, 4.0, 5.0], [2.0, 1.0, 3.0, 4.0]]);
+    let t = toeplitz(&r, &c);
+    assert_eq!(t, array![[9.0, 2.0], [3.0, 9.0], [4.0, 3.0], [5.0, 4.0]]);
+}
+
+#[test]
+fn circulant_is_toeplitz() {
+    let c: Array1<c64> = random(5);
+    let r: Array1<c64> = (0..5).map(|j| c[(5 - j) % 5]).collect();
+    assert_eq!(circulant(&c), toeplitz(&c, &r));
+    assert_eq!(circulant(&Array1::<f64>::zeros(0)).dim(), (0, 0));
+}
+
+#[test]
+fn vandermonde_order() {
+    let x = array![2.0, -1.0];
+    let v = vandermonde(&x, 3, true);
+    assert_eq!(v, array![[1.0, 2.0, 4.0, 8.0], [1.0, -1.0, 1.0, -1.0]]);
+    let v = vandermonde(&x, 3, false);
+    assert_eq!(v, array![[8.0, 4.0, 2.0, 1.0], [-1.0, 1.0, -1.0, 1.0]]);
+    assert_eq!(vandermonde(&x, 0, true), array![[1.0], [1.0]]);
+}
+
+#[test]
+fn vandermonde_polyfit() {
+    // y = 1 - 2x + 3x^2
+    let x = Array1::linspace(-1.0, 1.0, 10);
+    let y = x.mapv(|x: f64| 1.0 - 2.0 * x + 3.0 * x * x);
+    let v = vandermonde(&x, 2, true);
+    let result = v.least_squares(&y).unwrap();
+    assert_close_l2!(&result.solution, &array![1.0, -2.0, 3.0], 1e-9);
+}
+
+#[test]
+fn companion_eigvals() {
+    // (x - 1)(x - 2)(x - 3) = x^3 - 6 x^2 + 11 x - 6
+    let c = companion(&array![2.0, -12.0, 22.0, -12.0]).unwrap();
+    assert_eq!(
+        c,
+        array![[6.0, -11.0, 6.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]
+    );
+    let mut e: Vec<f64> = c.eigvals().unwrap().iter().map(|e: &c64| e.re).collect();
+    e.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_close_l2!(&Array1::from(e), &array![1.0, 2.0, 3.0], 1e-9);
+}
+
+#[test]
+fn companion_invalid() {
+    assert!(matches!(
+        companion(&array![0.0, 1.0, 2.0]),
+        Err(error::LinalgError::ZeroLeadingCoefficient)
+    ));
+    assert!(matches!(
+        companion(&array![1.0]),
+        Err(error::LinalgError::Shape(_))
+    ));
+}
+
+#[test]
+fn kron_sum_definition() {
+    let a: Array2<c64> = random((3, 3));
+    let b: Array2<c64> = random((2, 2));
+    let k = kron_sum(&a, &b).unwrap();
+    let truth =
+        ndarray::linalg::kron(&a, &Array2::eye(2)) + ndarray::linalg::kron(&Array2::eye(3), &b);
+    assert_eq!(k, truth);
+    assert!(matches!(
+        kron_sum(&a, &Array2::<c64>::zeros((2, 3))),
+        Err(error::LinalgError::NotSquare { .. })
+    ));
+}
+
+#[test]
+fn kron_sum_spectrum() {
+    // 2D Laplacian on the 3 x 4 grid
+    let laplacian = |n: usize| {
+        Array2::from_shape_fn((n, n), |(i, j)| match (i as i64 - j as i64).abs() {
+            0 => 2.0,
+            1 => -1.0,
+            _ => 0.0,
+        })
+    };
+    let (a, b) = (laplacian(3), laplacian(4));
+    let k = kron_sum(&a, &b).unwrap();
+    assert_eq!(k.dim(), (12, 12));
+    let ea = a.eigvalsh(UPLO::Lower).unwrap();
+    let eb = b.eigvalsh(UPLO::Lower).unwrap();
+    let mut sums: Vec<f64> = ea
+        .iter()
+        .flat_map(|&x| eb.iter().map(move |&y| x + y))
+        .collect();
+    sums.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let ek = k.eigvalsh(UPLO::Lower).unwrap();
+    assert_close_l2!(&ek, &Array1::from(sums), 1e-9);
+}
@@ -0,0 +1,67 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_givens<T: Scalar + Lapack>(a: T, b: T) {
+    let (c, s, r) = givens(a, b);
+    let c_ = T::from_real(c);
+    assert_aclose!(c_ * c_ + s * s.conj(), T::one(), T::real(1e-5));
+    assert_aclose!(c_ * a + s * b, r, T::real(1e-5));
+    assert_aclose!(c_ * b - s.conj() * a, T::zero(), T::real(1e-5));
+}
+
+macro_rules! impl_test_givens {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<givens_ $scalar>]() {
+                let v: Array1<$scalar> = random(2);
+                test_givens(v[0], v[1]);
+                test_givens(v[0] * 0.0, v[1]);
+                test_givens(v[0], v[1] * 0.0);
+            }
+        }
+    };
+}
+
+impl_test_givens!(f64);
+impl_test_givens!(f32);
+impl_test_givens!(c64);
+impl_test_givens!(c32);
+
+#[test]
+fn givens_no_overflow() {
+    let (c, s, r) = givens(1e300_f64, 1e300);
+    assert!(r.is_finite());
+    assert_rclose!(c, 0.5_f64.sqrt(), 1e-12);
+    assert_rclose!(s, 0.5_f64.sqrt(), 1e-12);
+}
+
+#[test]
+fn apply_givens_rows() {
+    let a: Array2<c64> = random((3, 4));
+    let (c, s, r) = givens(a[(0, 1)], a[(2, 1)]);
+    let mut b = a.clone();
+    apply_givens(&mut b, Axis(0), 0, 2, c, s);
+    assert_aclose!(b[(0, 1)], r, 1e-9);
+    assert_aclose!(b[(2, 1)], c64::new(0.0, 0.0), 1e-9);
+    // untouched row and preserved norm
+    assert_close_l2!(&b.row(1), &a.row(1), 1e-12);
+    assert_rclose!(b.opnorm_fro().unwrap(), a.opnorm_fro().unwrap(), 1e-9);
+}
+
+#[test]
+fn apply_givens_columns() {
+    let a: Array2<f64> = random((4, 3));
+    let (c, s, _) = givens(a[(1, 0)], a[(1, 2)]);
+    let mut b = a.clone();
+    apply_givens(&mut b, Axis(1), 0, 2, c, s);
+    assert_aclose!(b[(1, 2)], 0.0, 1e-9);
+    assert_close_l2!(&b.column(1), &a.column(1), 1e-12);
+    // the same as `A G^T` with the rotation matrix G
+    let mut g: Array2<f64> = Array2::eye(3);
+    g[(0, 0)] = c;
+    g[(0, 2)] = s;
+    g[(2, 0)] = -s;
+    g[(2, 2)] = c;
+    assert_close_l2!(&b, &a.dot(&g.t()), 1e-9);
+}
@@ -0,0 +1,89 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+fn test_gmres<T: Scalar + Lapack>(restart: usize, rtol: T::Real) {
+    let n = 10;
+    let mut a: Array2<T> = random((n, n));
+    for i in 0..n {
+        a[(i, i)] += T::from_real(T::real(n as f64));
+    }
+    let x: Array1<T> = random(n);
+    let b = a.dot(&x);
+    let result = gmres(a.view(), &b, restart, rtol, 1000);
+    assert!(result.converged);
+    assert_close_l2!(&result.solution, &x, T::real(100.0) * rtol);
+    // GMRES minimizes the residual, thus it is non-increasing
+    for w in result.residual_norms.windows(2) {
+        assert!(w[1] <= w[0] * T::real(1.0 + 1e-6));
+    }
+}
+
+#[test]
+fn gmres_full() {
+    test_gmres::<f64>(10, 1e-10);
+}
+
+#[test]
+fn gmres_restarted() {
+    test_gmres::<f64>(3, 1e-10);
+}
+
+#[test]
+fn gmres_complex() {
+    test_gmres::<c64>(4, 1e-10);
+}
+
+#[test]
+fn gmres_full_converges_in_n() {
+    let a: Array2<f64> = random((6, 6));
+    let a = a + Array2::<f64>::eye(6);
+    let b: Array1<f64> = random(6);
+    let result = gmres(a.view(), &b, 6, 1e-10, 6);
+    assert!(result.converged);
+    assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+}
+
+#[test]
+fn gmres_not_converged() {
+    let a: Array2<f64> = random((20, 20));
+    let b: Array1<f64> = random(20);
+    let result = gmres(a.view(), &b, 2, 1e-14, 3);
+    assert!(!result.converged);
+    assert_eq!(result.residual_norms.len(), 4);
+}
+
+#[test]
+fn gmres_zero_rhs() {
+    let a: Array2<f64> = random((4, 4));
+    let result = gmres(a.view(), &Array1::zeros(4), 2, 1e-10, 10);
+    assert!(result.converged);
+    assert_eq!(result.solution, Array1::zeros(4));
+}
+
+#[test]
+fn gmres_preconditioned_exact() {
+    // The exact inverse as the preconditioner converges in one iteration
+    let n = 8;
+    let a: Array2<f64> = random((n, n));
+    let a = a + Array2::<f64>::eye(n);
+    let b: Array1<f64> = random(n);
+    let f = a.factorize().unwrap();
+    let result = gmres_preconditioned(a.view(), &b, |v| f.solve(v).unwrap(), 4, 1e-10, 10);
+    assert!(result.converged);
+    assert_eq!(result.residual_norms.len(), 2);
+    assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+}
+
+#[test]
+fn gmres_preconditioned_complex() {
+    let n = 10;
+    let mut a: Array2<c64> = random((n, n));
+    for i in 0..n {
+        a[(i, i)] += c64::new(n as f64 * (i + 1) as f64, 0.0);
+    }
+    let b: Array1<c64> = random(n);
+    let d = a.diag().to_owned();
+    let result = gmres_preconditioned(a.view(), &b, |v| v / &d, 3, 1e-10, 1000);
+    assert!(result.converged);
+    assert_close_l2!(&a.dot(&result.solution), &b, 1e-9);
+}
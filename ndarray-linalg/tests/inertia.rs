@@ -0,0 +1,58 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn inertia_empty() {
+    macro_rules! inertia_empty {
+        ($elem:ty) => {
+            let a: Array2<$elem> = Array2::zeros((0, 0));
+            assert_eq!(a.factorizeh().unwrap().inertia(), (0, 0, 0));
+            assert_eq!(a.inertia().unwrap(), (0, 0, 0));
+        };
+    }
+    inertia_empty!(f64);
+    inertia_empty!(f32);
+    inertia_empty!(c64);
+    inertia_empty!(c32);
+}
+
+#[test]
+fn inertia_fixed() {
+    // eigenvalues: -1, 3, 3
+    let a: Array2<f64> = array![[1., 2., 0.], [2., 1., 0.], [0., 0., 3.]];
+    assert_eq!(a.inertia().unwrap(), (2, 1, 0));
+    assert_eq!(a.t().inertia().unwrap(), (2, 1, 0));
+}
+
+#[test]
+fn inertia_singular() {
+    // eigenvalues: -2, 0, 2
+    let a: Array2<f64> = array![[0., 2., 0.], [2., 0., 0.], [0., 0., 0.]];
+    assert_eq!(a.inertia().unwrap(), (1, 1, 1));
+    let a: Array2<c64> = Array2::zeros((2, 2));
+    assert_eq!(a.inertia().unwrap(), (0, 0, 2));
+}
+
+#[test]
+fn inertia() {
+    macro_rules! inertia {
+        ($elem:ty, $rows:expr) => {
+            let a: Array2<$elem> = random_hermite($rows);
+            let eigs = a.eigvalsh(UPLO::Upper).unwrap();
+            let positive = eigs.iter().filter(|&&e| e > 0.0).count();
+            let negative = eigs.iter().filter(|&&e| e < 0.0).count();
+            assert_eq!(a.inertia().unwrap(), (positive, negative, 0));
+            assert_eq!(a.factorizeh().unwrap().inertia(), (positive, negative, 0));
+        };
+    }
+    for rows in 1..6 {
+        inertia!(f64, rows);
+        inertia!(c64, rows);
+    }
+}
+
+#[test]
+fn inertia_nonsquare() {
+    let a: Array2<f64> = Array2::zeros((2, 3));
+    assert!(a.inertia().is_err());
+}
@@ -24,3 +24,67 @@ fn abs() {
     assert_aclose!(aa.re(), a.norm().powi(2), 1e-5);
     assert_aclose!(aa.im(), 0.0, 1e-5);
 }
+
+#[test]
+fn outer_conj() {
+    let x = array![c64::new(1.0, 1.0), c64::new(0.0, 2.0)];
+    let y = array![c64::new(0.0, 1.0), c64::new(3.0, 0.0), c64::new(1.0, -1.0)];
+    let a = outer(&x, &y);
+    assert_eq!(a.dim(), (2, 3));
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(a[(i, j)], x[i] * y[j].conj());
+        }
+    }
+    // adjoint of the inner product
+    let z: Array1<c64> = random(3);
+    assert_close_l2!(&a.dot(&z), &x.mapv(|x| x * y.inner(&z)), 1e-12);
+}
+
+#[test]
+fn outer_no_conj() {
+    let x = array![c64::new(1.0, 1.0), c64::new(0.0, 2.0)];
+    let y = array![c64::new(0.0, 1.0), c64::new(3.0, 0.0), c64::new(1.0, -1.0)];
+    let a = outer_t(&x, &y);
+    for i in 0..2 {
+        for j in 0..3 {
+            assert_eq!(a[(i, j)], x[i] * y[j]);
+        }
+    }
+}
+
+#[test]
+fn outer_real() {
+    let x = array![1.0, 2.0];
+    let y = array![3.0, 4.0, 5.0];
+    let truth = array![[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]];
+    assert_eq!(outer(&x, &y), truth);
+    assert_eq!(outer_t(&x, &y), truth);
+}
+
+#[test]
+fn hadamard_product() {
+    let a: Array2<c64> = random((3, 2));
+    let b: Array2<c64> = random((3, 2).f());
+    let c = hadamard(&a, &b).unwrap();
+    let d = hadamard_conj(&a, &b).unwrap();
+    for i in 0..3 {
+        for j in 0..2 {
+            assert_eq!(c[(i, j)], a[(i, j)] * b[(i, j)]);
+            assert_eq!(d[(i, j)], a[(i, j)].conj() * b[(i, j)]);
+        }
+    }
+    // Frobenius inner product
+    let a1 = Array::from_iter(a.iter().cloned());
+    let b1 = Array::from_iter(b.iter().cloned());
+    assert_rclose!(d.sum().re, a1.inner(&b1).re, 1e-12);
+    assert_rclose!(d.sum().im, a1.inner(&b1).im, 1e-12);
+}
+
+#[test]
+fn hadamard_shape_mismatch() {
+    let a: Array2<f64> = random((3, 2));
+    let b: Array2<f64> = random((2, 3));
+    assert!(hadamard(&a, &b).is_err());
+    assert!(hadamard_conj(&a, &b).is_err());
+}
@@ -117,3 +117,22 @@ fn inv_2x2() {
     let a_inv = a.inv().unwrap();
     assert_close_l2!(&a_inv, &array!([-2.0, 1.0], [1.5, -0.5]), 1e-7);
 }
+
+#[test]
+fn inv_nonsquare() {
+    let a: Array2<f64> = random((2, 3));
+    assert!(matches!(
+        a.inv(),
+        Err(error::LinalgError::NotSquare { rows: 2, cols: 3 })
+    ));
+    assert!(matches!(
+        a.inv_into(),
+        Err(error::LinalgError::NotSquare { rows: 2, cols: 3 })
+    ));
+    // shape is checked before the layout
+    let a: Array2<f64> = random((2, 6));
+    assert!(matches!(
+        a.slice(s![.., ..;2]).inv(),
+        Err(error::LinalgError::NotSquare { rows: 2, cols: 3 })
+    ));
+}
@@ -0,0 +1,45 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+macro_rules! impl_lanczos {
+    ($name:ident, $scalar:ty, $order:expr) => {
+        #[test]
+        fn $name() {
+            let n = 20;
+            let k = 3;
+            let a: Array2<$scalar> = random_hermite(n);
+            let v0: Array1<$scalar> = random(n);
+            let (w, _) = a.eigh(UPLO::Upper).unwrap();
+            let truth = match $order {
+                TruncatedOrder::Largest => w.slice(s![n - k..; -1]).to_owned(),
+                TruncatedOrder::Smallest => w.slice(s![..k]).to_owned(),
+            };
+            let result = lanczos(a.view(), &v0, k, $order, 1e-10, n).unwrap();
+            assert!(result.converged);
+            assert_close_l2!(&result.eigvals, &truth, 1e-7);
+            for (i, x) in result.eigvecs.axis_iter(Axis(1)).enumerate() {
+                let lx = x.mapv(|x| x * <$scalar>::from_real(result.eigvals[i]));
+                assert_close_l2!(&a.dot(&x), &lx, 1e-7);
+            }
+        }
+    };
+}
+
+impl_lanczos!(lanczos_largest, f64, TruncatedOrder::Largest);
+impl_lanczos!(lanczos_smallest, f64, TruncatedOrder::Smallest);
+impl_lanczos!(lanczos_largest_complex, c64, TruncatedOrder::Largest);
+impl_lanczos!(lanczos_smallest_complex, c64, TruncatedOrder::Smallest);
+
+#[test]
+fn lanczos_invariant_subspace() {
+    // `v0` spans an invariant subspace of dimension 2
+    let a = Array2::from_diag(&array![1.0, 2.0, 3.0, 4.0]);
+    let v0 = array![1.0, 1.0, 0.0, 0.0];
+    let result = lanczos(a.view(), &v0, 1, TruncatedOrder::Largest, 1e-10, 4).unwrap();
+    assert!(result.converged);
+    assert_close_l2!(&result.eigvals, &array![2.0], 1e-9);
+
+    let result = lanczos(a.view(), &v0, 3, TruncatedOrder::Largest, 1e-10, 4).unwrap();
+    assert!(!result.converged);
+    assert_eq!(result.eigvals.len(), 2);
+}
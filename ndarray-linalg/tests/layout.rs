@@ -29,3 +29,42 @@ fn layout_f_3x2() {
     println!("a = {:?}", &a);
     assert_eq!(a.layout().unwrap(), MatrixLayout::F { col: 2, lda: 3 });
 }
+
+#[test]
+fn as_lapack_slice() {
+    let a: Array2<f64> = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let (l, s) = a.as_lapack_slice().unwrap();
+    assert_eq!(l, MatrixLayout::C { row: 2, lda: 3 });
+    assert_eq!(s, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let mut b = a.t().as_standard_layout().into_owned();
+    b.swap_axes(0, 1);
+    let (l, s) = b.as_lapack_slice_mut().unwrap();
+    assert_eq!(l, MatrixLayout::F { col: 3, lda: 2 });
+    assert_eq!(s, &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    s[1] = 0.0;
+    assert_eq!(b[(1, 0)], 0.0);
+
+    // a sub-matrix is not contiguous
+    let c: Array2<f64> = Array::zeros((4, 4));
+    assert!(c.slice(s![..2, ..2]).as_lapack_slice().is_err());
+}
+
+#[test]
+fn logical_transpose() {
+    let a: Array2<f64> = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let (l, at) = a.logical_transpose().unwrap();
+    assert_eq!(l, MatrixLayout::F { col: 2, lda: 3 });
+    assert_eq!(at.shape(), &[3, 2]);
+    assert_eq!(AllocatedArray::layout(&at).unwrap(), l);
+    assert_eq!(at.as_allocated().unwrap(), a.as_allocated().unwrap());
+
+    let b = a.t().as_standard_layout().into_owned();
+    let (l, bt) = b.logical_transpose().unwrap();
+    assert_eq!(l, MatrixLayout::F { col: 3, lda: 2 });
+    assert_eq!(bt, a);
+
+    let (l, att) = at.logical_transpose().unwrap();
+    assert_eq!(l, a.layout().unwrap());
+    assert_eq!(att, a);
+}
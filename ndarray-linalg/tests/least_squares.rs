@@ -127,3 +127,345 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+/// Feed `A` in blocks of rows, and compare with the SVD solution
+fn test_accumulator<T: Scalar + Lapack>(a: Array2<T>) {
+    let b: Array1<T> = random(a.nrows());
+    let expected = a.least_squares(&b).unwrap().solution;
+
+    let mut acc = LeastSquaresAccumulator::new(a.ncols());
+    for i in (0..a.nrows()).step_by(3) {
+        let end = std::cmp::min(i + 3, a.nrows());
+        acc.update(&a.slice(s![i..end, ..]), &b.slice(s![i..end]))
+            .unwrap();
+    }
+    assert_eq!(acc.rows(), a.nrows());
+    let x = acc.solve().unwrap();
+    assert_close_l2!(&x, &expected, T::real(1.0e-3));
+}
+
+macro_rules! impl_accumulator {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _accumulator>]() {
+                let a: Array2<$scalar> = random((10, 3));
+                test_accumulator(a)
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _accumulator_t>]() {
+                let a: Array2<$scalar> = random((10, 3).f());
+                test_accumulator(a)
+            }
+        }
+    };
+}
+
+impl_accumulator!(f32);
+impl_accumulator!(f64);
+impl_accumulator!(c32);
+impl_accumulator!(c64);
+
+#[test]
+fn least_squares_accumulator_shape_mismatch() {
+    let mut acc = LeastSquaresAccumulator::<f64>::new(3);
+    let a: Array2<f64> = random((4, 2));
+    let b: Array1<f64> = random(4);
+    assert!(acc.update(&a, &b).is_err());
+    let a: Array2<f64> = random((4, 3));
+    let b: Array1<f64> = random(3);
+    assert!(acc.update(&a, &b).is_err());
+}
+
+#[test]
+fn least_squares_strided_view() {
+    let big: Array2<f64> = random((6, 8));
+    // every other column of rows 0..5, not contiguous
+    let a = big.slice(s![..5, ..6;2]);
+    let b = big.slice(s![..5, 7]);
+    assert!(b.as_slice().is_none());
+    let expected = a.to_owned().least_squares(&b.to_owned()).unwrap();
+    let result = a.least_squares(&b).unwrap();
+    assert_close_l2!(&result.solution, &expected.solution, 1e-9);
+}
+
+#[test]
+fn least_squares_in_place_strided_rhs() {
+    let a: Array2<f64> = random((5, 3));
+    let mut big: Array2<f64> = random((5, 4));
+    let expected = a.least_squares(&big.column(1).to_owned()).unwrap();
+    let mut rhs = big.column_mut(1);
+    assert!(rhs.as_slice().is_none());
+    let result = a.clone().least_squares_in_place(&mut rhs).unwrap();
+    assert_close_l2!(&result.solution, &expected.solution, 1e-9);
+    // the solution is written back into the strided view
+    assert_close_l2!(&big.slice(s![..3, 1]), &expected.solution, 1e-9);
+}
+
+#[test]
+fn least_squares_in_place_strided_rhs_nrhs() {
+    let a: Array2<f64> = random((5, 3));
+    let mut big: Array2<f64> = random((5, 4));
+    let expected = a
+        .least_squares(&big.slice(s![.., ..;2]).to_owned())
+        .unwrap();
+    let mut rhs = big.slice_mut(s![.., ..;2]);
+    assert!(rhs.as_slice_memory_order().is_none());
+    let result = a.clone().least_squares_in_place(&mut rhs).unwrap();
+    assert_close_l2!(&result.solution, &expected.solution, 1e-9);
+    assert_close_l2!(&big.slice(s![..3, ..;2]), &expected.solution, 1e-9);
+}
+
+macro_rules! impl_stats {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random((10, 3));
+            let b: Array1<$scalar> = random(10);
+            let stats = a.least_squares_stats(&b).unwrap();
+            let result = a.least_squares(&b).unwrap();
+            assert_eq!(stats.rank, 3);
+            assert_close_l2!(&stats.solution, &result.solution, 1e-9);
+            let rss = result.residual_sum_of_squares.unwrap()[()];
+            assert_rclose!(stats.residual_sum_of_squares, rss, 1e-9);
+            assert_rclose!(stats.residual_variance, rss / 7.0, 1e-9);
+
+            // sigma^2 (A^H A)^{-1}
+            let ah: Array2<$scalar> = conjugate(&a);
+            let cov = ah
+                .dot(&a)
+                .inv()
+                .unwrap()
+                .mapv(|c| c * <$scalar>::from_real(stats.residual_variance));
+            assert_close_l2!(&stats.covariance, &cov, 1e-9);
+            for i in 0..3 {
+                assert_rclose!(stats.standard_errors[i], cov[(i, i)].re().sqrt(), 1e-9);
+            }
+        }
+    };
+}
+
+impl_stats!(least_squares_stats_real, f64);
+impl_stats!(least_squares_stats_complex, c64);
+
+#[test]
+fn least_squares_stats_rank_deficient() {
+    let mut a: Array2<f64> = random((6, 3));
+    let c = &a.column(0) * 2.0;
+    a.column_mut(2).assign(&c);
+    let b: Array1<f64> = random(6);
+    let stats = a.least_squares_stats(&b).unwrap();
+    assert_eq!(stats.rank, 2);
+    // the minimum norm solution as `*gelsd`
+    let result = a.least_squares(&b).unwrap();
+    assert_close_l2!(&stats.solution, &result.solution, 1e-9);
+    assert_rclose!(
+        stats.residual_variance,
+        stats.residual_sum_of_squares / 4.0,
+        1e-12
+    );
+}
+
+#[test]
+fn least_squares_stats_no_dof() {
+    let a: Array2<f64> = random((3, 3));
+    let b: Array1<f64> = random(3);
+    assert!(a.least_squares_stats(&b).is_err());
+    assert!(a.least_squares_stats(&Array1::<f64>::zeros(2)).is_err());
+}
+
+#[test]
+fn least_squares_r_squared() {
+    let a: Array2<f64> = random((20, 3));
+    let b: Array1<f64> = random(20);
+    let result = a.least_squares(&b).unwrap();
+    let rss = result.residual_sum_of_squares.as_ref().unwrap()[()];
+    let mean = b.mean().unwrap();
+    let tss = b.mapv(|b| (b - mean).powi(2)).sum();
+    let r2 = result.r_squared(&b).unwrap();
+    assert_rclose!(r2, 1.0 - rss / tss, 1e-12);
+    let adj = result.adjusted_r_squared(&b, 3).unwrap();
+    assert_rclose!(adj, 1.0 - (1.0 - r2) * 19.0 / 17.0, 1e-12);
+    assert!(adj < r2);
+    assert!(result.adjusted_r_squared(&b, 20).is_none());
+
+    // perfect fit
+    let x: Array1<f64> = random(3);
+    let result = a.least_squares(&a.dot(&x)).unwrap();
+    assert_rclose!(result.r_squared(&a.dot(&x)).unwrap(), 1.0, 1e-9);
+
+    // underdetermined problems have no residual
+    let a: Array2<f64> = random((2, 3));
+    let b: Array1<f64> = random(2);
+    assert!(a.least_squares(&b).unwrap().r_squared(&b).is_none());
+}
+
+macro_rules! impl_weighted {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random((8, 3));
+            let b: Array1<$scalar> = random(8);
+            let w = array![1.0, 2.0, 0.5, 3.0, 1.0, 0.0, 4.0, 1.5];
+            let result = a.least_squares_weighted(&b, &w).unwrap();
+            // normal equations `A^H W A x = A^H W b`
+            let ah: Array2<$scalar> = conjugate(&a);
+            let wc = w.mapv(<$scalar>::from_real);
+            let ahw = &ah * &wc;
+            let x = ahw.dot(&a).solve(&ahw.dot(&b)).unwrap();
+            assert_close_l2!(&result.solution, &x, 1e-9);
+        }
+    };
+}
+
+impl_weighted!(least_squares_weighted_real, f64);
+impl_weighted!(least_squares_weighted_complex, c64);
+
+#[test]
+fn least_squares_weighted_shape_mismatch() {
+    let a: Array2<f64> = random((4, 2));
+    let b: Array1<f64> = random(4);
+    assert!(a.least_squares_weighted(&b, &Array1::ones(3)).is_err());
+}
+
+macro_rules! impl_tls {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random((10, 3));
+            let b: Array1<$scalar> = random(10);
+            let (x, s) = a.total_least_squares(&b).unwrap();
+            // (A^H A - s^2 I) x = A^H b
+            let ah: Array2<$scalar> = conjugate(&a);
+            let lhs = ah.dot(&a).dot(&x) - x.mapv(|x| x.mul_real(s * s));
+            assert_close_l2!(&lhs, &ah.dot(&b), 1e-9);
+            // the smallest singular value of `[A | b]`
+            let mut c = Array2::zeros((10, 4));
+            c.slice_mut(s![.., ..3]).assign(&a);
+            c.column_mut(3).assign(&b);
+            let (_, sv, _) = c.svd(false, false).unwrap();
+            assert_rclose!(s, sv[3], 1e-9);
+        }
+    };
+}
+
+impl_tls!(total_least_squares_real, f64);
+impl_tls!(total_least_squares_complex, c64);
+
+#[test]
+fn total_least_squares_repeated() {
+    // `[A | b]` is the identity, whose singular values are all one
+    let a = array![[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]];
+    let b = array![0.0, 0.0, 1.0];
+    let (x, s) = a.total_least_squares(&b).unwrap();
+    assert_close_l2!(&x, &array![0.0, 0.0], 1e-12);
+    assert_rclose!(s, 1.0, 1e-12);
+}
+
+#[test]
+fn total_least_squares_nongeneric() {
+    // the second column of `A` is zero, which is the smallest singular vector
+    let a = array![[1.0, 0.0], [0.0, 0.0]];
+    let b = array![1.0, 1.0];
+    assert!(matches!(
+        a.total_least_squares(&b),
+        Err(error::LinalgError::NoSolution)
+    ));
+}
+
+#[test]
+fn nnls_kkt() {
+    let a: Array2<f64> = random((12, 5));
+    let b: Array1<f64> = random(12);
+    let (x, r) = a.nnls(&b).unwrap();
+    let res = &b - &a.dot(&x);
+    assert_rclose!(r, res.norm_l2(), 1e-12);
+    // KKT conditions: x >= 0, w = A^T (b - Ax) <= 0, and x_j w_j = 0
+    let w = a.t().dot(&res);
+    for j in 0..5 {
+        assert!(x[j] >= 0.0);
+        assert!(w[j] <= 1e-9);
+        assert!((x[j] * w[j]).abs() <= 1e-9);
+    }
+}
+
+#[test]
+fn nnls_unconstrained() {
+    // the unconstrained solution is already non-negative
+    let a: Array2<f64> = random((8, 3));
+    let x = array![1.0, 2.0, 0.5];
+    let (y, r) = a.nnls(&a.dot(&x)).unwrap();
+    assert_close_l2!(&y, &x, 1e-9);
+    assert!(r < 1e-9);
+    // the zero vector is optimal for `b = 0`
+    let (y, r) = a.nnls(&Array1::zeros(8)).unwrap();
+    assert_eq!(y, Array1::zeros(3));
+    assert_eq!(r, 0.0);
+    assert!(a.nnls(&Array1::zeros(7)).is_err());
+}
+
+macro_rules! impl_raw_output {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _raw_output>]() {
+                let a: Array2<$scalar> = random((5, 3));
+                let b: Array1<$scalar> = random(5);
+                let (result, raw) = a.least_squares_raw(&b).unwrap();
+                assert_eq!(raw.len(), 5);
+                assert_close_l2!(&raw.slice(s![..3]).to_owned(), &result.solution, $rtol);
+                let rss: <$scalar as Scalar>::Real = raw.slice(s![3..]).iter().map(|x| x.square()).sum();
+                assert_rclose!(rss, result.residual_sum_of_squares.unwrap()[()], $rtol);
+                assert_close_l2!(&result.solution, &a.least_squares(&b).unwrap().solution, $rtol);
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _raw_output_nrhs>]() {
+                for a in &[random((5, 3)), random((5, 3).f())] {
+                    let a: &Array2<$scalar> = a;
+                    let b: Array2<$scalar> = random((5, 2));
+                    let (result, raw) = a.least_squares_raw(&b).unwrap();
+                    assert_eq!(raw.dim(), (5, 2));
+                    assert_close_l2!(&raw.slice(s![..3, ..]).to_owned(), &result.solution, $rtol);
+                    let rss = raw.slice(s![3.., ..]).mapv(|x| x.square()).sum_axis(Axis(0));
+                    assert_close_l2!(&rss, result.residual_sum_of_squares.as_ref().unwrap(), $rtol);
+                }
+            }
+
+            #[test]
+            fn [<least_squares_ $scalar _raw_output_underdetermined>]() {
+                let a: Array2<$scalar> = random((3, 5));
+                let b: Array1<$scalar> = random(3);
+                let (result, raw) = a.least_squares_raw(&b).unwrap();
+                assert_eq!(raw.len(), 5);
+                assert_close_l2!(&raw, &result.solution, $rtol);
+            }
+        }
+    };
+}
+
+impl_raw_output!(f32, 1e-3);
+impl_raw_output!(f64, 1e-9);
+impl_raw_output!(c32, 1e-3);
+impl_raw_output!(c64, 1e-9);
+
+#[test]
+fn least_squares_empty() {
+    // no equations: the minimum norm solution is zero
+    let a: Array2<f64> = Array2::zeros((0, 3));
+    let result = a.least_squares(&Array1::zeros(0)).unwrap();
+    assert_eq!(result.solution, Array1::<f64>::zeros(3));
+    assert_eq!(result.rank, 0);
+    assert!(result.residual_sum_of_squares.is_none());
+    let result = a.least_squares(&Array2::zeros((0, 2))).unwrap();
+    assert_eq!(result.solution, Array2::<f64>::zeros((3, 2)));
+
+    // no unknowns: the residual is the right-hand side itself
+    let a: Array2<f64> = Array2::zeros((3, 0));
+    let result = a.least_squares(&array![1.0, 2.0, 2.0]).unwrap();
+    assert_eq!(result.solution.len(), 0);
+    assert_eq!(result.singular_values.len(), 0);
+    assert_eq!(result.rank, 0);
+    assert_eq!(result.residual_sum_of_squares.unwrap()[()], 9.0);
+}
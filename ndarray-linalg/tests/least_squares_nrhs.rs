@@ -188,3 +188,45 @@ impl_underdetermined!(f32);
 impl_underdetermined!(f64);
 impl_underdetermined!(c32);
 impl_underdetermined!(c64);
+
+macro_rules! impl_per_column {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_ $scalar _per_column>]() {
+                let a: Array2<$scalar> = random((6, 3));
+                let b: Array2<$scalar> = random((6, 4));
+                let result = a.least_squares(&b).unwrap();
+                let norms = result.residual_norms().unwrap();
+                let rel = result.relative_residuals(&b).unwrap();
+                let xnorms = result.solution_norms();
+                for (j, bj) in b.axis_iter(Axis(1)).enumerate() {
+                    // Each column is the same as solving it separately
+                    let rj = a.least_squares(&bj).unwrap();
+                    assert_eq!(rj.rank, result.rank);
+                    let ss = rj.residual_sum_of_squares.unwrap()[()];
+                    assert_rclose!(norms[j], ss.sqrt(), $rtol);
+                    assert_rclose!(rel[j], ss.sqrt() / bj.norm_l2(), $rtol);
+                    assert_rclose!(xnorms[j], rj.solution.norm_l2(), $rtol);
+                    let res = &bj - &a.dot(&rj.solution);
+                    assert_rclose!(norms[j], res.norm_l2(), $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_per_column!(f32, 1e-3);
+impl_per_column!(f64, 1e-9);
+impl_per_column!(c32, 1e-3);
+impl_per_column!(c64, 1e-9);
+
+#[test]
+fn least_squares_per_column_underdetermined() {
+    let a: Array2<f64> = random((3, 4));
+    let b: Array2<f64> = random((3, 2));
+    let result = a.least_squares(&b).unwrap();
+    assert!(result.residual_norms().is_none());
+    assert!(result.relative_residuals(&b).is_none());
+    assert_eq!(result.solution_norms().len(), 2);
+}
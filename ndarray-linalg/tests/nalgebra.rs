@@ -0,0 +1,47 @@
+#![cfg(feature = "nalgebra")]
+
+use ndarray::*;
+use ndarray_linalg::{interop::*, *};
+
+#[test]
+fn to_nalgebra_layouts() {
+    let a: Array2<f64> = random((3, 4));
+    for a in &[a.clone(), replicate(&a.t()).reversed_axes()] {
+        let m = a.to_nalgebra();
+        assert_eq!(m.shape(), (3, 4));
+        for ((i, j), &x) in a.indexed_iter() {
+            assert_eq!(m[(i, j)], x);
+        }
+        assert_eq!(a.clone().into_nalgebra(), m);
+        // non-contiguous
+        let m = a.slice(s![..;2, ..]).to_nalgebra();
+        assert_eq!(m.shape(), (2, 4));
+        assert_eq!(m[(1, 3)], a[(2, 3)]);
+    }
+}
+
+#[test]
+fn from_nalgebra_roundtrip() {
+    let m = nalgebra::DMatrix::<c64>::from_fn(3, 2, |i, j| c64::new(i as f64, j as f64));
+    let a = from_nalgebra(m.clone());
+    assert_eq!(a.dim(), (3, 2));
+    assert!(a.t().is_standard_layout());
+    for ((i, j), &x) in a.indexed_iter() {
+        assert_eq!(m[(i, j)], x);
+    }
+    assert_eq!(a.into_nalgebra(), m);
+}
+
+#[test]
+fn into_nalgebra_sliced() {
+    let a: Array2<f64> = random((4, 4).f());
+    // Fortran-contiguous, but a part of the original buffer
+    let m = a.clone().slice_move(s![.., 1..3]).into_nalgebra();
+    assert_eq!(m.shape(), (4, 2));
+    for ((i, j), &x) in a.slice(s![.., 1..3]).indexed_iter() {
+        assert_eq!(m[(i, j)], x);
+    }
+    // not contiguous
+    let m = a.clone().slice_move(s![1..3, ..]).into_nalgebra();
+    assert_eq!(m, a.slice(s![1..3, ..]).to_nalgebra());
+}
@@ -14,3 +14,24 @@ fn n_rows() {
     let (n, v) = normalize(a.clone(), NormalizeAxis::Row);
     assert_close_l2!(&from_diag(&v).dot(&n), &a, 1e-7);
 }
+
+#[test]
+fn norm_p() {
+    let v: Array1<f64> = array![3.0, -4.0];
+    assert_rclose!(v.norm_p(1.0), 7.0, 1e-9);
+    assert_rclose!(v.norm_p(2.0), 5.0, 1e-9);
+    assert_rclose!(v.norm_p(f64::INFINITY), 4.0, 1e-9);
+    assert_rclose!(v.norm_p(3.0), 91.0_f64.cbrt(), 1e-9);
+    assert_rclose!(v.norm_p(0.5), (3.0_f64.sqrt() + 2.0).powi(2), 1e-9);
+    // entrywise for matrices
+    let a: Array2<f64> = random((3, 4));
+    assert_rclose!(a.norm_p(2.0), a.opnorm_fro().unwrap(), 1e-9);
+    assert_rclose!(
+        a.norm_p(1.5),
+        a.iter()
+            .map(|x| x.abs().powf(1.5))
+            .sum::<f64>()
+            .powf(1.0 / 1.5),
+        1e-9
+    );
+}
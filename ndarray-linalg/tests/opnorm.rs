@@ -51,3 +51,16 @@ fn opnorm_4x3() {
 fn opnorm_4x3_t() {
     test(gen(4, 3, true), 42.0, 24.0, 650.0.sqrt());
 }
+
+#[test]
+fn opnorm_p() {
+    let a = gen(3, 4, false);
+    assert_rclose!(a.opnorm_p(1.0).unwrap(), 24.0, 1e-7);
+    assert_rclose!(a.opnorm_p(f64::INFINITY).unwrap(), 42.0, 1e-7);
+    let (_, s, _) = a.svd(false, false).unwrap();
+    assert_rclose!(a.opnorm_p(2.0).unwrap(), s[0], 1e-7);
+    assert!(matches!(
+        a.opnorm_p(3.0),
+        Err(error::LinalgError::UnsupportedNorm { .. })
+    ));
+}
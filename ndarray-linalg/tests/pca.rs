@@ -0,0 +1,53 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! impl_incremental_pca {
+    ($name:ident, $scalar:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            let (n, m, k) = (30, 5, 3);
+            // data far from the origin
+            let offset = <$scalar>::from_real(<$scalar>::real(1e3));
+            let mut x: Array2<$scalar> = random((n, m));
+            x.mapv_inplace(|x| x + offset);
+            let mut pca = IncrementalPca::new(m, k);
+            for i in (0..n).step_by(7) {
+                pca.update(&x.slice(s![i..std::cmp::min(i + 7, n), ..]))
+                    .unwrap();
+            }
+            assert_eq!(pca.n_samples(), n);
+
+            let mean = x.mean_axis(Axis(0)).unwrap();
+            assert_close_l2!(&pca.mean(), &mean, $rtol);
+            let xc = &x - &mean.insert_axis(Axis(0));
+            let nm1 = <$scalar>::real((n - 1) as f64);
+            let cov = conjugate::<_, _, OwnedRepr<_>>(&xc)
+                .dot(&xc)
+                .mapv(|c| c.div_real(nm1));
+            assert_close_l2!(&pca.covariance().unwrap(), &cov, $rtol);
+
+            let (w, _) = cov.eigh(UPLO::Upper).unwrap().sort_descending();
+            let ev = pca.explained_variance().unwrap();
+            assert_close_l2!(&ev, &w.slice(s![..k]).to_owned(), $rtol);
+            let v = pca.components().unwrap();
+            assert_eq!(v.dim(), (m, k));
+            let vw = &v * &ev.mapv(<$scalar>::from_real);
+            assert_close_l2!(&cov.dot(&v), &vw, $rtol);
+
+            let t = pca.transform(&x).unwrap();
+            assert_close_l2!(&t, &xc.dot(&v), $rtol);
+        }
+    };
+}
+
+impl_incremental_pca!(incremental_pca_f64, f64, 1e-7);
+impl_incremental_pca!(incremental_pca_c64, c64, 1e-7);
+
+#[test]
+fn incremental_pca_few_samples() {
+    let mut pca = IncrementalPca::<f64>::new(2, 1);
+    assert!(pca.components().is_err());
+    pca.update(&array![[1.0, 2.0]]).unwrap();
+    assert!(pca.covariance().is_err());
+    assert!(pca.update(&array![[1.0, 2.0, 3.0]]).is_err());
+}
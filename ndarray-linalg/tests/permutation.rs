@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn permute_rows_cols() {
+    let a: Array2<f64> = random((5, 4));
+    let perm_rows = array![3, 0, 4, 1, 2];
+    let perm_cols = array![1, 0, 3, 2];
+
+    let mut b = a.clone();
+    b.permute_rows(&perm_rows).unwrap();
+    assert_eq!(b, a.select(Axis(0), perm_rows.as_slice().unwrap()));
+    b.inverse_permute_rows(&perm_rows).unwrap();
+    assert_eq!(b, a);
+
+    // F layout
+    let mut b = a.t().to_owned().reversed_axes();
+    b.permute_cols(&perm_cols).unwrap();
+    assert_eq!(b, a.select(Axis(1), perm_cols.as_slice().unwrap()));
+    b.inverse_permute_cols(&perm_cols).unwrap();
+    assert_eq!(b, a);
+}
+
+#[test]
+fn permute_vector() {
+    let mut v = array![1, 2, 3];
+    v.view_mut()
+        .insert_axis(Axis(1))
+        .permute_rows(&array![1, 2, 0])
+        .unwrap();
+    assert_eq!(v, array![2, 3, 1]);
+}
+
+#[test]
+fn inverse_permutation_roundtrip() {
+    let perm = array![2, 4, 0, 1, 3];
+    let inv = inverse_permutation(&perm).unwrap();
+    for i in 0..perm.len() {
+        assert_eq!(inv[perm[i]], i);
+    }
+}
+
+#[test]
+fn invalid_permutation() {
+    let mut a = Array2::<f64>::zeros((3, 3));
+    assert!(matches!(
+        a.permute_rows(&array![0, 1, 1]),
+        Err(error::LinalgError::InvalidPermutation { n: 3 })
+    ));
+    assert!(a.permute_cols(&array![0, 1, 3]).is_err());
+    assert!(matches!(
+        a.permute_rows(&array![0, 1]),
+        Err(error::LinalgError::Shape(_))
+    ));
+}
+
+#[test]
+fn pivot_to_permutation_swaps() {
+    // Swap the first and third rows, and then the second and third rows
+    let perm = pivot_to_permutation(&[3, 3], 3).unwrap();
+    assert_eq!(perm, array![2, 0, 1]);
+    let perm = pivot_to_permutation(&[1, 2], 2).unwrap();
+    assert_eq!(perm, array![0, 1]);
+    assert!(pivot_to_permutation(&[0], 2).is_err());
+    assert!(pivot_to_permutation(&[3], 2).is_err());
+}
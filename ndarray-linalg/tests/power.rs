@@ -0,0 +1,124 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+#[test]
+fn power_iteration_deflated() {
+    let q: Array2<f64> = random_unitary(4);
+    let e = array![5.0, 3.0, 1.0, 0.5];
+    let a = q.dot(&Array2::from_diag(&e)).dot(&q.t());
+    let x0: Array1<f64> = random(4);
+
+    let (e1, v1) = power_iteration(a.view(), &x0, 1e-12, 10000).unwrap();
+    assert_rclose!(e1, 5.0, 1e-9);
+    assert_close_l2!(&a.dot(&v1), &(&v1 * e1), 1e-9);
+
+    let deflated = Deflated::new(a.view(), e1, v1);
+    let (e2, v2) = power_iteration(deflated.clone(), &x0, 1e-12, 10000).unwrap();
+    assert_rclose!(e2, 3.0, 1e-9);
+    assert_close_l2!(&a.dot(&v2), &(&v2 * e2), 1e-9);
+
+    let deflated = Deflated::new(deflated, e2, v2);
+    let (e3, _) = power_iteration(deflated.clone(), &x0, 1e-12, 10000).unwrap();
+    assert_rclose!(e3, 1.0, 1e-9);
+}
+
+#[test]
+fn power_iteration_complex() {
+    let q: Array2<c64> = random_unitary(3);
+    let e = array![c64::new(0.0, 4.0), c64::new(1.0, 0.0), c64::new(0.5, 0.5)];
+    let a = q
+        .dot(&Array2::from_diag(&e))
+        .dot(&conjugate::<c64, _, OwnedRepr<_>>(&q));
+    let x0: Array1<c64> = random(3);
+    let (e1, v1) = power_iteration(a.view(), &x0, 1e-12, 10000).unwrap();
+    assert_rclose!(e1, c64::new(0.0, 4.0), 1e-9);
+    assert_close_l2!(&a.dot(&v1), &(&v1 * e1), 1e-9);
+}
+
+#[test]
+fn power_iteration_negative() {
+    let a = array![[-3.0, 0.0], [0.0, 1.0]];
+    let (e, _) = power_iteration(a.view(), &array![1.0, 1.0], 1e-12, 1000).unwrap();
+    assert_rclose!(e, -3.0, 1e-9);
+}
+
+#[test]
+fn power_iteration_not_converged() {
+    // eigenvalues are `±i`
+    let a = array![[0.0, -1.0], [1.0, 0.0]];
+    let result = power_iteration(a.view(), &array![1.0, 0.0], 1e-12, 100);
+    assert!(matches!(
+        result,
+        Err(error::LinalgError::NotConverged { iterations: 100 })
+    ));
+}
+
+#[test]
+fn power_iteration_deflate_inplace() {
+    let q: Array2<c64> = random_unitary(4);
+    let e = array![5.0, 3.0, 1.0, 0.5].mapv(c64::from);
+    let mut a = q
+        .dot(&Array2::from_diag(&e))
+        .dot(&conjugate::<c64, _, OwnedRepr<_>>(&q));
+    let x0: Array1<c64> = random(4);
+    for &truth in &[5.0, 3.0, 1.0] {
+        let (e, v) = power_iteration(a.view(), &x0, 1e-12, 10000).unwrap();
+        assert_rclose!(e.re, truth, 1e-9);
+        a.deflate(e, &v).unwrap();
+    }
+    let (w, _) = a.eigh(UPLO::Upper).unwrap();
+    assert_close_l2!(&w, &array![0.0, 0.0, 0.0, 0.5], 1e-9);
+}
+
+#[test]
+fn deflate_not_normalized() {
+    let mut a = array![[2.0, 1.0], [1.0, 2.0]];
+    assert!(matches!(
+        a.deflate(3.0, &array![1.0, 1.0]),
+        Err(error::LinalgError::NotNormalized)
+    ));
+    assert!(a.deflate(3.0, &array![1.0, 0.0, 0.0]).is_err());
+}
+
+#[test]
+fn orthogonal_iteration_close_eigenvalues() {
+    // power iteration converges slowly by the ratio 4.9 / 5
+    let q: Array2<f64> = random_unitary(5);
+    let e = array![5.0, 4.9, 1.0, 0.5, -0.2];
+    let a = q.dot(&Array2::from_diag(&e)).dot(&q.t());
+    let x0: Array2<f64> = random((5, 2));
+    let (ritz, v) = orthogonal_iteration(a.view(), &x0, 1e-12, 1000).unwrap();
+    assert_close_l2!(&ritz.mapv(|e| e.re), &array![5.0, 4.9], 1e-9);
+    assert_close_l2!(&v.t().dot(&v), &Array2::eye(2), 1e-9);
+    // `v` spans the dominant eigenvectors
+    let p = q.slice(s![.., ..2]);
+    assert_close_l2!(&p.dot(&p.t()).dot(&v), &v, 1e-9);
+}
+
+#[test]
+fn orthogonal_iteration_complex() {
+    let q: Array2<c64> = random_unitary(4);
+    let e = array![
+        c64::new(0.0, 3.0),
+        c64::new(-2.0, 0.0),
+        c64::new(0.5, 0.5),
+        c64::new(0.1, 0.0)
+    ];
+    let a = q
+        .dot(&Array2::from_diag(&e))
+        .dot(&conjugate::<c64, _, OwnedRepr<_>>(&q));
+    let x0: Array2<c64> = random((4, 2));
+    let (ritz, v) = orthogonal_iteration(a.view(), &x0, 1e-12, 1000).unwrap();
+    assert_close_l2!(&ritz, &e.slice(s![..2]).to_owned(), 1e-9);
+    let t = conjugate::<c64, _, OwnedRepr<_>>(&v).dot(&a).dot(&v);
+    assert_close_l2!(&a.dot(&v), &v.dot(&t), 1e-9);
+}
+
+#[test]
+fn orthogonal_iteration_not_converged() {
+    let a = array![[2.0, 0.0], [0.0, 1.9]];
+    assert!(matches!(
+        orthogonal_iteration(a.view(), &array![[1.0], [1.0]], 1e-12, 2),
+        Err(error::LinalgError::NotConverged { iterations: 2 })
+    ));
+}
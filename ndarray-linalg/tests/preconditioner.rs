@@ -0,0 +1,133 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+macro_rules! impl_jacobi {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<jacobi_preconditioner_ $scalar>]() {
+                let n = 6;
+                let mut a: Array2<$scalar> = random((n, n));
+                for i in 0..n {
+                    a[(i, i)] = <$scalar>::from_real((i + 1) as f64 * 10.0);
+                }
+                let r: Array1<$scalar> = random(n);
+                let m_inv = jacobi_preconditioner(&a).unwrap();
+                let z = m_inv(&r);
+                for i in 0..n {
+                    assert_rclose!(z[i].abs(), r[i].abs() / ((i + 1) as f64 * 10.0), 1e-12);
+                }
+                let result = gmres_preconditioned(a.view(), &r, m_inv, n, 1e-10, 100);
+                assert!(result.converged);
+                assert_close_l2!(&a.dot(&result.solution), &r, 1e-9);
+            }
+        }
+    };
+}
+
+impl_jacobi!(f64);
+impl_jacobi!(c64);
+
+#[test]
+fn jacobi_preconditioner_zero_diagonal() {
+    let a = array![[2.0, 1.0], [1.0, 0.0]];
+    let m_inv = jacobi_preconditioner(&a).unwrap();
+    assert_eq!(m_inv(&array![1.0, 3.0]), array![0.5, 3.0]);
+}
+
+#[test]
+fn jacobi_preconditioner_not_square() {
+    let a: Array2<f64> = Array2::zeros((2, 3));
+    assert!(jacobi_preconditioner(&a).is_err());
+}
+
+/// 2D Laplacian on a `k x k` grid, whose Cholesky factor has fill-in
+fn laplacian_2d(k: usize) -> Array2<f64> {
+    let n = k * k;
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        let (xi, yi) = (i / k, i % k);
+        let (xj, yj) = (j / k, j % k);
+        if i == j {
+            4.0
+        } else if (xi == xj && (yi as i32 - yj as i32).abs() == 1)
+            || (yi == yj && (xi as i32 - xj as i32).abs() == 1)
+        {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+#[test]
+fn ichol0_pattern() {
+    let a = laplacian_2d(4);
+    let l = ichol0(&a, None).unwrap();
+    let llh = l.dot(&l.t());
+    for ((i, j), &x) in a.indexed_iter() {
+        if j > i {
+            assert_eq!(l[(i, j)], 0.0);
+        }
+        if x != 0.0 {
+            // `L L^H` equals `A` on the pattern
+            assert!((llh[(i, j)] - x).abs() < 1e-12);
+        } else if i >= j {
+            // no fill-in
+            assert_eq!(l[(i, j)], 0.0);
+        }
+    }
+}
+
+macro_rules! impl_ichol0_full {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<ichol0_full_ $scalar>]() {
+                // Full pattern gives the exact Cholesky factor
+                let a: Array2<$scalar> = random_hpd(5);
+                let l = ichol0(&a, None).unwrap();
+                assert_close_l2!(&l, &a.cholesky(UPLO::Lower).unwrap(), 1e-9);
+                let mask = Array2::from_elem((5, 5), true);
+                let l = ichol0(&a, Some(&mask)).unwrap();
+                assert_close_l2!(&l, &a.cholesky(UPLO::Lower).unwrap(), 1e-9);
+                let m_inv = ichol0_preconditioner(&a, None).unwrap();
+                let b: Array1<$scalar> = random(5);
+                assert_close_l2!(&m_inv(&b), &a.solvec(&b).unwrap(), 1e-9);
+            }
+        }
+    };
+}
+
+impl_ichol0_full!(f64);
+impl_ichol0_full!(c64);
+
+#[test]
+fn ichol0_accelerates_cg() {
+    let a = laplacian_2d(10);
+    let b = Array1::ones(a.nrows());
+    let plain = cg(a.view(), &b, 1e-10, 1000);
+    let m_inv = ichol0_preconditioner(&a, None).unwrap();
+    let pre = cg_preconditioned(a.view(), &b, m_inv, 1e-10, 1000);
+    assert!(plain.converged && pre.converged);
+    assert!(pre.residual_norms.len() < plain.residual_norms.len());
+    assert_close_l2!(&a.dot(&pre.solution), &b, 1e-9);
+}
+
+#[test]
+fn ichol0_mask() {
+    // Diagonal mask gives the Jacobi preconditioner
+    let a = laplacian_2d(3);
+    let mask = Array2::from_shape_fn(a.dim(), |(i, j)| i == j);
+    let l = ichol0(&a, Some(&mask)).unwrap();
+    assert_eq!(l, Array2::eye(9) * 2.0);
+    assert!(ichol0(&a, Some(&Array2::from_elem((2, 2), true))).is_err());
+}
+
+#[test]
+fn ichol0_not_positive_definite() {
+    let a = array![[1.0, 2.0], [2.0, 1.0]];
+    assert!(matches!(
+        ichol0(&a, None),
+        Err(error::LinalgError::NotPositiveDefinite)
+    ));
+}
@@ -0,0 +1,46 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn diagonally_dominant() {
+    let a = array![[3.0, -1.0, 1.0], [1.0, -4.0, 2.0], [0.0, 1.0, 2.0]];
+    assert!(a.is_diagonally_dominant());
+    // weak dominance is not enough
+    let b = array![[2.0, -1.0, 1.0], [1.0, -4.0, 2.0], [0.0, 1.0, 2.0]];
+    assert!(!b.is_diagonally_dominant());
+    let c = array![
+        [c64::new(0.0, 3.0), c64::new(2.0, 0.0)],
+        [c64::new(1.0, 1.0), c64::new(-2.0, 0.0)]
+    ];
+    assert!(c.is_diagonally_dominant());
+    assert!(!Array2::<f64>::ones((2, 3)).is_diagonally_dominant());
+}
+
+#[test]
+fn banded() {
+    let mut a = Array2::<f64>::zeros((5, 5));
+    a[(3, 1)] = 1.0;
+    a[(0, 3)] = 1.0;
+    a[(4, 4)] = 1.0;
+    assert_eq!(a.is_banded(), Some((2, 3)));
+    assert_eq!(Array2::<f64>::eye(3).is_banded(), Some((0, 0)));
+    assert_eq!(Array2::<f64>::ones((3, 3)).is_banded(), None);
+    // rectangular
+    let b = array![[1.0, 1.0, 0.0, 0.0], [0.0, 1.0, 1.0, 0.0]];
+    assert_eq!(b.is_banded(), Some((0, 1)));
+}
+
+#[test]
+fn triangular() {
+    let a: Array2<f64> = random((4, 4));
+    let u = a.clone().into_triangular(UPLO::Upper);
+    let l = a.clone().into_triangular(UPLO::Lower);
+    assert!(matches!(u.is_triangular(), Some(UPLO::Upper)));
+    assert!(matches!(l.is_triangular(), Some(UPLO::Lower)));
+    assert!(matches!(
+        Array2::<f64>::eye(3).is_triangular(),
+        Some(UPLO::Upper)
+    ));
+    assert!(a.is_triangular().is_none());
+    assert!(Array2::<f64>::zeros((2, 3)).is_triangular().is_none());
+}
@@ -71,3 +71,42 @@ fn qr_4x3_t() {
     let a = random((4, 3).f());
     test(&a, 4, 3);
 }
+
+macro_rules! impl_orthonormalize {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random((5, 3));
+            let q = a.orthonormalize(1e-9);
+            assert_eq!(q.dim(), (5, 3));
+            let qh: Array2<$scalar> = conjugate(&q);
+            assert_close_l2!(&qh.dot(&q), &Array2::eye(3), 1e-9);
+            // same column space as `a`
+            assert_close_l2!(&q.dot(&qh.dot(&a)), &a, 1e-9);
+
+            // the third column is a combination of the others
+            let mut b: Array2<$scalar> = random((5, 4));
+            let c = &b.column(0) + &b.column(1);
+            b.column_mut(2).assign(&c);
+            let q = b.orthonormalize(1e-9);
+            assert_eq!(q.dim(), (5, 3));
+            let qh: Array2<$scalar> = conjugate(&q);
+            assert_close_l2!(&qh.dot(&q), &Array2::eye(3), 1e-9);
+            assert_close_l2!(&q.dot(&qh.dot(&b)), &b, 1e-9);
+        }
+    };
+}
+
+impl_orthonormalize!(orthonormalize_real, f64);
+impl_orthonormalize!(orthonormalize_complex, c64);
+
+#[test]
+fn orthonormalize_wide_and_zero() {
+    let a: Array2<f64> = random((3, 5).f());
+    let q = a.orthonormalize(1e-9);
+    assert_eq!(q.dim(), (3, 3));
+    assert_close_l2!(&q.t().dot(&q), &Array2::eye(3), 1e-9);
+
+    let z = Array2::<f64>::zeros((3, 2));
+    assert_eq!(z.orthonormalize(1e-9).dim(), (3, 0));
+}
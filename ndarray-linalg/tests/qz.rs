@@ -0,0 +1,82 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+macro_rules! impl_qz {
+    ($scalar:ty, $rtol:expr, $real:expr) => {
+        paste::item! {
+            #[test]
+            fn [<qz_ $scalar>]() {
+                let n = 6;
+                let a: Array2<$scalar> = random((n, n));
+                let b: Array2<$scalar> = random((n, n));
+                for sort in &[QzSort::None, QzSort::LeftHalfPlane, QzSort::UnitDisk] {
+                    let r = qz(&a, &b, *sort).unwrap();
+                    let eye = Array2::<$scalar>::eye(n);
+                    assert_close_l2!(&r.q.adjoint().dot(&r.q), &eye, $rtol);
+                    assert_close_l2!(&r.z.adjoint().dot(&r.z), &eye, $rtol);
+                    assert_close_l2!(&r.q.dot(&r.s).dot(&r.z.adjoint()), &a, $rtol);
+                    assert_close_l2!(&r.q.dot(&r.t).dot(&r.z.adjoint()), &b, $rtol);
+                    // `S` has 2x2 blocks only for real matrices
+                    let lower = if $real { 2 } else { 1 };
+                    for ((i, j), x) in r.s.indexed_iter() {
+                        if i >= j + lower {
+                            assert_eq!(x.abs(), 0.0);
+                        }
+                    }
+                    for ((i, j), x) in r.t.indexed_iter() {
+                        if i > j {
+                            assert_eq!(x.abs(), 0.0);
+                        }
+                    }
+
+                    let eigs = r.eigenvalues();
+                    let selected = |l: &c64| match sort {
+                        QzSort::None => false,
+                        QzSort::LeftHalfPlane => l.re < 0.0,
+                        QzSort::UnitDisk => l.norm() < 1.0,
+                    };
+                    let eigs: Vec<c64> = eigs.iter().map(|l| c64::new(l.re as f64, l.im as f64)).collect();
+                    let count = eigs.iter().filter(|l| selected(l)).count();
+                    assert_eq!(r.sdim, count);
+                    assert!(eigs[..r.sdim].iter().all(|l| selected(l)));
+
+                    // Generalized eigenvalues are the eigenvalues of `B^{-1} A`
+                    let trace: $scalar = b.inv().unwrap().dot(&a).diag().sum();
+                    let sum: c64 = eigs.iter().sum();
+                    assert!((sum.re - trace.re() as f64).abs() < $rtol * 100.0 * sum.norm().max(1.0));
+                }
+            }
+        }
+    };
+}
+
+impl_qz!(f64, 1e-9, true);
+impl_qz!(f32, 1e-3, true);
+impl_qz!(c64, 1e-9, false);
+impl_qz!(c32, 1e-3, false);
+
+#[test]
+fn qz_infinite_eigenvalue() {
+    // Singular `B` gives `β = 0`
+    let a: Array2<f64> = array![[1., 0.], [0., 2.]];
+    let b: Array2<f64> = array![[1., 0.], [0., 0.]];
+    let r = qz(&a, &b, QzSort::None).unwrap();
+    assert_eq!(r.beta.iter().filter(|&&b| b == 0.0).count(), 1);
+    assert_eq!(
+        r.eigenvalues()
+            .iter()
+            .filter(|l| l.re.is_infinite() || l.re.is_nan())
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn qz_shape() {
+    let a: Array2<f64> = Array2::zeros((3, 3));
+    let b: Array2<f64> = Array2::zeros((2, 2));
+    assert!(qz(&a, &b, QzSort::None).is_err());
+    let a: Array2<f64> = Array2::zeros((0, 0));
+    let r = qz(&a, &a, QzSort::None).unwrap();
+    assert_eq!(r.sdim, 0);
+}
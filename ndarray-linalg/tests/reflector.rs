@@ -0,0 +1,66 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn test_householder<T: Scalar + Lapack>(n: usize, rtol: T::Real) {
+    let x: Array1<T> = random(n);
+    let (v, tau, beta) = householder_reflector(&x);
+    assert_eq!(v[0], T::one());
+    // H^H x = beta e_1
+    let mut c = x.clone().insert_axis(Axis(1));
+    apply_householder(&mut c, Side::Left, &v, tau.conj()).unwrap();
+    let mut e = Array1::zeros(n);
+    e[0] = T::from_real(beta);
+    assert_close_l2!(&c.column(0), &e, rtol);
+    assert_rclose!(T::from_real(beta).abs(), x.norm_l2(), rtol);
+}
+
+fn test_apply<T: Scalar + Lapack>(side: Side, c: Array2<T>, rtol: T::Real) {
+    let n = match side {
+        Side::Left => c.nrows(),
+        Side::Right => c.ncols(),
+    };
+    let x: Array1<T> = random(n);
+    let (v, tau, _) = householder_reflector(&x);
+    let vm = v.clone().insert_axis(Axis(1));
+    let vh: Array2<T> = conjugate(&vm);
+    let h = Array2::<T>::eye(n) - vm.dot(&vh).mapv(|x| x * tau);
+    let expected = match side {
+        Side::Left => h.dot(&c),
+        Side::Right => c.dot(&h),
+    };
+    let mut c = c;
+    apply_householder(&mut c, side, &v, tau).unwrap();
+    assert_close_l2!(&c, &expected, rtol);
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<householder_reflector_ $scalar>]() {
+                test_householder::<$scalar>(4, $rtol);
+                test_householder::<$scalar>(1, $rtol);
+            }
+
+            #[test]
+            fn [<apply_householder_ $scalar>]() {
+                test_apply::<$scalar>(Side::Left, random((4, 3)), $rtol);
+                test_apply::<$scalar>(Side::Left, random((4, 3).f()), $rtol);
+                test_apply::<$scalar>(Side::Right, random((3, 4)), $rtol);
+                test_apply::<$scalar>(Side::Right, random((3, 4).f()), $rtol);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 1e-9);
+impl_test!(f32, 1e-4);
+impl_test!(c64, 1e-9);
+impl_test!(c32, 1e-4);
+
+#[test]
+fn apply_householder_shape_mismatch() {
+    let mut c: Array2<f64> = random((3, 2));
+    let v: Array1<f64> = random(2);
+    assert!(apply_householder(&mut c, Side::Left, &v, 1.0).is_err());
+}
@@ -0,0 +1,57 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+fn sorted_re(r: &Array1<c64>) -> Array1<f64> {
+    let mut re: Vec<f64> = r.iter().map(|r| r.re).collect();
+    re.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Array1::from(re)
+}
+
+#[test]
+fn roots_real() {
+    // 2 (x + 1)(x - 1)(x - 3)
+    let r = roots(&array![2.0, -6.0, -2.0, 6.0]).unwrap();
+    assert_close_l2!(&sorted_re(&r), &array![-1.0, 1.0, 3.0], 1e-9);
+}
+
+#[test]
+fn roots_complex_pair() {
+    // x^2 + 1
+    let r: Array1<c64> = roots(&array![1.0, 0.0, 1.0]).unwrap();
+    assert_eq!(r.len(), 2);
+    for r in r.iter() {
+        assert!(r.re.abs() < 1e-12);
+        assert!((r.im.abs() - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn roots_complex_coeffs() {
+    // (x - i)(x - 2) = x^2 - (2 + i) x + 2i
+    let c = array![c64::new(1.0, 0.0), c64::new(-2.0, -1.0), c64::new(0.0, 2.0)];
+    let r = roots(&c).unwrap();
+    assert_eq!(r.len(), 2);
+    for r in r.iter() {
+        let p = c[0] * r * r + c[1] * r + c[2];
+        assert!(p.norm() < 1e-12);
+    }
+}
+
+#[test]
+fn roots_strip_zeros() {
+    // 0 x^4 + x^3 - x^2 + 0 x + 0 = x^2 (x - 1)
+    let r = roots(&array![0.0, 1.0, -1.0, 0.0, 0.0]).unwrap();
+    assert_eq!(r.len(), 3);
+    assert_close_l2!(&sorted_re(&r), &array![0.0, 0.0, 1.0], 1e-12);
+    assert_eq!(r[1], c64::new(0.0, 0.0));
+    assert_eq!(r[2], c64::new(0.0, 0.0));
+}
+
+#[test]
+fn roots_degenerate() {
+    assert_eq!(roots(&array![0.0, 0.0]).unwrap().len(), 0);
+    assert_eq!(roots(&array![0.0, 3.0]).unwrap().len(), 0);
+    assert_eq!(roots(&Array1::<f64>::zeros(0)).unwrap().len(), 0);
+    let r = roots(&array![3.0, 0.0]).unwrap();
+    assert_eq!(r, array![c64::new(0.0, 0.0)]);
+}
@@ -0,0 +1,84 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Random matrix `V D V^{-1}` with the eigenvalues of the given real parts
+fn with_eigenvalues<A: Scalar + Lapack>(re: &[f64]) -> (Array2<A>, Array2<A>) {
+    let n = re.len();
+    let mut v: Array2<A> = random((n, n));
+    for i in 0..n {
+        v[(i, i)] += A::from_real(A::real(n as f64));
+    }
+    let v_inv = v.inv().unwrap();
+    let d = Array2::from_diag(
+        &re.iter()
+            .map(|&r| A::from_real(A::real(r)))
+            .collect::<Array1<A>>(),
+    );
+    let s = Array2::from_diag(
+        &d.diag()
+            .mapv(|x| A::from_real(num_traits::Float::signum(x.re()))),
+    );
+    (v.dot(&d).dot(&v_inv), v.dot(&s).dot(&v_inv))
+}
+
+macro_rules! impl_signm {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<signm_ $scalar>]() {
+                let (a, truth) = with_eigenvalues::<$scalar>(&[-3.0, -0.5, 0.2, 1.0, 4.0]);
+                let s = a.signm().unwrap();
+                assert_close_l2!(&s, &truth, 1e-9);
+                assert_close_l2!(&s.dot(&s), &Array2::eye(5), 1e-9);
+                assert_close_l2!(&s.dot(&a), &a.dot(&s), 1e-9);
+            }
+
+            #[test]
+            fn [<spectral_projector_ $scalar>]() {
+                let (a, _) = with_eigenvalues::<$scalar>(&[-3.0, -0.5, 0.2, 1.0, 4.0]);
+                let left = a.spectral_projector(HalfPlane::Left).unwrap();
+                let right = a.spectral_projector(HalfPlane::Right).unwrap();
+                assert_close_l2!(&left.dot(&left), &left, 1e-9);
+                assert_close_l2!(&right.dot(&right), &right, 1e-9);
+                assert_close_l2!(&(&left + &right), &Array2::eye(5), 1e-9);
+                assert_close_l2!(&left.dot(&a), &a.dot(&left), 1e-9);
+                // rank is the number of the eigenvalues in each half-plane
+                let trace_left = left.diag().sum();
+                assert!((trace_left - <$scalar>::from_real(2.0)).abs() < 1e-9);
+            }
+        }
+    };
+}
+
+impl_signm!(f64);
+impl_signm!(c64);
+
+#[test]
+fn signm_invariant_subspace() {
+    // Eigenvalues `-1 ± 2i` and `3`
+    let a: Array2<f64> = array![[-1., 2., 1.], [-2., -1., 0.], [0., 0., 3.]];
+    let p = a.spectral_projector(HalfPlane::Left).unwrap();
+    let (q, _) = p.qr().unwrap();
+    let q = q.slice(s![.., ..2]).to_owned();
+    // `A Q = Q (Q^T A Q)` for the invariant subspace spanned by `Q`
+    let h = q.t().dot(&a).dot(&q);
+    assert_close_l2!(&a.dot(&q), &q.dot(&h), 1e-9);
+    let mut e = h.eigvals().unwrap().mapv(|e| e.re);
+    e.mapv_inplace(|x| x + 1.0);
+    assert_close_l2!(&e, &array![0.0, 0.0], 1e-9);
+}
+
+#[test]
+fn signm_imaginary_axis() {
+    let a: Array2<f64> = array![[0., 1.], [-1., 0.]];
+    assert!(matches!(
+        a.signm(),
+        Err(error::LinalgError::EigenvalueOnImaginaryAxis)
+    ));
+    let a: Array2<f64> = array![[1., 0.], [0., 0.]];
+    assert!(matches!(
+        a.spectral_projector(HalfPlane::Right),
+        Err(error::LinalgError::EigenvalueOnImaginaryAxis)
+    ));
+    assert!(Array2::<f64>::zeros((2, 3)).signm().is_err());
+}
@@ -1,6 +1,7 @@
 use ndarray::prelude::*;
 use ndarray_linalg::{
-    assert_aclose, assert_close_l2, c32, c64, random, random_hpd, solve::*, OperationNorm, Scalar,
+    assert_aclose, assert_close_l2, c32, c64, error::LinalgError, random, random_hpd, solve::*,
+    Lapack, OperationNorm, Scalar, SolveC, SolveH,
 };
 
 macro_rules! test_solve {
@@ -268,3 +269,311 @@ fn rcond_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+#[test]
+fn solve_singular_error() {
+    let a: Array2<f64> = array![[1., 2.], [2., 4.]];
+    let b: Array1<f64> = array![1., 2.];
+    match a.solve(&b) {
+        Err(ndarray_linalg::error::LinalgError::Lapack(
+            lax::error::Error::LapackComputationalFailure {
+                routine,
+                return_code,
+            },
+        )) => {
+            assert_eq!(routine, "dgetrf");
+            assert_eq!(return_code, 2);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn solve_factored() {
+    fn test<T: Scalar + Lapack>(a: &Array2<T>, rtol: T::Real) {
+        let x: Array1<T> = random(3);
+        let b = a.dot(&x);
+        let (y, f) = a.solve_factored(&b).unwrap();
+        assert_close_l2!(&y, &x, rtol);
+        // the factorization is reusable
+        assert_close_l2!(&f.solve(&b).unwrap(), &x, rtol);
+        assert_aclose!(f.det().unwrap(), a.det().unwrap(), rtol);
+    }
+    for &set_f in &[false, true] {
+        test::<f64>(&random([3; 2].set_f(set_f)), 1e-9);
+        test::<f32>(&random([3; 2].set_f(set_f)), 1e-3);
+        test::<c64>(&random([3; 2].set_f(set_f)), 1e-9);
+        test::<c32>(&random([3; 2].set_f(set_f)), 1e-3);
+    }
+}
+
+#[test]
+fn solve_strided_view() {
+    let a: Array2<f64> = random((4, 4));
+    let x: Array1<f64> = random(4);
+    let mut bs: Array2<f64> = random((4, 3));
+    bs.column_mut(1).assign(&a.dot(&x));
+    let b = bs.column(1);
+    assert!(b.as_slice().is_none());
+
+    // view inputs
+    assert_close_l2!(&a.view().solve(&b).unwrap(), &x, 1e-9);
+    assert_close_l2!(&a.slice(s![.., ..]).solve(&b).unwrap(), &x, 1e-9);
+    let f = a.factorize().unwrap();
+    assert_close_l2!(&f.solve(&b).unwrap(), &x, 1e-9);
+
+    // written back into the strided view
+    let mut bs_ = bs.clone();
+    f.solve_inplace(&mut bs_.column_mut(1)).unwrap();
+    assert_close_l2!(&bs_.column(1), &x, 1e-9);
+    assert_eq!(bs_.column(0), bs.column(0));
+    assert_eq!(bs_.column(2), bs.column(2));
+
+    let b = a.t().dot(&x);
+    let mut bs_ = bs.clone();
+    bs_.column_mut(1).assign(&b);
+    f.solve_t_inplace(&mut bs_.column_mut(1)).unwrap();
+    assert_close_l2!(&bs_.column(1), &x, 1e-9);
+
+    // reversed view
+    let b = a.dot(&x);
+    let mut rev = b.slice(s![..;-1]).to_owned();
+    let mut view = rev.slice_mut(s![..;-1]);
+    a.solve_inplace(&mut view).unwrap();
+    assert_close_l2!(&view, &x, 1e-9);
+}
+
+#[test]
+fn solveh_strided_view() {
+    let a: Array2<f64> = random_hpd(4);
+    let x: Array1<f64> = random(4);
+    let mut bs: Array2<f64> = random((4, 3));
+    bs.column_mut(2).assign(&a.dot(&x));
+    let mut bh = bs.clone();
+    a.solveh_inplace(&mut bh.column_mut(2)).unwrap();
+    assert_close_l2!(&bh.column(2), &x, 1e-9);
+    let mut bc = bs.clone();
+    a.solvec_inplace(&mut bc.column_mut(2)).unwrap();
+    assert_close_l2!(&bc.column(2), &x, 1e-9);
+}
+
+#[test]
+fn solve_into_buffer() {
+    let a: Array2<f64> = random((3, 3));
+    let x: Array1<f64> = random(3);
+    let b = a.dot(&x);
+    let f = a.factorize().unwrap();
+    let mut out = Array1::zeros(3);
+    f.solve_into_buffer(&b, &mut out).unwrap();
+    assert_close_l2!(&out, &x, 1e-7);
+    // a strided buffer
+    let mut buf = Array2::zeros((3, 2));
+    a.solve_into_buffer(&b, &mut buf.column_mut(1)).unwrap();
+    assert_close_l2!(&buf.column(1), &x, 1e-7);
+    assert!(f.solve_into_buffer(&b, &mut Array1::zeros(2)).is_err());
+}
+
+macro_rules! impl_solve_complex {
+    ($name:ident, $real:ty, $complex:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$real> = random((4, 4));
+            let x: Array1<$complex> = random(4);
+            let ac: Array2<$complex> = a.mapv(<$complex>::from);
+            let b = ac.dot(&x);
+            assert_close_l2!(&a.solve_complex(&b).unwrap(), &x, $rtol);
+            // C and F layouts of the factorization
+            let f = a.t().to_owned().factorize_into().unwrap();
+            let bt = ac.t().dot(&x);
+            assert_close_l2!(&f.solve_complex(&bt).unwrap(), &x, $rtol);
+        }
+    };
+}
+
+impl_solve_complex!(solve_complex_f64, f64, c64, 1e-9);
+impl_solve_complex!(solve_complex_f32, f32, c32, 1e-3);
+
+macro_rules! impl_solve_real_part {
+    ($name:ident, $real:ty, $complex:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$complex> = random((4, 4));
+            let x: Array1<$real> = random(4);
+            let b = a.dot(&x.mapv(<$complex>::from));
+            assert_close_l2!(&a.solve_real_part(&b, $rtol).unwrap(), &x, $rtol);
+            let f = a.factorize().unwrap();
+            assert_close_l2!(&f.solve_real_part(&b, $rtol).unwrap(), &x, $rtol);
+            // the imaginary part is not dropped silently
+            let x: Array1<$complex> = random(4);
+            let b = a.dot(&x);
+            assert!(matches!(
+                a.solve_real_part(&b, $rtol),
+                Err(LinalgError::NotReal { .. })
+            ));
+        }
+    };
+}
+
+impl_solve_real_part!(solve_real_part_f64, f64, c64, 1e-9);
+impl_solve_real_part!(solve_real_part_f32, f32, c32, 1e-3);
+
+#[test]
+fn rcond_cached() {
+    // The LU factors `[[10, 1], [0.1, -0.1]]` have a different norm from `A`
+    let a: Array2<f64> = array![[1.0, 0.0], [10.0, 1.0]];
+    let f = a.factorize().unwrap();
+    let rcond = f.rcond().unwrap();
+    assert_aclose!(rcond, 1.0 / 121.0, 1e-12);
+    assert_eq!(f.rcond().unwrap(), rcond);
+    assert_eq!(f.cond().unwrap(), 1.0 / rcond);
+    // the cache is kept by clone
+    let g = f.clone();
+    assert_eq!(g.rcond().unwrap(), rcond);
+    assert_aclose!(
+        a.t().to_owned().factorize_into().unwrap().rcond().unwrap(),
+        1.0 / 121.0,
+        1e-12
+    );
+}
+
+macro_rules! impl_solve_xrefine {
+    ($name:ident, $scalar:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random_hpd(4);
+            let x: Array1<$scalar> = random(4);
+            let b = a.dot(&x);
+            for a in &[a.clone(), a.t().to_owned()] {
+                let b = a.dot(&x);
+                let (y, bounds) = a.solve_xrefine(&b).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+                assert!(bounds.backward_error < $rtol);
+                assert!(bounds.rcond > 0.0 && bounds.rcond <= 1.0);
+            }
+            let (y, _) = a.solve_xrefine(&b.slice(s![..;-1]).slice(s![..;-1])).unwrap();
+            assert_close_l2!(&y, &x, $rtol);
+        }
+    };
+}
+
+impl_solve_xrefine!(solve_xrefine_f64, f64, 1e-9);
+impl_solve_xrefine!(solve_xrefine_f32, f32, 1e-3);
+impl_solve_xrefine!(solve_xrefine_c64, c64, 1e-9);
+impl_solve_xrefine!(solve_xrefine_c32, c32, 1e-3);
+
+#[test]
+fn solve_xrefine_hilbert() {
+    // condition number is about 1.5e7
+    let n = 6;
+    let a = Array2::from_shape_fn((n, n), |(i, j)| 1.0 / (i + j + 1) as f64);
+    let x = Array1::<f64>::ones(n);
+    let b = a.dot(&x);
+    let (y, bounds) = a.solve_xrefine(&b).unwrap();
+    assert_close_l2!(&y, &x, 1e-7);
+    assert!(bounds.rcond < 1e-5);
+    if bounds.normwise_trusted {
+        let err = (&y - &x).iter().fold(0.0_f64, |m, e| m.max(e.abs()));
+        assert!(err <= bounds.normwise * 10.0 + 1e-15);
+    }
+    assert!(Array2::<f64>::zeros((0, 0))
+        .solve_xrefine(&Array1::zeros(0))
+        .unwrap()
+        .0
+        .is_empty());
+}
+
+macro_rules! impl_kron_solve {
+    ($name:ident, $scalar:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random_hpd(3);
+            let b: Array2<$scalar> = random_hpd(4);
+            let x: Array1<$scalar> = random(12);
+            let k = ndarray::linalg::kron(&a, &b);
+            let c = k.dot(&x);
+            assert_close_l2!(&kron_solve(&a, &b, &c).unwrap(), &x, $rtol);
+            // non-symmetric factors and F layout
+            let a = a.t().to_owned().reversed_axes() + Array2::<$scalar>::eye(3);
+            let b2: Array2<$scalar> = random_hpd(4);
+            let b = b.dot(&b2);
+            let c = ndarray::linalg::kron(&a, &b).dot(&x);
+            assert_close_l2!(&kron_solve(&a, &b, &c).unwrap(), &x, $rtol);
+        }
+    };
+}
+
+impl_kron_solve!(kron_solve_f64, f64, 1e-9);
+impl_kron_solve!(kron_solve_c64, c64, 1e-9);
+
+#[test]
+fn kron_solve_shape_mismatch() {
+    let a: Array2<f64> = Array2::eye(2);
+    let b: Array2<f64> = Array2::eye(3);
+    assert!(kron_solve(&a, &b, &Array1::zeros(5)).is_err());
+    assert!(kron_solve(&a, &Array2::zeros((2, 3)), &Array1::zeros(6)).is_err());
+}
+
+macro_rules! impl_solve_matrix {
+    ($name:ident, $scalar:ty, $rtol:expr) => {
+        #[test]
+        fn $name() {
+            // all the combinations of the layouts of `A` and `B`
+            for &a_f in &[false, true] {
+                for &b_f in &[false, true] {
+                    let a: Array2<$scalar> = random([4; 2].set_f(a_f));
+                    let x: Array2<$scalar> = random((4, 3).set_f(b_f));
+                    let f = a.factorize().unwrap();
+
+                    let mut b = Array2::zeros((4, 3).set_f(b_f));
+                    b.assign(&a.dot(&x));
+                    assert_eq!(b.is_standard_layout(), !b_f);
+                    let y = a.solve_matrix(&b).unwrap();
+                    assert_eq!(y.is_standard_layout(), !b_f);
+                    assert_close_l2!(&y, &x, $rtol);
+                    assert_close_l2!(&f.solve_matrix(&b).unwrap(), &x, $rtol);
+                    assert_close_l2!(&f.solve_matrix_into(b.clone()).unwrap(), &x, $rtol);
+
+                    b.assign(&a.t().dot(&x));
+                    assert_close_l2!(&a.solve_t_matrix(&b).unwrap(), &x, $rtol);
+                    assert_close_l2!(
+                        &f.solve_t_matrix_inplace(&mut b.clone()).unwrap(),
+                        &x,
+                        $rtol
+                    );
+
+                    b.assign(&a.t().mapv(|x| x.conj()).dot(&x));
+                    assert_close_l2!(&a.solve_h_matrix(&b).unwrap(), &x, $rtol);
+                    assert_close_l2!(&f.solve_h_matrix_into(b).unwrap(), &x, $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_solve_matrix!(solve_matrix_f64, f64, 1e-9);
+impl_solve_matrix!(solve_matrix_c64, c64, 1e-9);
+impl_solve_matrix!(solve_matrix_f32, f32, 1e-3);
+impl_solve_matrix!(solve_matrix_c32, c32, 1e-3);
+
+#[test]
+fn solve_matrix_strided_view() {
+    let a: Array2<f64> = random((4, 4));
+    let x: Array2<f64> = random((4, 2));
+    let mut bs: Array2<f64> = random((4, 5));
+    bs.slice_mut(s![.., 1..;2]).assign(&a.dot(&x));
+    let mut bs_ = bs.clone();
+    let mut view = bs_.slice_mut(s![.., 1..;2]);
+    a.solve_matrix_inplace(&mut view).unwrap();
+    assert_close_l2!(&view, &x, 1e-9);
+    assert_eq!(bs_.column(0), bs.column(0));
+    assert_eq!(bs_.column(2), bs.column(2));
+    assert_eq!(bs_.column(4), bs.column(4));
+}
+
+#[test]
+#[should_panic]
+fn solve_matrix_shape_mismatch() {
+    let a: Array2<f64> = random((3, 3));
+    let b: Array2<f64> = random((4, 2));
+    let _ = a.solve_matrix(&b);
+}
@@ -1,6 +1,9 @@
 use ndarray::*;
 use ndarray_linalg::*;
 
+mod common;
+use common::half_filled;
+
 #[should_panic]
 #[test]
 fn solveh_shape_mismatch() {
@@ -62,3 +65,35 @@ fn solveh_random_t() {
     let y = f.solveh_into(b).unwrap();
     assert_close_l2!(&x, &y, 1e-7);
 }
+
+macro_rules! impl_factorizeh_uplo {
+    ($name:ident, $uplo:expr, $f:expr) => {
+        #[test]
+        fn $name() {
+            let a: Array2<c64> = random_hermite(4);
+            let b = if $f {
+                let mut b = Array2::zeros((4, 4).f());
+                b.assign(&half_filled(&a, $uplo));
+                b
+            } else {
+                half_filled(&a, $uplo)
+            };
+            let x: Array1<c64> = random(4);
+            let ax = a.dot(&x);
+
+            let f = b.factorizeh_uplo($uplo).unwrap();
+            assert_close_l2!(&f.solveh(&ax).unwrap(), &x, 1e-9);
+            assert_rclose!(f.deth(), a.deth().unwrap(), 1e-9);
+            assert_eq!(f.inertia(), a.inertia().unwrap());
+            assert_close_l2!(&f.invh().unwrap(), &a.invh().unwrap(), 1e-9);
+
+            let f = b.clone().factorizeh_uplo_into($uplo).unwrap();
+            assert_close_l2!(&f.solveh(&ax).unwrap(), &x, 1e-9);
+        }
+    };
+}
+
+impl_factorizeh_uplo!(factorizeh_uplo_c_upper, UPLO::Upper, false);
+impl_factorizeh_uplo!(factorizeh_uplo_c_lower, UPLO::Lower, false);
+impl_factorizeh_uplo!(factorizeh_uplo_f_upper, UPLO::Upper, true);
+impl_factorizeh_uplo!(factorizeh_uplo_f_lower, UPLO::Lower, true);
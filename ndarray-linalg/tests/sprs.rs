@@ -0,0 +1,45 @@
+#![cfg(feature = "sprs")]
+
+use ndarray::*;
+use ndarray_linalg::interop::*;
+use sprs::TriMat;
+
+fn sample() -> (Array2<f64>, sprs::CsMat<f64>) {
+    let dense = array![
+        [1., 0., 2., 0.],
+        [0., 3., 0., 0.],
+        [4., 0., 5., 6.],
+        [0., 0., 0., 7.]
+    ];
+    let mut tri = TriMat::new((4, 4));
+    for ((i, j), &x) in dense.indexed_iter() {
+        if x != 0. {
+            tri.add_triplet(i, j, x);
+        }
+    }
+    (dense, tri.to_csr())
+}
+
+#[test]
+fn from_sprs_csr_csc() {
+    let (dense, csr) = sample();
+    assert_eq!(from_sprs(&csr), dense);
+    assert_eq!(from_sprs(&csr.to_csc()), dense);
+}
+
+#[test]
+fn sprs_block_csr_csc() {
+    let (dense, csr) = sample();
+    for m in &[csr.clone(), csr.to_csc()] {
+        assert_eq!(
+            sprs_block(m, 1..3, 0..3).unwrap(),
+            dense.slice(s![1..3, 0..3])
+        );
+        assert_eq!(
+            sprs_diagonal_block(m, 2..4).unwrap(),
+            dense.slice(s![2..4, 2..4])
+        );
+        assert_eq!(sprs_block(m, 2..2, 0..4).unwrap().dim(), (0, 4));
+        assert!(sprs_block(m, 0..5, 0..4).is_err());
+    }
+}
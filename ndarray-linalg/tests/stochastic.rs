@@ -0,0 +1,68 @@
+use ndarray::*;
+use ndarray_linalg::*;
+use rand::{rngs::StdRng, SeedableRng};
+
+macro_rules! impl_trace_inv {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<trace_inv_estimate_ $scalar>]() {
+                let mut rng = StdRng::seed_from_u64(0);
+                let n = 30;
+                let a: Array2<$scalar> = random_hpd(n);
+                let truth = a.invh().unwrap().trace().unwrap();
+                let f = a.factorizeh().unwrap();
+                let (tr, err) = trace_inv_estimate(|z| f.solveh(z).unwrap(), n, 1000, &mut rng);
+                assert!(err > 0.0);
+                assert!((tr - truth).abs() < 5.0 * err);
+                assert!((tr - truth).abs() < 0.1 * truth.abs());
+            }
+        }
+    };
+}
+
+impl_trace_inv!(f64);
+impl_trace_inv!(c64);
+
+#[test]
+fn trace_inv_estimate_diagonal() {
+    // `z^H D^{-1} z = tr D^{-1}` for any Rademacher vector `z`
+    let mut rng = StdRng::seed_from_u64(0);
+    let d: Array1<f64> = array![1.0, 2.0, 4.0, 8.0];
+    let (tr, err) = trace_inv_estimate(|z: &Array1<f64>| z / &d, 4, 5, &mut rng);
+    assert!((tr - 1.875).abs() < 1e-12);
+    assert!(err < 1e-12);
+
+    let (_, err) = trace_inv_estimate(|z: &Array1<f64>| z / &d, 4, 1, &mut rng);
+    assert!(err.is_infinite());
+}
+
+macro_rules! impl_logdet {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<logdet_estimate_ $scalar>]() {
+                let mut rng = StdRng::seed_from_u64(0);
+                let n = 40;
+                let a: Array2<$scalar> = random_hpd(n);
+                let (_, truth) = a.sln_deth().unwrap();
+                let (logdet, err) = logdet_estimate(|x| a.dot(x), n, 500, 20, &mut rng).unwrap();
+                assert!(err > 0.0);
+                assert!((logdet - truth).abs() < 5.0 * err + 1e-6 * truth.abs());
+                assert!((logdet - truth).abs() < 0.1 * truth.abs().max(1.0));
+            }
+        }
+    };
+}
+
+impl_logdet!(f64);
+impl_logdet!(c64);
+
+#[test]
+fn logdet_estimate_diagonal() {
+    // The Lanczos iteration terminates at the invariant subspace, and the quadrature is exact
+    let mut rng = StdRng::seed_from_u64(0);
+    let d: Array1<f64> = array![1.0, 2.0, 4.0, 8.0];
+    let (logdet, _) = logdet_estimate(|x: &Array1<f64>| x * &d, 4, 5, 10, &mut rng).unwrap();
+    assert!((logdet - 64.0_f64.ln()).abs() < 1e-9);
+}
@@ -90,3 +90,140 @@ test_svd_impl!(c64, test, 3, 4);
 test_svd_impl!(c64, test_no_vt, 3, 4);
 test_svd_impl!(c64, test_no_u, 3, 4);
 test_svd_impl!(c64, test_diag_only, 3, 4);
+
+#[test]
+fn condition_from_singular_values() {
+    let a: Array2<f64> = random((4, 3));
+    let (_, s, _) = a.svd(false, false).unwrap();
+    let cond: f64 = ndarray_linalg::condition_from_singular_values(&s);
+    assert_rclose!(cond, s[0] / s[2], 1e-9);
+    assert!(cond >= 1.0);
+}
+
+#[test]
+fn condition_from_singular_values_singular() {
+    let s = array![2.0, 1.0, 0.0];
+    let cond: f64 = ndarray_linalg::condition_from_singular_values(&s);
+    assert!(cond.is_infinite());
+    let s = Array1::<f32>::zeros(0);
+    let cond: f32 = ndarray_linalg::condition_from_singular_values(&s);
+    assert_eq!(cond, 1.0);
+}
+
+macro_rules! test_low_rank_approx_impl {
+    ($type:ty, $n:expr, $m:expr) => {
+        paste::item! {
+            #[test]
+            fn [<low_rank_approx_ $type _ $n x $m>]() {
+                let a: Array2<$type> = random(($n, $m));
+                let s = a.svd(false, false).unwrap().1;
+                let (b, err) = a.low_rank_approx(2).unwrap();
+                assert_eq!(b.dim(), a.dim());
+                assert_aclose!(err, s[2], 1e-7);
+                // `A - B` has the remaining singular values
+                let sr = (&a - &b).svd(false, false).unwrap().1;
+                assert_aclose!(sr[0], s[2], 1e-7);
+                // `B` has rank 2
+                let sb = b.svd(false, false).unwrap().1;
+                assert_close_l2!(&sb.slice(s![..2]), &s.slice(s![..2]), 1e-7);
+                assert_aclose!(sb[2], 0.0, 1e-7);
+
+                let (b, err) = a.low_rank_approx(min($n, $m)).unwrap();
+                assert_close_l2!(&b, &a, 1e-7);
+                assert_eq!(err, 0.0);
+            }
+        }
+    };
+}
+
+test_low_rank_approx_impl!(f64, 5, 4);
+test_low_rank_approx_impl!(f64, 4, 5);
+test_low_rank_approx_impl!(c64, 5, 4);
+test_low_rank_approx_impl!(c64, 4, 5);
+
+macro_rules! test_svd_analysis_impl {
+    ($type:ty, $n:expr, $m:expr) => {
+        paste::item! {
+            #[test]
+            fn [<svd_analysis_ $type _ $n x $m>]() {
+                // rank 2 matrix
+                let x: Array2<$type> = random(($n, 2));
+                let y: Array2<$type> = random((2, $m));
+                let a = x.dot(&y);
+                let an = a.svd_analysis(None).unwrap();
+                assert_eq!(an.rank(), 2);
+
+                let null = an.null_space();
+                assert_eq!(null.dim(), ($m, $m - 2));
+                assert_close_max!(&a.dot(&null), &Array2::zeros(($n, $m - 2)), 1e-7);
+                let col = an.col_space();
+                assert_eq!(col.dim(), ($n, 2));
+                // `A` is in the column space
+                let p: Array2<$type> = col.dot(&conjugate::<_, _, OwnedRepr<_>>(&col));
+                assert_close_l2!(&p.dot(&a), &a, 1e-7);
+
+                assert!(an.condition_number().is_infinite() || an.condition_number() > 1e10);
+                assert_eq!(a.svd_analysis(Some(2.0)).unwrap().rank(), 0);
+            }
+        }
+    };
+}
+
+test_svd_analysis_impl!(f64, 5, 4);
+test_svd_analysis_impl!(f64, 4, 5);
+test_svd_analysis_impl!(c64, 5, 4);
+test_svd_analysis_impl!(c64, 4, 5);
+
+macro_rules! test_solve_homogeneous_impl {
+    ($type:ty, $n:expr, $m:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solve_homogeneous_ $type _ $n x $m>]() {
+                let a: Array2<$type> = random(($n, $m));
+                let h = a.solve_homogeneous().unwrap();
+                let (_, s, _) = a.svd(false, false).unwrap();
+                assert!(h.is_unique());
+                assert!((h.solution.norm_l2() - 1.0).abs() < 1e-9);
+                assert!((h.residual - s[$m - 1]).abs() < 1e-9);
+                assert!((a.dot(&h.solution).norm_l2() - h.residual).abs() < 1e-9);
+
+                // exact solution of the rank `m - 1` matrix
+                let x: Array2<$type> = random(($n, $m - 1));
+                let y: Array2<$type> = random(($m - 1, $m));
+                let a = x.dot(&y);
+                let h = a.solve_homogeneous().unwrap();
+                assert!(h.is_unique());
+                assert!(h.residual < 1e-9);
+                assert_close_max!(&a.dot(&h.solution), &Array1::zeros($n), 1e-9);
+            }
+        }
+    };
+}
+
+test_solve_homogeneous_impl!(f64, 5, 4);
+test_solve_homogeneous_impl!(f64, 4, 4);
+test_solve_homogeneous_impl!(c64, 5, 4);
+test_solve_homogeneous_impl!(c64, 4, 4);
+
+#[test]
+fn solve_homogeneous_degenerate() {
+    // null space of dimension 2
+    let a: Array2<f64> = array![[1., 0., 0., 0.], [0., 2., 0., 0.]];
+    let h = a.solve_homogeneous().unwrap();
+    assert!(!h.is_unique());
+    assert_eq!(h.basis.dim(), (4, 2));
+    assert_eq!(h.residual, 0.0);
+    assert_close_max!(&a.dot(&h.basis), &Array2::zeros((2, 2)), 1e-12);
+    assert_close_max!(&h.basis.t().dot(&h.basis), &Array2::eye(2), 1e-12);
+    assert_close_max!(&h.solution, &h.basis.column(1), 0.0);
+
+    // repeated smallest non-zero singular value
+    let a: Array2<f64> = Array2::from_diag(&array![3., 1., 1.]);
+    let h = a.solve_homogeneous().unwrap();
+    assert_eq!(h.basis.ncols(), 2);
+    assert!((h.residual - 1.0).abs() < 1e-12);
+
+    let a: Array2<f64> = Array2::zeros((0, 3));
+    assert_eq!(a.solve_homogeneous().unwrap().basis.ncols(), 3);
+    assert!(Array2::<f64>::zeros((3, 0)).solve_homogeneous().is_err());
+}
@@ -0,0 +1,55 @@
+use ndarray::*;
+use ndarray_linalg::{krylov::*, *};
+
+macro_rules! impl_svds {
+    ($name:ident, $scalar:ty, $shape:expr) => {
+        #[test]
+        fn $name() {
+            let k = 3;
+            let a: Array2<$scalar> = random($shape);
+            let ah: Array2<$scalar> = conjugate(&a);
+            let (_, s, _) = a.svd(false, false).unwrap();
+            let result = svds(|x| a.dot(x), |y| ah.dot(y), a.dim(), k, 1e-10).unwrap();
+            assert!(result.converged);
+            assert_close_l2!(&result.singular_values, &s.slice(s![..k]).to_owned(), 1e-7);
+            assert_eq!(result.u.dim(), (a.nrows(), k));
+            assert_eq!(result.v.dim(), (a.ncols(), k));
+            for i in 0..k {
+                let sigma = <$scalar>::from_real(result.singular_values[i]);
+                let u = result.u.column(i);
+                let v = result.v.column(i);
+                assert_close_l2!(&a.dot(&v), &u.mapv(|u| u * sigma), 1e-7);
+                assert_close_l2!(&ah.dot(&u), &v.mapv(|v| v * sigma), 1e-7);
+                assert!(result.residual_norms[i] <= 1e-9 * result.singular_values[0]);
+            }
+        }
+    };
+}
+
+impl_svds!(svds_tall, f64, (20, 10));
+impl_svds!(svds_wide, f64, (10, 20));
+impl_svds!(svds_tall_complex, c64, (20, 10));
+impl_svds!(svds_wide_complex, c64, (10, 20));
+
+#[test]
+fn svds_low_rank() {
+    // rank 2, and the other singular values are zero
+    let x: Array2<f64> = random((8, 2));
+    let y: Array2<f64> = random((2, 6));
+    let a = x.dot(&y);
+    let (_, s, _) = a.svd(false, false).unwrap();
+    let result = svds(|v| a.dot(v), |u| a.t().dot(u), a.dim(), 2, 1e-10).unwrap();
+    assert!(result.converged);
+    assert_close_l2!(&result.singular_values, &s.slice(s![..2]).to_owned(), 1e-7);
+
+    let result = svds(|v| a.dot(v), |u| a.t().dot(u), a.dim(), 3, 1e-10).unwrap();
+    let sv = &result.singular_values;
+    assert_close_l2!(
+        &sv.slice(s![..2]).to_owned(),
+        &s.slice(s![..2]).to_owned(),
+        1e-7
+    );
+    if sv.len() == 3 {
+        assert!(sv[2] < 1e-7 * sv[0]);
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "testing")]
+
+use ndarray::*;
+use ndarray_linalg::testing::*;
+use ndarray_linalg::*;
+
+#[test]
+fn abs_diff_eq_close() {
+    let a: Array2<f64> = random((3, 4));
+    let b = &a + 1e-10;
+    assert_matrix_abs_diff_eq(&a, &b, 1e-9);
+    let a: Array2<c64> = random((3, 4).f());
+    assert_matrix_abs_diff_eq(&a, &a.t().t(), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Too large absolute deviation at (1, 2)")]
+fn abs_diff_eq_far() {
+    let a: Array2<f64> = Array2::zeros((3, 4));
+    let mut b = a.clone();
+    b[(0, 0)] = 0.1;
+    b[(1, 2)] = -0.5;
+    assert_matrix_abs_diff_eq(&a, &b, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "Too large absolute deviation at (2, 0)")]
+fn abs_diff_eq_nan() {
+    let a: Array2<f64> = Array2::zeros((3, 4));
+    let mut b = a.clone();
+    b[(2, 0)] = f64::NAN;
+    b[(2, 1)] = 1.0;
+    assert_matrix_abs_diff_eq(&a, &b, 1e-9);
+}
+
+#[test]
+#[should_panic(expected = "Shapes of arrays differ")]
+fn abs_diff_eq_shape() {
+    let a: Array2<f64> = Array2::zeros((3, 4));
+    let b: Array2<f64> = Array2::zeros((4, 3));
+    assert_matrix_abs_diff_eq(&a, &b, 1e-9);
+}
+
+#[test]
+fn compare_close() {
+    let a: Array2<f64> = random((3, 4));
+    let b = &a + 1e-10;
+    let report = compare_matrices(&a, &b, 0.0, 1e-9).unwrap();
+    assert!(report.passed);
+    assert!(report.max_abs_diff < 2e-10);
+    assert!(report.max_abs_index.is_some());
+    let a: Array2<c64> = random((3, 4).f());
+    let report = compare_matrices(&a, &a.t().t(), 0.0, 0.0).unwrap();
+    assert!(report.passed);
+    assert_eq!(report.max_abs_diff, 0.0);
+    assert_eq!(report.max_rel_diff, 0.0);
+}
+
+#[test]
+fn compare_far() {
+    let a: Array2<f64> = array![[1.0, 100.0], [0.0, 1.0]];
+    let b = array![[1.5, 101.0], [0.0, 1.0]];
+    let report = compare_matrices(&a, &b, 0.1, 0.0).unwrap();
+    assert!(!report.passed);
+    assert_eq!(report.max_abs_diff, 1.0);
+    assert_eq!(report.max_abs_index, Some((0, 1)));
+    assert!((report.max_rel_diff - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(report.max_rel_index, Some((0, 0)));
+    // relative tolerance to `b`
+    assert!(compare_matrices(&a, &b, 0.34, 0.0).unwrap().passed);
+    // absolute tolerance
+    assert!(compare_matrices(&a, &b, 0.0, 1.0).unwrap().passed);
+}
+
+#[test]
+fn compare_zero_and_nan() {
+    let a: Array1<f64> = array![1e-12, 0.0, 2.0];
+    let b = array![0.0, 0.0, 2.0];
+    let report = compare_matrices(&a, &b, 1e-6, 0.0).unwrap();
+    assert!(!report.passed);
+    assert!(report.max_rel_diff.is_infinite());
+    assert_eq!(report.max_rel_index, Some(0));
+    assert!(compare_matrices(&a, &b, 1e-6, 1e-9).unwrap().passed);
+
+    let a: Array1<f64> = array![0.0, f64::NAN, 1.0];
+    let b = array![0.0, 0.0, 2.0];
+    let report = compare_matrices(&a, &b, 1.0, 1.0).unwrap();
+    assert!(!report.passed);
+    assert!(report.max_abs_diff.is_nan());
+    assert_eq!(report.max_abs_index, Some(1));
+}
+
+#[test]
+fn compare_shape() {
+    let a: Array2<f64> = Array2::zeros((3, 4));
+    let b: Array2<f64> = Array2::zeros((4, 3));
+    assert!(compare_matrices(&a, &b, 0.0, 1e-9).is_err());
+    let e: Array2<f64> = Array2::zeros((0, 3));
+    let report = compare_matrices(&e, &e, 0.0, 0.0).unwrap();
+    assert!(report.passed);
+    assert_eq!(report.max_abs_index, None);
+}
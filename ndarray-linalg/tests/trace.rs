@@ -6,3 +6,50 @@ fn trace() {
     let a: Array2<f64> = random((3, 3));
     assert_rclose!(a.trace().unwrap(), a[(0, 0)] + a[(1, 1)] + a[(2, 2)], 1e-7);
 }
+
+#[test]
+fn trace_complex() {
+    let a = array![
+        [c64::new(1.0, 2.0), c64::new(5.0, 5.0)],
+        [c64::new(5.0, 5.0), c64::new(3.0, -1.0)]
+    ];
+    assert_eq!(a.trace().unwrap(), c64::new(4.0, 1.0));
+}
+
+#[test]
+fn trace_nonsquare() {
+    let a: Array2<f64> = random((3, 2));
+    assert!(matches!(
+        a.trace(),
+        Err(error::LinalgError::NotSquare { rows: 3, cols: 2 })
+    ));
+}
+
+macro_rules! impl_trace_power {
+    ($name:ident, $scalar:ty) => {
+        #[test]
+        fn $name() {
+            let a: Array2<$scalar> = random((4, 4));
+            let mut ak = Array2::<$scalar>::eye(4);
+            for k in 0..5 {
+                let t = a.trace_power(k).unwrap();
+                let truth = ak.trace().unwrap();
+                assert_rclose!(t.re, truth.re(), 1e-9);
+                assert_rclose!(t.im, truth.im(), 1e-9);
+                ak = ak.dot(&a);
+            }
+        }
+    };
+}
+
+impl_trace_power!(trace_power_real, f64);
+impl_trace_power!(trace_power_complex, c64);
+
+#[test]
+fn trace_power_nonsquare() {
+    let a: Array2<f64> = random((3, 2));
+    assert!(matches!(
+        a.trace_power(2),
+        Err(error::LinalgError::NotSquare { rows: 3, cols: 2 })
+    ));
+}
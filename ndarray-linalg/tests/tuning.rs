@@ -0,0 +1,16 @@
+use ndarray_linalg::*;
+
+#[test]
+fn optimal_block_size_blocked() {
+    for &routine in &["GETRF", "GEQRF", "POTRF"] {
+        assert!(optimal_block_size::<f64>(routine, 200, 200).unwrap() >= 1);
+        assert!(optimal_block_size::<f32>(routine, 200, 200).unwrap() >= 1);
+        assert!(optimal_block_size::<c64>(routine, 200, 200).unwrap() >= 1);
+        assert!(optimal_block_size::<c32>(routine, 200, 200).unwrap() >= 1);
+    }
+}
+
+#[test]
+fn optimal_block_size_unknown() {
+    assert_eq!(optimal_block_size::<f64>("XXXXX", 200, 200).unwrap(), 1);
+}